@@ -2,20 +2,27 @@
 
 mod inst_16;
 mod inst_32;
+mod stream;
 
 mod a_extension;
+mod b_extension;
 mod base_i;
 mod c_extension;
+mod d_extension;
+mod f_extension;
 mod m_extension;
 mod priv_extension;
+mod q_extension;
+mod v_extension;
 mod zicboz_extension;
 mod zicfiss_extension;
 mod zicntr_extension;
 mod zicsr_extension;
 mod zifencei_extension;
 
-use crate::instruction::{Instruction, Opcode, OpcodeKind};
-use crate::{Extensions, Isa};
+use crate::instruction::{f_extension::RoundingMode, Instruction, Opcode, OpcodeKind};
+use crate::{ExtensionSet, Extensions, Isa};
+pub use stream::{decode_stream, DecodedInst, Decoder};
 
 /// Return Err if given opcode is only available on Rv64.
 fn only_rv64<T: Opcode>(opcode: T, isa: Isa) -> Result<T, DecodingError> {
@@ -65,6 +72,12 @@ pub enum DecodingError {
     IllegalInstruction,
     /// This instruction is only for Rv64 but appeared at Rv32.
     OnlyRv64Inst,
+    /// The encoding is reserved by the spec (e.g. `vadc.vvm` with vm = 1, `hfence.gvma` with rd != 0).
+    ReservedEncoding,
+    /// This instruction is included in the extension disabled by `ExtensionSet`.
+    DisabledExtension(Extensions),
+    /// The byte stream ends in the middle of an instruction.
+    TruncatedInst,
 }
 
 /// A trait to decode an instruction from u16/u32.
@@ -86,6 +99,29 @@ pub trait Decode {
     /// It will throws an error if target bytes is invalid for decoding.
     fn decode(&self, isa: Isa) -> Result<Instruction, DecodingError>;
 
+    /// Decode an instruction from u16/u32 only if its extension is enabled.
+    ///
+    /// # Errors
+    /// It will throws `DisabledExtension` if the instruction is included in (or depends on) the
+    /// disabled extension, in addition to the errors of `decode`.
+    fn decode_with(
+        &self,
+        isa: Isa,
+        extensions: ExtensionSet,
+    ) -> Result<Instruction, DecodingError> {
+        let inst = self.decode(isa)?;
+        let extension = inst.opc.get_extension();
+        if !extensions.contains(extension) {
+            return Err(DecodingError::DisabledExtension(extension));
+        }
+        match inst.opc.get_required_extension() {
+            Some(required) if !extensions.contains(required) => {
+                Err(DecodingError::DisabledExtension(required))
+            }
+            _ => Ok(inst),
+        }
+    }
+
     /// Parse opcode.
     ///
     /// # Errors
@@ -110,11 +146,29 @@ pub trait Decode {
     /// It will throws an error if rs2 is invalid.
     fn parse_rs2(self, opkind: &OpcodeKind) -> Result<Option<usize>, DecodingError>;
 
+    /// Parse source register 3.
+    ///
+    /// # Errors
+    /// It will throws an error if rs3 is invalid.
+    fn parse_rs3(self, opkind: &OpcodeKind) -> Result<Option<usize>, DecodingError>;
+
     /// Parse immediate.
     ///
     /// # Errors
     /// It will throws an error if immediate is invalid.
     fn parse_imm(self, opkind: &OpcodeKind, isa: Isa) -> Result<Option<i32>, DecodingError>;
+
+    /// Parse vector mask bit.
+    ///
+    /// # Errors
+    /// It will throws an error if vm is invalid.
+    fn parse_vm(self, opkind: &OpcodeKind) -> Result<Option<bool>, DecodingError>;
+
+    /// Parse rounding mode.
+    ///
+    /// # Errors
+    /// It will throws an error if rm is invalid.
+    fn parse_rm(self, opkind: &OpcodeKind) -> Result<Option<RoundingMode>, DecodingError>;
 }
 
 /// A trait to help decoding.
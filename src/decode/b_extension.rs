@@ -0,0 +1,324 @@
+pub mod bit_32 {
+    use super::super::{only_rv64, DecodeUtil, DecodingError};
+    use crate::instruction::b_extension::BOpcode;
+    use crate::Isa;
+
+    #[allow(clippy::too_many_lines)]
+    pub fn parse_opcode(inst: u32, isa: Isa) -> Result<BOpcode, DecodingError> {
+        let opmap: u8 = u8::try_from(inst.slice(6, 0)).unwrap();
+        let funct3: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
+        let funct5: u8 = u8::try_from(inst.slice(24, 20)).unwrap();
+        let funct6: u8 = u8::try_from(inst.slice(31, 26)).unwrap();
+        let funct7: u8 = u8::try_from(inst.slice(31, 25)).unwrap();
+        let funct12: u16 = u16::try_from(inst.slice(31, 20)).unwrap();
+        // shamt[5] must be zero on Rv32.
+        let shamt_funct6 = |opc: BOpcode| match (isa, inst.slice(25, 25)) {
+            (Isa::Rv32, 0b1) => Err(DecodingError::InvalidFunct7),
+            _ => Ok(opc),
+        };
+
+        match opmap {
+            0b011_0011 => match funct7 {
+                0b001_0000 => match funct3 {
+                    0b010 => Ok(BOpcode::SH1ADD),
+                    0b100 => Ok(BOpcode::SH2ADD),
+                    0b110 => Ok(BOpcode::SH3ADD),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b010_0000 => match funct3 {
+                    0b111 => Ok(BOpcode::ANDN),
+                    0b110 => Ok(BOpcode::ORN),
+                    0b100 => Ok(BOpcode::XNOR),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b000_0101 => match funct3 {
+                    0b001 => Ok(BOpcode::CLMUL),
+                    0b010 => Ok(BOpcode::CLMULR),
+                    0b011 => Ok(BOpcode::CLMULH),
+                    0b100 => Ok(BOpcode::MIN),
+                    0b101 => Ok(BOpcode::MINU),
+                    0b110 => Ok(BOpcode::MAX),
+                    0b111 => Ok(BOpcode::MAXU),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                // zext.h is encoded in OP-32 on Rv64.
+                0b000_0100 => match (isa, funct3, funct5) {
+                    (Isa::Rv32, 0b100, 0b00000) => Ok(BOpcode::ZEXT_H),
+                    (Isa::Rv32, 0b100, _) => Err(DecodingError::InvalidFunct5),
+                    (Isa::Rv32, _, _) => Err(DecodingError::InvalidFunct3),
+                    (Isa::Rv64, _, _) => Err(DecodingError::InvalidFunct7),
+                },
+                0b011_0000 => match funct3 {
+                    0b001 => Ok(BOpcode::ROL),
+                    0b101 => Ok(BOpcode::ROR),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b010_0100 => match funct3 {
+                    0b001 => Ok(BOpcode::BCLR),
+                    0b101 => Ok(BOpcode::BEXT),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b011_0100 => match funct3 {
+                    0b001 => Ok(BOpcode::BINV),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b001_0100 => match funct3 {
+                    0b001 => Ok(BOpcode::BSET),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                _ => Err(DecodingError::InvalidFunct7),
+            },
+            0b001_0011 => match funct3 {
+                0b001 => match funct6 {
+                    0b01_1000 => match (funct7, funct5) {
+                        (0b011_0000, 0b00000) => Ok(BOpcode::CLZ),
+                        (0b011_0000, 0b00001) => Ok(BOpcode::CTZ),
+                        (0b011_0000, 0b00010) => Ok(BOpcode::CPOP),
+                        (0b011_0000, 0b00100) => Ok(BOpcode::SEXT_B),
+                        (0b011_0000, 0b00101) => Ok(BOpcode::SEXT_H),
+                        (0b011_0000, _) => Err(DecodingError::InvalidFunct5),
+                        _ => Err(DecodingError::InvalidFunct7),
+                    },
+                    0b01_0010 => shamt_funct6(BOpcode::BCLRI),
+                    0b01_1010 => shamt_funct6(BOpcode::BINVI),
+                    0b00_1010 => shamt_funct6(BOpcode::BSETI),
+                    _ => Err(DecodingError::InvalidFunct6),
+                },
+                0b101 => match (funct12, isa) {
+                    (0b0010_1000_0111, _) => Ok(BOpcode::ORC_B),
+                    (0b0110_1001_1000, Isa::Rv32) | (0b0110_1011_1000, Isa::Rv64) => {
+                        Ok(BOpcode::REV8)
+                    }
+                    _ => match funct6 {
+                        0b01_1000 => shamt_funct6(BOpcode::RORI),
+                        0b01_0010 => shamt_funct6(BOpcode::BEXTI),
+                        _ => Err(DecodingError::InvalidFunct6),
+                    },
+                },
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            0b001_1011 => match funct3 {
+                0b001 => match funct6 {
+                    0b00_0010 => only_rv64(BOpcode::SLLI_UW, isa),
+                    _ => match (funct7, funct5) {
+                        (0b011_0000, 0b00000) => only_rv64(BOpcode::CLZW, isa),
+                        (0b011_0000, 0b00001) => only_rv64(BOpcode::CTZW, isa),
+                        (0b011_0000, 0b00010) => only_rv64(BOpcode::CPOPW, isa),
+                        (0b011_0000, _) => Err(DecodingError::InvalidFunct5),
+                        _ => Err(DecodingError::InvalidFunct7),
+                    },
+                },
+                0b101 => match funct7 {
+                    0b011_0000 => only_rv64(BOpcode::RORIW, isa),
+                    _ => Err(DecodingError::InvalidFunct7),
+                },
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            0b011_1011 => match funct7 {
+                0b000_0100 => match funct3 {
+                    0b000 => only_rv64(BOpcode::ADD_UW, isa),
+                    0b100 => match funct5 {
+                        0b00000 => only_rv64(BOpcode::ZEXT_H, isa),
+                        _ => Err(DecodingError::InvalidFunct5),
+                    },
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b001_0000 => match funct3 {
+                    0b010 => only_rv64(BOpcode::SH1ADD_UW, isa),
+                    0b100 => only_rv64(BOpcode::SH2ADD_UW, isa),
+                    0b110 => only_rv64(BOpcode::SH3ADD_UW, isa),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b011_0000 => match funct3 {
+                    0b001 => only_rv64(BOpcode::ROLW, isa),
+                    0b101 => only_rv64(BOpcode::RORW, isa),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                _ => Err(DecodingError::InvalidFunct7),
+            },
+            _ => Err(DecodingError::InvalidOpcode),
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn parse_rd(inst: u32, _opkind: &BOpcode) -> Option<usize> {
+        let rd: usize = inst.slice(11, 7) as usize;
+
+        Some(rd)
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn parse_rs1(inst: u32, _opkind: &BOpcode) -> Option<usize> {
+        let rs1: usize = inst.slice(19, 15) as usize;
+
+        Some(rs1)
+    }
+
+    pub fn parse_rs2(inst: u32, opkind: &BOpcode) -> Option<usize> {
+        let rs2: usize = inst.slice(24, 20) as usize;
+
+        match opkind {
+            BOpcode::SH1ADD
+            | BOpcode::SH2ADD
+            | BOpcode::SH3ADD
+            | BOpcode::ANDN
+            | BOpcode::ORN
+            | BOpcode::XNOR
+            | BOpcode::MAX
+            | BOpcode::MAXU
+            | BOpcode::MIN
+            | BOpcode::MINU
+            | BOpcode::ROL
+            | BOpcode::ROR
+            | BOpcode::CLMUL
+            | BOpcode::CLMULH
+            | BOpcode::CLMULR
+            | BOpcode::BCLR
+            | BOpcode::BEXT
+            | BOpcode::BINV
+            | BOpcode::BSET
+            | BOpcode::ADD_UW
+            | BOpcode::SH1ADD_UW
+            | BOpcode::SH2ADD_UW
+            | BOpcode::SH3ADD_UW
+            | BOpcode::ROLW
+            | BOpcode::RORW => Some(rs2),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn parse_imm(inst: u32, opkind: &BOpcode, isa: Isa) -> Option<i32> {
+        let shamt5 = || inst.slice(24, 20) as i32;
+        let shamt6 = || inst.slice(25, 20) as i32;
+        let shamt = || match isa {
+            Isa::Rv32 => shamt5(),
+            Isa::Rv64 => shamt6(),
+        };
+
+        match opkind {
+            BOpcode::RORI | BOpcode::BCLRI | BOpcode::BEXTI | BOpcode::BINVI | BOpcode::BSETI => {
+                Some(shamt())
+            }
+            BOpcode::SLLI_UW => Some(shamt6()),
+            BOpcode::RORIW => Some(shamt5()),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_variables)]
+mod test_b {
+    #[test]
+    #[allow(overflowing_literals, clippy::too_many_lines)]
+    fn b_decode_test() {
+        use crate::decode::inst_32::{test_32_in_rv32, test_32_in_rv64};
+        use crate::instruction::b_extension::BOpcode;
+        use crate::{Decode, DecodingError, Isa, OpcodeKind};
+
+        test_32_in_rv64(
+            0x20b5_2533,
+            OpcodeKind::B(BOpcode::SH1ADD),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv64(
+            0x40b5_7533,
+            OpcodeKind::B(BOpcode::ANDN),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv32(
+            0x6005_1513,
+            OpcodeKind::B(BOpcode::CLZ),
+            Some(10),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x6b85_5513,
+            OpcodeKind::B(BOpcode::REV8),
+            Some(10),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv32(
+            0x6985_5513,
+            OpcodeKind::B(BOpcode::REV8),
+            Some(10),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x0ab5_1533,
+            OpcodeKind::B(BOpcode::CLMUL),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv64(
+            0x28b5_1533,
+            OpcodeKind::B(BOpcode::BSET),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv64(
+            0x6255_5513,
+            OpcodeKind::B(BOpcode::RORI),
+            Some(10),
+            Some(10),
+            None,
+            Some(37),
+        );
+        test_32_in_rv64(
+            0x0805_453b,
+            OpcodeKind::B(BOpcode::ZEXT_H),
+            Some(10),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv32(
+            0x0805_4533,
+            OpcodeKind::B(BOpcode::ZEXT_H),
+            Some(10),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x0a05_151b,
+            OpcodeKind::B(BOpcode::SLLI_UW),
+            Some(10),
+            Some(10),
+            None,
+            Some(32),
+        );
+
+        // *.uw is only for Rv64.
+        assert_eq!(
+            0x08b5_053b_u32.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+        assert_eq!(
+            0x20b5_253b_u32.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+        // shamt[5] is reserved on Rv32.
+        assert_eq!(
+            0x6255_5513_u32.decode(Isa::Rv32),
+            Err(DecodingError::InvalidFunct7)
+        );
+    }
+}
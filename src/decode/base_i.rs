@@ -92,6 +92,14 @@ pub mod bit_32 {
                 0b111 => Ok(BaseIOpcode::AND),
                 _ => Err(DecodingError::InvalidFunct3),
             },
+            0b000_1111 => match funct3 {
+                0b000 => match (inst.slice(31, 20), inst.slice(19, 7)) {
+                    (0b1000_0011_0011, _) => Ok(BaseIOpcode::FENCE_TSO),
+                    (0b0000_0001_0000, 0) => Ok(BaseIOpcode::PAUSE),
+                    _ => Ok(BaseIOpcode::FENCE),
+                },
+                _ => Err(DecodingError::InvalidFunct3),
+            },
             0b111_0011 => match funct3 {
                 0b000 => match funct7 {
                     0b000_0000 => match funct5 {
@@ -174,7 +182,10 @@ pub mod bit_32 {
             | BaseIOpcode::SUBW
             | BaseIOpcode::SLLW
             | BaseIOpcode::SRLW
-            | BaseIOpcode::SRAW => Some(rd),
+            | BaseIOpcode::SRAW
+            | BaseIOpcode::FENCE
+            | BaseIOpcode::FENCE_TSO
+            | BaseIOpcode::PAUSE => Some(rd),
             _ => None,
         }
     }
@@ -182,7 +193,7 @@ pub mod bit_32 {
     pub fn parse_rs1(inst: u32, opkind: &BaseIOpcode) -> Option<usize> {
         let rs1: usize = inst.slice(19, 15) as usize;
 
-        // LUI, AUIPC, JAL, FENCE, ECALL, EBREAK
+        // LUI, AUIPC, JAL, ECALL, EBREAK
         match opkind {
             BaseIOpcode::JALR
             | BaseIOpcode::BEQ
@@ -229,7 +240,10 @@ pub mod bit_32 {
             | BaseIOpcode::SUBW
             | BaseIOpcode::SLLW
             | BaseIOpcode::SRLW
-            | BaseIOpcode::SRAW => Some(rs1),
+            | BaseIOpcode::SRAW
+            | BaseIOpcode::FENCE
+            | BaseIOpcode::FENCE_TSO
+            | BaseIOpcode::PAUSE => Some(rs1),
             _ => None,
         }
     }
@@ -332,6 +346,10 @@ pub mod bit_32 {
                 Isa::Rv64 => Some(shamt6() as i32),
             },
             BaseIOpcode::SLLIW | BaseIOpcode::SRLIW | BaseIOpcode::SRAIW => Some(shamt5() as i32),
+            // fm, pred and succ
+            BaseIOpcode::FENCE | BaseIOpcode::FENCE_TSO | BaseIOpcode::PAUSE => {
+                Some(inst.slice(31, 20) as i32)
+            }
             _ => None,
         }
     }
@@ -419,6 +437,39 @@ mod test_basei {
             None,
             None,
         );
+        test_32_in_rv64(
+            0x0330_000f,
+            OpcodeKind::BaseI(BaseIOpcode::FENCE),
+            Some(0),
+            Some(0),
+            None,
+            Some(0b0011_0011),
+        );
+        test_32_in_rv64(
+            0x8330_000f,
+            OpcodeKind::BaseI(BaseIOpcode::FENCE_TSO),
+            Some(0),
+            Some(0),
+            None,
+            Some(0b1000_0011_0011),
+        );
+        test_32_in_rv64(
+            0x0100_000f,
+            OpcodeKind::BaseI(BaseIOpcode::PAUSE),
+            Some(0),
+            Some(0),
+            None,
+            Some(0b0001_0000),
+        );
+        // fence w, 0 with a non-zero rd is not a pause hint.
+        test_32_in_rv64(
+            0x0100_008f,
+            OpcodeKind::BaseI(BaseIOpcode::FENCE),
+            Some(1),
+            Some(0),
+            None,
+            Some(0b0001_0000),
+        );
         test_32_in_rv64(
             0b0000_0000_0000_0101_0100_1100_0110_0011,
             OpcodeKind::BaseI(BaseIOpcode::BLT),
@@ -484,4 +535,46 @@ mod test_basei {
             None,
         );
     }
+
+    #[test]
+    fn basei_fence_test() {
+        use crate::instruction::base_i::{Fence, FenceMode, FenceSet};
+        use crate::{Decode, Isa};
+
+        let decode = |inst: u32| inst.decode(Isa::Rv64).unwrap();
+
+        assert_eq!(decode(0x0ff0_000f).to_string(), "fence iorw, iorw");
+        assert_eq!(decode(0x0310_000f).to_string(), "fence rw, w");
+        assert_eq!(decode(0x0c50_000f).to_string(), "fence io, ow");
+        assert_eq!(decode(0x0010_000f).to_string(), "fence 0, w");
+        assert_eq!(decode(0x8330_000f).to_string(), "fence.tso");
+        assert_eq!(decode(0x0100_000f).to_string(), "pause");
+        assert_eq!(decode(0x0000_100f).to_string(), "fence.i");
+
+        assert_eq!(
+            decode(0x0ff0_000f).fence(),
+            Some(Fence {
+                fm: FenceMode::Normal,
+                pred: FenceSet::new(0b1111),
+                succ: FenceSet::new(0b1111),
+            })
+        );
+        assert_eq!(
+            decode(0x8330_000f).fence(),
+            Some(Fence {
+                fm: FenceMode::Tso,
+                pred: FenceSet::new(0b0011),
+                succ: FenceSet::new(0b0011),
+            })
+        );
+        // fm = 1000 with pred != rw is a plain fence.
+        let fence = decode(0x8310_000f);
+        assert_eq!(fence.to_string(), "fence rw, w");
+        assert_eq!(fence.fence().map(|fence| fence.fm), Some(FenceMode::Tso));
+        assert_eq!(
+            decode(0x4ff0_000f).fence().map(|fence| fence.fm),
+            Some(FenceMode::Reserved(0b0100))
+        );
+        assert_eq!(decode(0x0000_100f).fence(), None);
+    }
 }
@@ -6,10 +6,18 @@ pub mod bit_16 {
     fn quadrant0(_inst: u16, opmap: u8, isa: Isa) -> Result<COpcode, DecodingError> {
         match opmap {
             0b000 => Ok(COpcode::ADDI4SPN),
+            0b001 => Ok(COpcode::FLD),
             0b010 => Ok(COpcode::LW),
-            0b011 => only_rv64(COpcode::LD, isa),
+            0b011 => match isa {
+                Isa::Rv32 => Ok(COpcode::FLW),
+                Isa::Rv64 => Ok(COpcode::LD),
+            },
+            0b101 => Ok(COpcode::FSD),
             0b110 => Ok(COpcode::SW),
-            0b111 => only_rv64(COpcode::SD, isa),
+            0b111 => match isa {
+                Isa::Rv32 => Ok(COpcode::FSW),
+                Isa::Rv64 => Ok(COpcode::SD),
+            },
             _ => Err(DecodingError::InvalidOpcode),
         }
     }
@@ -32,9 +40,19 @@ pub mod bit_16 {
             0b010 => Ok(COpcode::LI),
             0b011 => match mi_flag {
                 0b00010 => Ok(COpcode::ADDI16SP),
+                // c.lui with nzimm = 0 is reserved.
+                _ if bit_12 == 0b0 && inst.slice(6, 2) == 0 => Err(DecodingError::ReservedEncoding),
                 _ => Ok(COpcode::LUI),
             },
             0b100 => match sr_flag {
+                // shamt[5] = 1 is reserved on RV32.
+                0b00 | 0b01 if bit_12 == 0b1 => only_rv64(
+                    match sr_flag {
+                        0b00 => COpcode::SRLI,
+                        _ => COpcode::SRAI,
+                    },
+                    isa,
+                ),
                 0b00 => Ok(COpcode::SRLI),
                 0b01 => Ok(COpcode::SRAI),
                 0b10 => Ok(COpcode::ANDI),
@@ -68,9 +86,17 @@ pub mod bit_16 {
         let hi_flag: u8 = u8::try_from(inst.slice(12, 12)).unwrap();
 
         match opmap {
-            0b000 => Ok(COpcode::SLLI),
+            // shamt[5] = 1 is reserved on RV32.
+            0b000 => match hi_flag {
+                0b1 => only_rv64(COpcode::SLLI, isa),
+                _ => Ok(COpcode::SLLI),
+            },
+            0b001 => Ok(COpcode::FLDSP),
             0b010 => Ok(COpcode::LWSP),
-            0b011 => only_rv64(COpcode::LDSP, isa),
+            0b011 => match isa {
+                Isa::Rv32 => Ok(COpcode::FLWSP),
+                Isa::Rv64 => Ok(COpcode::LDSP),
+            },
             0b100 => match hi_flag {
                 0b0 => match lo_flag {
                     0b0 => Ok(COpcode::JR),
@@ -85,8 +111,12 @@ pub mod bit_16 {
                 },
                 _ => Err(DecodingError::InvalidOpcode),
             },
+            0b101 => Ok(COpcode::FSDSP),
             0b110 => Ok(COpcode::SWSP),
-            0b111 => only_rv64(COpcode::SDSP, isa),
+            0b111 => match isa {
+                Isa::Rv32 => Ok(COpcode::FSWSP),
+                Isa::Rv64 => Ok(COpcode::SDSP),
+            },
             _ => Err(DecodingError::InvalidOpcode),
         }
     }
@@ -116,7 +146,9 @@ pub mod bit_16 {
 
         match opkind {
             // Quadrant 0
-            COpcode::ADDI4SPN | COpcode::LW | COpcode::LD => Some(q0_rd),
+            COpcode::ADDI4SPN | COpcode::LW | COpcode::LD | COpcode::FLD | COpcode::FLW => {
+                Some(q0_rd)
+            }
             // Quadrant 1
             COpcode::SRLI
             | COpcode::SRAI
@@ -131,9 +163,13 @@ pub mod bit_16 {
                 Some(q1_wide_rd)
             }
             // Quadrant 2
-            COpcode::SLLI | COpcode::LWSP | COpcode::LDSP | COpcode::MV | COpcode::ADD => {
-                Some(q2_rd)
-            }
+            COpcode::SLLI
+            | COpcode::LWSP
+            | COpcode::LDSP
+            | COpcode::FLDSP
+            | COpcode::FLWSP
+            | COpcode::MV
+            | COpcode::ADD => Some(q2_rd),
             _ => None,
         }
     }
@@ -147,7 +183,14 @@ pub mod bit_16 {
 
         match opkind {
             // Quadrant 0
-            COpcode::LW | COpcode::LD | COpcode::SW | COpcode::SD => Some(q0_rs1),
+            COpcode::LW
+            | COpcode::LD
+            | COpcode::SW
+            | COpcode::SD
+            | COpcode::FLD
+            | COpcode::FSD
+            | COpcode::FLW
+            | COpcode::FSW => Some(q0_rs1),
             // Quadrant 1
             COpcode::ADDI | COpcode::ADDIW | COpcode::ADDI16SP => Some(q1_addi_rs1),
             COpcode::SRLI
@@ -175,7 +218,7 @@ pub mod bit_16 {
 
         match opkind {
             // Quadrant 0
-            COpcode::SW | COpcode::SD => Some(q0_rs2),
+            COpcode::SW | COpcode::SD | COpcode::FSD | COpcode::FSW => Some(q0_rs2),
             // Quadrant 1
             COpcode::SUB
             | COpcode::XOR
@@ -189,7 +232,9 @@ pub mod bit_16 {
             | COpcode::MV
             | COpcode::ADD
             | COpcode::SWSP
-            | COpcode::SDSP => Some(q2_rs2),
+            | COpcode::SDSP
+            | COpcode::FSDSP
+            | COpcode::FSWSP => Some(q2_rs2),
             _ => None,
         }
     }
@@ -242,8 +287,8 @@ pub mod bit_16 {
         match opkind {
             // Quadrant0
             COpcode::ADDI4SPN => Some(q0_nzuimm() as i32),
-            COpcode::LW | COpcode::SW => Some(q0_uimm() as i32),
-            COpcode::LD | COpcode::SD => Some(q0_uimm_64() as i32),
+            COpcode::LW | COpcode::SW | COpcode::FLW | COpcode::FSW => Some(q0_uimm() as i32),
+            COpcode::LD | COpcode::SD | COpcode::FLD | COpcode::FSD => Some(q0_uimm_64() as i32),
             // Quadrant1
             COpcode::ADDIW | COpcode::LI | COpcode::ANDI => Some(q1_imm()),
             COpcode::NOP | COpcode::ADDI => Some(q1_nzimm()),
@@ -254,10 +299,10 @@ pub mod bit_16 {
             COpcode::ADDI16SP => Some(q1_16sp_nzimm()),
             // Quadrant2
             COpcode::SLLI => Some(q2_imm()),
-            COpcode::LWSP => Some(q2_lwsp_imm()),
-            COpcode::LDSP => Some(q2_ldsp_imm()),
-            COpcode::SWSP => Some(q2_swsp_imm()),
-            COpcode::SDSP => Some(q2_sdsp_imm()),
+            COpcode::LWSP | COpcode::FLWSP => Some(q2_lwsp_imm()),
+            COpcode::LDSP | COpcode::FLDSP => Some(q2_ldsp_imm()),
+            COpcode::SWSP | COpcode::FSWSP => Some(q2_swsp_imm()),
+            COpcode::SDSP | COpcode::FSDSP => Some(q2_sdsp_imm()),
             _ => None,
         }
     }
@@ -267,10 +312,11 @@ pub mod bit_16 {
 #[allow(unused_variables)]
 mod test_c {
     #[test]
+    #[allow(clippy::too_many_lines)]
     fn c_decode_test() {
-        use crate::decode::inst_16::test_16_in_rv64;
+        use crate::decode::inst_16::{test_16_in_rv32, test_16_in_rv64};
         use crate::instruction::c_extension::COpcode;
-        use crate::OpcodeKind;
+        use crate::{Decode, DecodingError, Isa, OpcodeKind};
 
         test_16_in_rv64(
             0b0000_0000_0000_0001,
@@ -281,12 +327,12 @@ mod test_c {
             Some(0),
         );
         test_16_in_rv64(
-            0b0110_0011_1000_0001,
+            0b0110_0011_1000_0101,
             OpcodeKind::C(COpcode::LUI),
             Some(7),
             None,
             None,
-            Some(0),
+            Some(4096),
         );
         test_16_in_rv64(
             0b1000_0010_1100_0001,
@@ -368,5 +414,63 @@ mod test_c {
             None,
             Some(1),
         );
+        test_16_in_rv64(
+            0x2588,
+            OpcodeKind::C(COpcode::FLD),
+            Some(10),
+            Some(11),
+            None,
+            Some(8),
+        );
+        test_16_in_rv64(
+            0xa422,
+            OpcodeKind::C(COpcode::FSDSP),
+            None,
+            None,
+            Some(8),
+            Some(8),
+        );
+        test_16_in_rv32(
+            0x61c8,
+            OpcodeKind::C(COpcode::FLW),
+            Some(10),
+            Some(11),
+            None,
+            Some(4),
+        );
+        test_16_in_rv32(
+            0x70fe,
+            OpcodeKind::C(COpcode::FLWSP),
+            Some(1),
+            None,
+            None,
+            Some(252),
+        );
+        // c.flw and c.ld share the same encoding.
+        test_16_in_rv64(
+            0x61c8,
+            OpcodeKind::C(COpcode::LD),
+            Some(10),
+            Some(11),
+            None,
+            Some(128),
+        );
+
+        // shamt[5] = 1 is reserved on RV32 (c.slli a0, 32 / c.srli a0, 32).
+        assert_eq!(
+            0x1502_u16.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+        assert_eq!(
+            0x9101_u16.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+        assert!(0x1502_u16.decode(Isa::Rv64).is_ok());
+
+        // c.lui a0, 0 (nzimm = 0) is reserved.
+        assert_eq!(
+            0x6501_u16.decode(Isa::Rv64),
+            Err(DecodingError::ReservedEncoding)
+        );
     }
 }
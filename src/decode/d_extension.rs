@@ -0,0 +1,307 @@
+pub mod bit_32 {
+    use super::super::{only_rv64, DecodeUtil, DecodingError};
+    use crate::instruction::{d_extension::DOpcode, f_extension::RoundingMode};
+    use crate::Isa;
+
+    pub fn parse_opcode(inst: u32, isa: Isa) -> Result<DOpcode, DecodingError> {
+        let opmap: u8 = u8::try_from(inst.slice(6, 0)).unwrap();
+        let funct3: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
+        let funct5: u8 = u8::try_from(inst.slice(24, 20)).unwrap();
+        let funct7: u8 = u8::try_from(inst.slice(31, 25)).unwrap();
+        let fmt: u8 = u8::try_from(inst.slice(26, 25)).unwrap();
+        let is_valid_rm = matches!(funct3, 0b000..=0b100 | 0b111);
+
+        match opmap {
+            0b000_0111 => match funct3 {
+                0b011 => Ok(DOpcode::FLD),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            0b010_0111 => match funct3 {
+                0b011 => Ok(DOpcode::FSD),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            0b100_0011 | 0b100_0111 | 0b100_1011 | 0b100_1111 if !is_valid_rm => {
+                Err(DecodingError::InvalidFunct3)
+            }
+            0b100_0011 | 0b100_0111 | 0b100_1011 | 0b100_1111 if fmt != 0b01 => {
+                Err(DecodingError::InvalidFunct7)
+            }
+            0b100_0011 => Ok(DOpcode::FMADD_D),
+            0b100_0111 => Ok(DOpcode::FMSUB_D),
+            0b100_1011 => Ok(DOpcode::FNMSUB_D),
+            0b100_1111 => Ok(DOpcode::FNMADD_D),
+            0b101_0011 => match funct7 {
+                0b000_0001 | 0b000_0101 | 0b000_1001 | 0b000_1101 | 0b010_1101 | 0b010_0000
+                | 0b010_0001 | 0b110_0001 | 0b110_1001
+                    if !is_valid_rm =>
+                {
+                    Err(DecodingError::InvalidFunct3)
+                }
+                0b000_0001 => Ok(DOpcode::FADD_D),
+                0b000_0101 => Ok(DOpcode::FSUB_D),
+                0b000_1001 => Ok(DOpcode::FMUL_D),
+                0b000_1101 => Ok(DOpcode::FDIV_D),
+                0b010_1101 => match funct5 {
+                    0b00000 => Ok(DOpcode::FSQRT_D),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b001_0001 => match funct3 {
+                    0b000 => Ok(DOpcode::FSGNJ_D),
+                    0b001 => Ok(DOpcode::FSGNJN_D),
+                    0b010 => Ok(DOpcode::FSGNJX_D),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b001_0101 => match funct3 {
+                    0b000 => Ok(DOpcode::FMIN_D),
+                    0b001 => Ok(DOpcode::FMAX_D),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b010_0000 => match funct5 {
+                    0b00001 => Ok(DOpcode::FCVT_S_D),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b010_0001 => match funct5 {
+                    0b00000 => Ok(DOpcode::FCVT_D_S),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b101_0001 => match funct3 {
+                    0b010 => Ok(DOpcode::FEQ_D),
+                    0b001 => Ok(DOpcode::FLT_D),
+                    0b000 => Ok(DOpcode::FLE_D),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b111_0001 => match (funct5, funct3) {
+                    (0b00000, 0b000) => only_rv64(DOpcode::FMV_X_D, isa),
+                    (0b00000, 0b001) => Ok(DOpcode::FCLASS_D),
+                    (0b00000, _) => Err(DecodingError::InvalidFunct3),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b110_0001 => match funct5 {
+                    0b00000 => Ok(DOpcode::FCVT_W_D),
+                    0b00001 => Ok(DOpcode::FCVT_WU_D),
+                    0b00010 => only_rv64(DOpcode::FCVT_L_D, isa),
+                    0b00011 => only_rv64(DOpcode::FCVT_LU_D, isa),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b110_1001 => match funct5 {
+                    0b00000 => Ok(DOpcode::FCVT_D_W),
+                    0b00001 => Ok(DOpcode::FCVT_D_WU),
+                    0b00010 => only_rv64(DOpcode::FCVT_D_L, isa),
+                    0b00011 => only_rv64(DOpcode::FCVT_D_LU, isa),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b111_1001 => match (funct5, funct3) {
+                    (0b00000, 0b000) => only_rv64(DOpcode::FMV_D_X, isa),
+                    (0b00000, _) => Err(DecodingError::InvalidFunct3),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                _ => Err(DecodingError::InvalidFunct7),
+            },
+            _ => Err(DecodingError::InvalidOpcode),
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn parse_rd(inst: u32, opkind: &DOpcode) -> Option<usize> {
+        let rd: usize = inst.slice(11, 7) as usize;
+
+        match opkind {
+            DOpcode::FSD => None,
+            _ => Some(rd),
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn parse_rs1(inst: u32, _opkind: &DOpcode) -> Option<usize> {
+        let rs1: usize = inst.slice(19, 15) as usize;
+
+        Some(rs1)
+    }
+
+    pub fn parse_rs2(inst: u32, opkind: &DOpcode) -> Option<usize> {
+        let rs2: usize = inst.slice(24, 20) as usize;
+
+        match opkind {
+            DOpcode::FSD
+            | DOpcode::FMADD_D
+            | DOpcode::FMSUB_D
+            | DOpcode::FNMSUB_D
+            | DOpcode::FNMADD_D
+            | DOpcode::FADD_D
+            | DOpcode::FSUB_D
+            | DOpcode::FMUL_D
+            | DOpcode::FDIV_D
+            | DOpcode::FSGNJ_D
+            | DOpcode::FSGNJN_D
+            | DOpcode::FSGNJX_D
+            | DOpcode::FMIN_D
+            | DOpcode::FMAX_D
+            | DOpcode::FEQ_D
+            | DOpcode::FLT_D
+            | DOpcode::FLE_D => Some(rs2),
+            _ => None,
+        }
+    }
+
+    pub fn parse_rs3(inst: u32, opkind: &DOpcode) -> Option<usize> {
+        let rs3: usize = inst.slice(31, 27) as usize;
+
+        match opkind {
+            DOpcode::FMADD_D | DOpcode::FMSUB_D | DOpcode::FNMSUB_D | DOpcode::FNMADD_D => {
+                Some(rs3)
+            }
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(non_snake_case)]
+    pub fn parse_imm(inst: u32, opkind: &DOpcode) -> Option<i32> {
+        let I_type = || {
+            let imm32 = inst.slice(31, 20) as i32;
+            inst.to_signed_nbit(imm32, 12)
+        };
+        let S_type = || {
+            let imm32 = (inst.slice(11, 7).set(&[4, 3, 2, 1, 0])
+                | inst.slice(31, 25).set(&[11, 10, 9, 8, 7, 6, 5])) as i32;
+            inst.to_signed_nbit(imm32, 12)
+        };
+
+        match opkind {
+            DOpcode::FLD => Some(I_type()),
+            DOpcode::FSD => Some(S_type()),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn parse_rm(inst: u32, opkind: &DOpcode) -> Option<RoundingMode> {
+        match opkind {
+            DOpcode::FMADD_D
+            | DOpcode::FMSUB_D
+            | DOpcode::FNMSUB_D
+            | DOpcode::FNMADD_D
+            | DOpcode::FADD_D
+            | DOpcode::FSUB_D
+            | DOpcode::FMUL_D
+            | DOpcode::FDIV_D
+            | DOpcode::FSQRT_D
+            | DOpcode::FCVT_S_D
+            | DOpcode::FCVT_D_S
+            | DOpcode::FCVT_W_D
+            | DOpcode::FCVT_WU_D
+            | DOpcode::FCVT_D_W
+            | DOpcode::FCVT_D_WU
+            | DOpcode::FCVT_L_D
+            | DOpcode::FCVT_LU_D
+            | DOpcode::FCVT_D_L
+            | DOpcode::FCVT_D_LU => RoundingMode::from_rm(inst.slice(14, 12) as i32),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_variables)]
+mod test_d {
+    #[test]
+    #[allow(overflowing_literals)]
+    fn d_decode_test() {
+        use crate::decode::inst_32::{test_32_in_rv32, test_32_in_rv64};
+        use crate::instruction::{d_extension::DOpcode, f_extension::RoundingMode};
+        use crate::{Decode, DecodingError, Isa, OpcodeKind};
+
+        test_32_in_rv64(
+            0x0085_3507,
+            OpcodeKind::D(DOpcode::FLD),
+            Some(10),
+            Some(10),
+            None,
+            Some(8),
+        );
+        test_32_in_rv32(
+            0xfea1_3c27,
+            OpcodeKind::D(DOpcode::FSD),
+            None,
+            Some(2),
+            Some(10),
+            Some(-8),
+        );
+        test_32_in_rv64(
+            0x02b5_7553,
+            OpcodeKind::D(DOpcode::FADD_D),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv64(
+            0x62b5_1543,
+            OpcodeKind::D(DOpcode::FMADD_D),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        assert_eq!(
+            0x62b5_1543_u32.decode(Isa::Rv64).unwrap().rm,
+            Some(RoundingMode::RTZ)
+        );
+        assert_eq!(
+            0x4205_8553_u32.decode(Isa::Rv64).unwrap().rm,
+            Some(RoundingMode::RNE)
+        );
+        // fcvt.s.d has fmt = S, but it belongs to D.
+        test_32_in_rv32(
+            0x4015_f553,
+            OpcodeKind::D(DOpcode::FCVT_S_D),
+            Some(10),
+            Some(11),
+            None,
+            None,
+        );
+        test_32_in_rv32(
+            0x4205_8553,
+            OpcodeKind::D(DOpcode::FCVT_D_S),
+            Some(10),
+            Some(11),
+            None,
+            None,
+        );
+        test_32_in_rv32(
+            0xa2b5_2553,
+            OpcodeKind::D(DOpcode::FEQ_D),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv64(
+            0xe205_0553,
+            OpcodeKind::D(DOpcode::FMV_X_D),
+            Some(10),
+            Some(10),
+            None,
+            None,
+        );
+
+        // fmv.x.d, fmv.d.x and fcvt.l.d are only for Rv64.
+        assert_eq!(
+            0xe205_0553_u32.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+        assert_eq!(
+            0xf205_0553_u32.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+        assert_eq!(
+            0xc225_7553_u32.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+        // fcvt.s.s does not exist.
+        assert_eq!(
+            0x4005_f553_u32.decode(Isa::Rv64),
+            Err(DecodingError::UnknownExtension)
+        );
+    }
+}
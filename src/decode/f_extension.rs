@@ -0,0 +1,289 @@
+pub mod bit_32 {
+    use super::super::{only_rv64, DecodeUtil, DecodingError};
+    use crate::instruction::f_extension::{FOpcode, RoundingMode};
+    use crate::Isa;
+
+    pub fn parse_opcode(inst: u32, isa: Isa) -> Result<FOpcode, DecodingError> {
+        let opmap: u8 = u8::try_from(inst.slice(6, 0)).unwrap();
+        let funct3: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
+        let funct5: u8 = u8::try_from(inst.slice(24, 20)).unwrap();
+        let funct7: u8 = u8::try_from(inst.slice(31, 25)).unwrap();
+        let fmt: u8 = u8::try_from(inst.slice(26, 25)).unwrap();
+        let is_valid_rm = matches!(funct3, 0b000..=0b100 | 0b111);
+
+        match opmap {
+            0b000_0111 => match funct3 {
+                0b010 => Ok(FOpcode::FLW),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            0b010_0111 => match funct3 {
+                0b010 => Ok(FOpcode::FSW),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            0b100_0011 | 0b100_0111 | 0b100_1011 | 0b100_1111 if !is_valid_rm => {
+                Err(DecodingError::InvalidFunct3)
+            }
+            0b100_0011 | 0b100_0111 | 0b100_1011 | 0b100_1111 if fmt != 0b00 => {
+                Err(DecodingError::InvalidFunct7)
+            }
+            0b100_0011 => Ok(FOpcode::FMADD_S),
+            0b100_0111 => Ok(FOpcode::FMSUB_S),
+            0b100_1011 => Ok(FOpcode::FNMSUB_S),
+            0b100_1111 => Ok(FOpcode::FNMADD_S),
+            0b101_0011 => match funct7 {
+                0b000_0000 | 0b000_0100 | 0b000_1000 | 0b000_1100 | 0b010_1100 | 0b110_0000
+                | 0b110_1000
+                    if !is_valid_rm =>
+                {
+                    Err(DecodingError::InvalidFunct3)
+                }
+                0b000_0000 => Ok(FOpcode::FADD_S),
+                0b000_0100 => Ok(FOpcode::FSUB_S),
+                0b000_1000 => Ok(FOpcode::FMUL_S),
+                0b000_1100 => Ok(FOpcode::FDIV_S),
+                0b010_1100 => match funct5 {
+                    0b00000 => Ok(FOpcode::FSQRT_S),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b001_0000 => match funct3 {
+                    0b000 => Ok(FOpcode::FSGNJ_S),
+                    0b001 => Ok(FOpcode::FSGNJN_S),
+                    0b010 => Ok(FOpcode::FSGNJX_S),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b001_0100 => match funct3 {
+                    0b000 => Ok(FOpcode::FMIN_S),
+                    0b001 => Ok(FOpcode::FMAX_S),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b110_0000 => match funct5 {
+                    0b00000 => Ok(FOpcode::FCVT_W_S),
+                    0b00001 => Ok(FOpcode::FCVT_WU_S),
+                    0b00010 => only_rv64(FOpcode::FCVT_L_S, isa),
+                    0b00011 => only_rv64(FOpcode::FCVT_LU_S, isa),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b111_0000 => match (funct5, funct3) {
+                    (0b00000, 0b000) => Ok(FOpcode::FMV_X_W),
+                    (0b00000, 0b001) => Ok(FOpcode::FCLASS_S),
+                    (0b00000, _) => Err(DecodingError::InvalidFunct3),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b101_0000 => match funct3 {
+                    0b010 => Ok(FOpcode::FEQ_S),
+                    0b001 => Ok(FOpcode::FLT_S),
+                    0b000 => Ok(FOpcode::FLE_S),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b110_1000 => match funct5 {
+                    0b00000 => Ok(FOpcode::FCVT_S_W),
+                    0b00001 => Ok(FOpcode::FCVT_S_WU),
+                    0b00010 => only_rv64(FOpcode::FCVT_S_L, isa),
+                    0b00011 => only_rv64(FOpcode::FCVT_S_LU, isa),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b111_1000 => match (funct5, funct3) {
+                    (0b00000, 0b000) => Ok(FOpcode::FMV_W_X),
+                    (0b00000, _) => Err(DecodingError::InvalidFunct3),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                _ => Err(DecodingError::InvalidFunct7),
+            },
+            _ => Err(DecodingError::InvalidOpcode),
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn parse_rd(inst: u32, opkind: &FOpcode) -> Option<usize> {
+        let rd: usize = inst.slice(11, 7) as usize;
+
+        match opkind {
+            FOpcode::FSW => None,
+            _ => Some(rd),
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn parse_rs1(inst: u32, _opkind: &FOpcode) -> Option<usize> {
+        let rs1: usize = inst.slice(19, 15) as usize;
+
+        Some(rs1)
+    }
+
+    pub fn parse_rs2(inst: u32, opkind: &FOpcode) -> Option<usize> {
+        let rs2: usize = inst.slice(24, 20) as usize;
+
+        match opkind {
+            FOpcode::FSW
+            | FOpcode::FMADD_S
+            | FOpcode::FMSUB_S
+            | FOpcode::FNMSUB_S
+            | FOpcode::FNMADD_S
+            | FOpcode::FADD_S
+            | FOpcode::FSUB_S
+            | FOpcode::FMUL_S
+            | FOpcode::FDIV_S
+            | FOpcode::FSGNJ_S
+            | FOpcode::FSGNJN_S
+            | FOpcode::FSGNJX_S
+            | FOpcode::FMIN_S
+            | FOpcode::FMAX_S
+            | FOpcode::FEQ_S
+            | FOpcode::FLT_S
+            | FOpcode::FLE_S => Some(rs2),
+            _ => None,
+        }
+    }
+
+    pub fn parse_rs3(inst: u32, opkind: &FOpcode) -> Option<usize> {
+        let rs3: usize = inst.slice(31, 27) as usize;
+
+        match opkind {
+            FOpcode::FMADD_S | FOpcode::FMSUB_S | FOpcode::FNMSUB_S | FOpcode::FNMADD_S => {
+                Some(rs3)
+            }
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(non_snake_case)]
+    pub fn parse_imm(inst: u32, opkind: &FOpcode) -> Option<i32> {
+        let I_type = || {
+            let imm32 = inst.slice(31, 20) as i32;
+            inst.to_signed_nbit(imm32, 12)
+        };
+        let S_type = || {
+            let imm32 = (inst.slice(11, 7).set(&[4, 3, 2, 1, 0])
+                | inst.slice(31, 25).set(&[11, 10, 9, 8, 7, 6, 5])) as i32;
+            inst.to_signed_nbit(imm32, 12)
+        };
+
+        match opkind {
+            FOpcode::FLW => Some(I_type()),
+            FOpcode::FSW => Some(S_type()),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn parse_rm(inst: u32, opkind: &FOpcode) -> Option<RoundingMode> {
+        match opkind {
+            FOpcode::FMADD_S
+            | FOpcode::FMSUB_S
+            | FOpcode::FNMSUB_S
+            | FOpcode::FNMADD_S
+            | FOpcode::FADD_S
+            | FOpcode::FSUB_S
+            | FOpcode::FMUL_S
+            | FOpcode::FDIV_S
+            | FOpcode::FSQRT_S
+            | FOpcode::FCVT_W_S
+            | FOpcode::FCVT_WU_S
+            | FOpcode::FCVT_S_W
+            | FOpcode::FCVT_S_WU
+            | FOpcode::FCVT_L_S
+            | FOpcode::FCVT_LU_S
+            | FOpcode::FCVT_S_L
+            | FOpcode::FCVT_S_LU => RoundingMode::from_rm(inst.slice(14, 12) as i32),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_variables)]
+mod test_f {
+    #[test]
+    #[allow(overflowing_literals)]
+    fn f_decode_test() {
+        use crate::decode::inst_32::{test_32_in_rv32, test_32_in_rv64};
+        use crate::instruction::f_extension::{FOpcode, RoundingMode};
+        use crate::{Decode, DecodingError, Isa, OpcodeKind};
+
+        test_32_in_rv64(
+            0x0085_2507,
+            OpcodeKind::F(FOpcode::FLW),
+            Some(10),
+            Some(10),
+            None,
+            Some(8),
+        );
+        test_32_in_rv64(
+            0xfea5_ae27,
+            OpcodeKind::F(FOpcode::FSW),
+            None,
+            Some(11),
+            Some(10),
+            Some(-4),
+        );
+        test_32_in_rv64(
+            0x00b5_7553,
+            OpcodeKind::F(FOpcode::FADD_S),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv64(
+            0xc005_1553,
+            OpcodeKind::F(FOpcode::FCVT_W_S),
+            Some(10),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0xa0b5_2553,
+            OpcodeKind::F(FOpcode::FEQ_S),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv64(
+            0xe005_1553,
+            OpcodeKind::F(FOpcode::FCLASS_S),
+            Some(10),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv32(
+            0xf005_0553,
+            OpcodeKind::F(FOpcode::FMV_W_X),
+            Some(10),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x60b5_7543,
+            OpcodeKind::F(FOpcode::FMADD_S),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        assert_eq!(0x60b5_7543_u32.decode(Isa::Rv64).unwrap().rs3, Some(12));
+        assert_eq!(
+            0xc005_1553_u32.decode(Isa::Rv64).unwrap().rm,
+            Some(RoundingMode::RTZ)
+        );
+        assert_eq!(
+            0x60b5_7543_u32.decode(Isa::Rv64).unwrap().rm,
+            Some(RoundingMode::DYN)
+        );
+
+        // fcvt.l.s is only for Rv64.
+        assert_eq!(
+            0xc025_1553_u32.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+        // rm = 0b101 is reserved.
+        assert_eq!(
+            0x00b5_d553_u32.decode(Isa::Rv64),
+            Err(DecodingError::InvalidFunct3)
+        );
+    }
+}
@@ -1,7 +1,7 @@
 use super::{c_extension, zicfiss_extension};
 use super::{Decode, DecodeUtil, DecodingError};
 
-use crate::instruction::{InstFormat, Instruction, OpcodeKind};
+use crate::instruction::{f_extension::RoundingMode, InstFormat, Instruction, OpcodeKind};
 use crate::{Extensions, Isa};
 
 impl Decode for u16 {
@@ -14,7 +14,10 @@ impl Decode for u16 {
         let new_rd: Option<usize> = self.parse_rd(&new_opc)?;
         let new_rs1: Option<usize> = self.parse_rs1(&new_opc)?;
         let new_rs2: Option<usize> = self.parse_rs2(&new_opc)?;
+        let new_rs3: Option<usize> = self.parse_rs3(&new_opc)?;
         let new_imm: Option<i32> = self.parse_imm(&new_opc, isa)?;
+        let new_vm: Option<bool> = self.parse_vm(&new_opc)?;
+        let new_rounding_mode: Option<RoundingMode> = self.parse_rm(&new_opc)?;
         let new_fmt: InstFormat = new_opc.get_format();
 
         Ok(Instruction {
@@ -22,7 +25,10 @@ impl Decode for u16 {
             rd: new_rd,
             rs1: new_rs1,
             rs2: new_rs2,
+            rs3: new_rs3,
             imm: new_imm,
+            vm: new_vm,
+            rm: new_rounding_mode,
             inst_format: new_fmt,
             is_compressed: true,
         })
@@ -64,6 +70,13 @@ impl Decode for u16 {
         }
     }
 
+    fn parse_rs3(self, opkind: &OpcodeKind) -> Result<Option<usize>, DecodingError> {
+        match opkind {
+            OpcodeKind::C(_) | OpcodeKind::Zicfiss(_) => Ok(None),
+            _ => Err(DecodingError::Not16BitInst),
+        }
+    }
+
     fn parse_imm(self, opkind: &OpcodeKind, _isa: Isa) -> Result<Option<i32>, DecodingError> {
         match opkind {
             OpcodeKind::C(opc) => Ok(c_extension::bit_16::parse_imm(self, opc)),
@@ -71,6 +84,20 @@ impl Decode for u16 {
             _ => Err(DecodingError::Not16BitInst),
         }
     }
+
+    fn parse_vm(self, opkind: &OpcodeKind) -> Result<Option<bool>, DecodingError> {
+        match opkind {
+            OpcodeKind::C(_) | OpcodeKind::Zicfiss(_) => Ok(None),
+            _ => Err(DecodingError::Not16BitInst),
+        }
+    }
+
+    fn parse_rm(self, opkind: &OpcodeKind) -> Result<Option<RoundingMode>, DecodingError> {
+        match opkind {
+            OpcodeKind::C(_) | OpcodeKind::Zicfiss(_) => Ok(None),
+            _ => Err(DecodingError::Not16BitInst),
+        }
+    }
 }
 
 impl DecodeUtil for u16 {
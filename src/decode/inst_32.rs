@@ -1,9 +1,10 @@
 use super::{
-    a_extension, base_i, m_extension, priv_extension, zicboz_extension, zicfiss_extension,
-    zicntr_extension, zicsr_extension, zifencei_extension,
+    a_extension, b_extension, base_i, d_extension, f_extension, m_extension, priv_extension,
+    q_extension, v_extension, zicboz_extension, zicfiss_extension, zicntr_extension,
+    zicsr_extension, zifencei_extension,
 };
 use super::{Decode, DecodeUtil, DecodingError};
-use crate::instruction::{InstFormat, Instruction, OpcodeKind};
+use crate::instruction::{f_extension::RoundingMode, InstFormat, Instruction, OpcodeKind};
 use crate::{Extensions, Isa};
 
 #[allow(non_snake_case)]
@@ -13,7 +14,10 @@ impl Decode for u32 {
         let new_rd: Option<usize> = self.parse_rd(&new_opc)?;
         let new_rs1: Option<usize> = self.parse_rs1(&new_opc)?;
         let new_rs2: Option<usize> = self.parse_rs2(&new_opc)?;
+        let new_rs3: Option<usize> = self.parse_rs3(&new_opc)?;
         let new_imm: Option<i32> = self.parse_imm(&new_opc, isa)?;
+        let new_vm: Option<bool> = self.parse_vm(&new_opc)?;
+        let new_rounding_mode: Option<RoundingMode> = self.parse_rm(&new_opc)?;
         let new_fmt: InstFormat = new_opc.get_format();
 
         Ok(Instruction {
@@ -21,7 +25,10 @@ impl Decode for u32 {
             rd: new_rd,
             rs1: new_rs1,
             rs2: new_rs2,
+            rs3: new_rs3,
             imm: new_imm,
+            vm: new_vm,
+            rm: new_rounding_mode,
             inst_format: new_fmt,
             is_compressed: false,
         })
@@ -36,6 +43,17 @@ impl Decode for u32 {
             }
             Ok(Extensions::M) => Ok(OpcodeKind::M(m_extension::bit_32::parse_opcode(self, isa)?)),
             Ok(Extensions::A) => Ok(OpcodeKind::A(a_extension::bit_32::parse_opcode(self, isa)?)),
+            Ok(
+                Extensions::B
+                | Extensions::Zba
+                | Extensions::Zbb
+                | Extensions::Zbc
+                | Extensions::Zbs,
+            ) => Ok(OpcodeKind::B(b_extension::bit_32::parse_opcode(self, isa)?)),
+            Ok(Extensions::F) => Ok(OpcodeKind::F(f_extension::bit_32::parse_opcode(self, isa)?)),
+            Ok(Extensions::D) => Ok(OpcodeKind::D(d_extension::bit_32::parse_opcode(self, isa)?)),
+            Ok(Extensions::Q) => Ok(OpcodeKind::Q(q_extension::bit_32::parse_opcode(self, isa)?)),
+            Ok(Extensions::V) => Ok(OpcodeKind::V(v_extension::bit_32::parse_opcode(self)?)),
             Ok(Extensions::Zifencei) => Ok(OpcodeKind::Zifencei(
                 zifencei_extension::bit_32::parse_opcode(self)?,
             )),
@@ -51,9 +69,9 @@ impl Decode for u32 {
             Ok(Extensions::Zicboz) => Ok(OpcodeKind::Zicboz(
                 zicboz_extension::bit_32::parse_opcode(self)?,
             )),
-            Ok(Extensions::Priv) => Ok(OpcodeKind::Priv(priv_extension::bit_32::parse_opcode(
-                self,
-            )?)),
+            Ok(Extensions::Priv | Extensions::H) => Ok(OpcodeKind::Priv(
+                priv_extension::bit_32::parse_opcode(self, isa)?,
+            )),
             Ok(Extensions::C) => Err(DecodingError::Not32BitInst),
             Err(decoding_err) => Err(decoding_err),
         }
@@ -64,6 +82,11 @@ impl Decode for u32 {
             OpcodeKind::BaseI(opc) => Ok(base_i::bit_32::parse_rd(self, opc)),
             OpcodeKind::M(opc) => Ok(m_extension::bit_32::parse_rd(self, opc)),
             OpcodeKind::A(opc) => Ok(a_extension::bit_32::parse_rd(self, opc)),
+            OpcodeKind::B(opc) => Ok(b_extension::bit_32::parse_rd(self, opc)),
+            OpcodeKind::F(opc) => Ok(f_extension::bit_32::parse_rd(self, opc)),
+            OpcodeKind::D(opc) => Ok(d_extension::bit_32::parse_rd(self, opc)),
+            OpcodeKind::Q(opc) => Ok(q_extension::bit_32::parse_rd(self, opc)),
+            OpcodeKind::V(opc) => Ok(v_extension::bit_32::parse_rd(self, opc)),
             OpcodeKind::Zifencei(opc) => Ok(zifencei_extension::bit_32::parse_rd(self, opc)),
             OpcodeKind::Zicsr(opc) => Ok(zicsr_extension::bit_32::parse_rd(self, opc)),
             OpcodeKind::Zicfiss(opc) => Ok(zicfiss_extension::bit_32::parse_rd(self, opc)),
@@ -79,6 +102,11 @@ impl Decode for u32 {
             OpcodeKind::BaseI(opc) => Ok(base_i::bit_32::parse_rs1(self, opc)),
             OpcodeKind::M(opc) => Ok(m_extension::bit_32::parse_rs1(self, opc)),
             OpcodeKind::A(opc) => Ok(a_extension::bit_32::parse_rs1(self, opc)),
+            OpcodeKind::B(opc) => Ok(b_extension::bit_32::parse_rs1(self, opc)),
+            OpcodeKind::F(opc) => Ok(f_extension::bit_32::parse_rs1(self, opc)),
+            OpcodeKind::D(opc) => Ok(d_extension::bit_32::parse_rs1(self, opc)),
+            OpcodeKind::Q(opc) => Ok(q_extension::bit_32::parse_rs1(self, opc)),
+            OpcodeKind::V(opc) => Ok(v_extension::bit_32::parse_rs1(self, opc)),
             OpcodeKind::Zifencei(opc) => Ok(zifencei_extension::bit_32::parse_rs1(self, opc)),
             OpcodeKind::Zicsr(opc) => Ok(zicsr_extension::bit_32::parse_rs1(self, opc)),
             OpcodeKind::Zicfiss(opc) => Ok(zicfiss_extension::bit_32::parse_rs1(self, opc)),
@@ -94,6 +122,11 @@ impl Decode for u32 {
             OpcodeKind::BaseI(opc) => Ok(base_i::bit_32::parse_rs2(self, opc)),
             OpcodeKind::M(opc) => Ok(m_extension::bit_32::parse_rs2(self, opc)),
             OpcodeKind::A(opc) => Ok(a_extension::bit_32::parse_rs2(self, opc)),
+            OpcodeKind::B(opc) => Ok(b_extension::bit_32::parse_rs2(self, opc)),
+            OpcodeKind::F(opc) => Ok(f_extension::bit_32::parse_rs2(self, opc)),
+            OpcodeKind::D(opc) => Ok(d_extension::bit_32::parse_rs2(self, opc)),
+            OpcodeKind::Q(opc) => Ok(q_extension::bit_32::parse_rs2(self, opc)),
+            OpcodeKind::V(opc) => Ok(v_extension::bit_32::parse_rs2(self, opc)),
             OpcodeKind::Zifencei(opc) => Ok(zifencei_extension::bit_32::parse_rs2(self, opc)),
             OpcodeKind::Zicsr(opc) => Ok(zicsr_extension::bit_32::parse_rs2(self, opc)),
             OpcodeKind::Zicfiss(opc) => Ok(zicfiss_extension::bit_32::parse_rs2(self, opc)),
@@ -104,11 +137,27 @@ impl Decode for u32 {
         }
     }
 
+    fn parse_rs3(self, opkind: &OpcodeKind) -> Result<Option<usize>, DecodingError> {
+        match opkind {
+            OpcodeKind::F(opc) => Ok(f_extension::bit_32::parse_rs3(self, opc)),
+            OpcodeKind::D(opc) => Ok(d_extension::bit_32::parse_rs3(self, opc)),
+            OpcodeKind::Q(opc) => Ok(q_extension::bit_32::parse_rs3(self, opc)),
+            OpcodeKind::V(opc) => Ok(v_extension::bit_32::parse_rs3(self, opc)),
+            OpcodeKind::C(_) => Err(DecodingError::Not32BitInst),
+            _ => Ok(None),
+        }
+    }
+
     fn parse_imm(self, opkind: &OpcodeKind, isa: Isa) -> Result<Option<i32>, DecodingError> {
         match opkind {
             OpcodeKind::BaseI(opc) => Ok(base_i::bit_32::parse_imm(self, opc, isa)),
             OpcodeKind::M(opc) => Ok(m_extension::bit_32::parse_imm(self, opc)),
             OpcodeKind::A(opc) => Ok(a_extension::bit_32::parse_imm(self, opc)),
+            OpcodeKind::B(opc) => Ok(b_extension::bit_32::parse_imm(self, opc, isa)),
+            OpcodeKind::F(opc) => Ok(f_extension::bit_32::parse_imm(self, opc)),
+            OpcodeKind::D(opc) => Ok(d_extension::bit_32::parse_imm(self, opc)),
+            OpcodeKind::Q(opc) => Ok(q_extension::bit_32::parse_imm(self, opc)),
+            OpcodeKind::V(opc) => Ok(v_extension::bit_32::parse_imm(self, opc)),
             OpcodeKind::Zifencei(opc) => Ok(zifencei_extension::bit_32::parse_imm(self, opc)),
             OpcodeKind::Zicsr(opc) => Ok(zicsr_extension::bit_32::parse_imm(self, opc)),
             OpcodeKind::Zicfiss(opc) => Ok(zicfiss_extension::bit_32::parse_imm(self, opc)),
@@ -118,6 +167,24 @@ impl Decode for u32 {
             OpcodeKind::C(_) => Err(DecodingError::Not32BitInst),
         }
     }
+
+    fn parse_vm(self, opkind: &OpcodeKind) -> Result<Option<bool>, DecodingError> {
+        match opkind {
+            OpcodeKind::V(opc) => Ok(v_extension::bit_32::parse_vm(self, opc)),
+            OpcodeKind::C(_) => Err(DecodingError::Not32BitInst),
+            _ => Ok(None),
+        }
+    }
+
+    fn parse_rm(self, opkind: &OpcodeKind) -> Result<Option<RoundingMode>, DecodingError> {
+        match opkind {
+            OpcodeKind::F(opc) => Ok(f_extension::bit_32::parse_rm(self, opc)),
+            OpcodeKind::D(opc) => Ok(d_extension::bit_32::parse_rm(self, opc)),
+            OpcodeKind::Q(opc) => Ok(q_extension::bit_32::parse_rm(self, opc)),
+            OpcodeKind::C(_) => Err(DecodingError::Not32BitInst),
+            _ => Ok(None),
+        }
+    }
 }
 
 impl DecodeUtil for u32 {
@@ -130,12 +197,38 @@ impl DecodeUtil for u32 {
         let opmap: u8 = u8::try_from(self.slice(6, 0)).unwrap();
         let funct3: u8 = u8::try_from(self.slice(14, 12)).unwrap();
         let funct5: u8 = u8::try_from(self.slice(31, 27)).unwrap();
+        let funct6: u8 = u8::try_from(self.slice(31, 26)).unwrap();
         let funct7: u8 = u8::try_from(self.slice(31, 25)).unwrap();
         let csr: u16 = u16::try_from(self.slice(31, 20)).unwrap();
+        let fmt: u8 = u8::try_from(self.slice(26, 25)).unwrap();
+        let rs2: u8 = u8::try_from(self.slice(24, 20)).unwrap();
 
         match opmap {
+            0b000_0111 | 0b010_0111 => match funct3 {
+                0b010 => Ok(Extensions::F),
+                0b011 => Ok(Extensions::D),
+                0b100 => Ok(Extensions::Q),
+                0b000 | 0b101 | 0b110 | 0b111 => Ok(Extensions::V),
+                _ => Err(DecodingError::UnknownExtension),
+            },
+            0b101_0111 => Ok(Extensions::V),
+            // fcvt between precisions belongs to the wider one (e.g. fcvt.s.d has fmt=S).
+            0b101_0011 if matches!(funct7, 0b010_0000 | 0b010_0001 | 0b010_0011) => {
+                match (fmt, rs2) {
+                    (0b11, _) | (_, 0b00011) => Ok(Extensions::Q),
+                    (0b01, _) | (_, 0b00001) => Ok(Extensions::D),
+                    _ => Err(DecodingError::UnknownExtension),
+                }
+            }
+            0b100_0011 | 0b100_0111 | 0b100_1011 | 0b100_1111 | 0b101_0011 => match fmt {
+                0b00 => Ok(Extensions::F),
+                0b01 => Ok(Extensions::D),
+                0b11 => Ok(Extensions::Q),
+                _ => Err(DecodingError::UnknownExtension),
+            },
             0b000_1111 => match funct3 {
-                0b000 => Ok(Extensions::Zifencei),
+                0b000 => Ok(Extensions::BaseI),
+                0b001 => Ok(Extensions::Zifencei),
                 0b010 => Ok(Extensions::Zicboz),
                 _ => Err(DecodingError::UnknownExtension),
             },
@@ -145,18 +238,31 @@ impl DecodeUtil for u32 {
                 0b01001 => Ok(Extensions::Zicfiss),
                 _ => Err(DecodingError::UnknownExtension),
             },
-            0b011_0011 => match funct7 {
-                0b000_0001 => Ok(Extensions::M),
+            0b001_0011 => match (funct3, funct6) {
+                (0b001, 0b00_0000) | (0b101, 0b00_0000 | 0b01_0000) => Ok(Extensions::BaseI),
+                (0b001 | 0b101, _) => Ok(Extensions::B),
+                _ => Ok(Extensions::BaseI),
+            },
+            0b001_1011 => match (funct3, funct7) {
+                (0b001, 0b000_0000) | (0b101, 0b000_0000 | 0b010_0000) => Ok(Extensions::BaseI),
+                (0b001 | 0b101, _) => Ok(Extensions::B),
                 _ => Ok(Extensions::BaseI),
             },
+            0b011_0011 => match (funct3, funct7) {
+                (_, 0b000_0001) => Ok(Extensions::M),
+                (_, 0b000_0000) | (0b000 | 0b101, 0b010_0000) => Ok(Extensions::BaseI),
+                _ => Ok(Extensions::B),
+            },
             0b011_1011 => match funct7 {
                 0b000_0000 | 0b010_0000 => Ok(Extensions::BaseI),
                 0b000_0001 => Ok(Extensions::M),
-                _ => Err(DecodingError::UnknownExtension),
+                _ => Ok(Extensions::B),
             },
             0b111_0011 => match funct3 {
                 0b000 => match funct7 {
                     0b000_0000 => Ok(Extensions::BaseI),
+                    // hfence.vvma, hfence.gvma
+                    0b001_0001 | 0b011_0001 => Ok(Extensions::H),
                     _ => Ok(Extensions::Priv),
                 },
                 0b010 => match csr {
@@ -164,7 +270,11 @@ impl DecodeUtil for u32 {
                     0xc00..=0xc02 | 0xc80..=0xc82 => Ok(Extensions::Zicntr),
                     _ => Ok(Extensions::Zicsr),
                 },
-                0b100 => Ok(Extensions::Zicfiss),
+                0b100 => match funct7 {
+                    // hlv.*, hlvx.*, hsv.*
+                    0b011_0000..=0b011_0111 => Ok(Extensions::H),
+                    _ => Ok(Extensions::Zicfiss),
+                },
                 _ => Ok(Extensions::Zicsr),
             },
             _ => Ok(Extensions::BaseI),
@@ -1,33 +1,112 @@
 pub mod bit_32 {
-    use super::super::{DecodeUtil, DecodingError};
+    use super::super::{only_rv64, DecodeUtil, DecodingError};
     use crate::instruction::priv_extension::PrivOpcode;
+    use crate::Isa;
 
-    pub fn parse_opcode(inst: u32) -> Result<PrivOpcode, DecodingError> {
+    pub fn parse_opcode(inst: u32, isa: Isa) -> Result<PrivOpcode, DecodingError> {
         let _opmap: u8 = u8::try_from(inst.slice(6, 0)).unwrap();
-        let _funct3: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
+        let rd: u8 = u8::try_from(inst.slice(11, 7)).unwrap();
+        let funct3: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
+        let funct5: u8 = u8::try_from(inst.slice(24, 20)).unwrap();
         let funct7: u8 = u8::try_from(inst.slice(31, 25)).unwrap();
 
         match inst {
             0b0001_0000_0010_0000_0000_0000_0111_0011 => Ok(PrivOpcode::SRET),
             0b0011_0000_0010_0000_0000_0000_0111_0011 => Ok(PrivOpcode::MRET),
             0b0001_0000_0101_0000_0000_0000_0111_0011 => Ok(PrivOpcode::WFI),
-            _ => match funct7 {
-                0b000_1001 => Ok(PrivOpcode::SFENCE_VMA),
-                _ => Err(DecodingError::InvalidFunct7),
+            0b0111_0000_0010_0000_0000_0000_0111_0011 => Ok(PrivOpcode::MNRET),
+            0b0111_1011_0010_0000_0000_0000_0111_0011 => Ok(PrivOpcode::DRET),
+            0b0001_1000_0000_0000_0000_0000_0111_0011 => Ok(PrivOpcode::SFENCE_W_INVAL),
+            0b0001_1000_0001_0000_0000_0000_0111_0011 => Ok(PrivOpcode::SFENCE_INVAL_IR),
+            _ => match funct3 {
+                0b000 => match funct7 {
+                    // sinval.vma, hfence.vvma and hfence.gvma have no destination register.
+                    0b000_1011 | 0b001_0001 | 0b011_0001 if rd != 0 => {
+                        Err(DecodingError::ReservedEncoding)
+                    }
+                    // sfence.w.inval and sfence.inval.ir are matched exactly above.
+                    0b000_1100 => Err(DecodingError::ReservedEncoding),
+                    0b000_1001 => Ok(PrivOpcode::SFENCE_VMA),
+                    0b000_1011 => Ok(PrivOpcode::SINVAL_VMA),
+                    0b001_0001 => Ok(PrivOpcode::HFENCE_VVMA),
+                    0b011_0001 => Ok(PrivOpcode::HFENCE_GVMA),
+                    _ => Err(DecodingError::InvalidFunct7),
+                },
+                0b100 => match funct7 {
+                    0b011_0000 => match funct5 {
+                        0b00000 => Ok(PrivOpcode::HLV_B),
+                        0b00001 => Ok(PrivOpcode::HLV_BU),
+                        _ => Err(DecodingError::InvalidFunct5),
+                    },
+                    0b011_0010 => match funct5 {
+                        0b00000 => Ok(PrivOpcode::HLV_H),
+                        0b00001 => Ok(PrivOpcode::HLV_HU),
+                        0b00011 => Ok(PrivOpcode::HLVX_HU),
+                        _ => Err(DecodingError::InvalidFunct5),
+                    },
+                    0b011_0100 => match funct5 {
+                        0b00000 => Ok(PrivOpcode::HLV_W),
+                        0b00001 => only_rv64(PrivOpcode::HLV_WU, isa),
+                        0b00011 => Ok(PrivOpcode::HLVX_WU),
+                        _ => Err(DecodingError::InvalidFunct5),
+                    },
+                    0b011_0110 => match funct5 {
+                        0b00000 => only_rv64(PrivOpcode::HLV_D, isa),
+                        _ => Err(DecodingError::InvalidFunct5),
+                    },
+                    // hsv.* have no destination register.
+                    0b011_0001 | 0b011_0011 | 0b011_0101 | 0b011_0111 if rd != 0 => {
+                        Err(DecodingError::ReservedEncoding)
+                    }
+                    0b011_0001 => Ok(PrivOpcode::HSV_B),
+                    0b011_0011 => Ok(PrivOpcode::HSV_H),
+                    0b011_0101 => Ok(PrivOpcode::HSV_W),
+                    0b011_0111 => only_rv64(PrivOpcode::HSV_D, isa),
+                    _ => Err(DecodingError::InvalidFunct7),
+                },
+                _ => Err(DecodingError::InvalidFunct3),
             },
         }
     }
 
-    #[allow(clippy::unnecessary_wraps)]
-    pub fn parse_rd(_inst: u32, _opkind: &PrivOpcode) -> Option<usize> {
-        None
+    pub fn parse_rd(inst: u32, opkind: &PrivOpcode) -> Option<usize> {
+        let rd: usize = inst.slice(11, 7) as usize;
+
+        match opkind {
+            PrivOpcode::HLV_B
+            | PrivOpcode::HLV_BU
+            | PrivOpcode::HLV_H
+            | PrivOpcode::HLV_HU
+            | PrivOpcode::HLVX_HU
+            | PrivOpcode::HLV_W
+            | PrivOpcode::HLVX_WU
+            | PrivOpcode::HLV_WU
+            | PrivOpcode::HLV_D => Some(rd),
+            _ => None,
+        }
     }
 
     pub fn parse_rs1(inst: u32, opkind: &PrivOpcode) -> Option<usize> {
         let rs1: usize = inst.slice(19, 15) as usize;
 
         match opkind {
-            PrivOpcode::SFENCE_VMA => Some(rs1),
+            PrivOpcode::SFENCE_VMA
+            | PrivOpcode::SINVAL_VMA
+            | PrivOpcode::HFENCE_VVMA
+            | PrivOpcode::HFENCE_GVMA
+            | PrivOpcode::HLV_B
+            | PrivOpcode::HLV_BU
+            | PrivOpcode::HLV_H
+            | PrivOpcode::HLV_HU
+            | PrivOpcode::HLVX_HU
+            | PrivOpcode::HLV_W
+            | PrivOpcode::HLVX_WU
+            | PrivOpcode::HLV_WU
+            | PrivOpcode::HLV_D
+            | PrivOpcode::HSV_B
+            | PrivOpcode::HSV_H
+            | PrivOpcode::HSV_W
+            | PrivOpcode::HSV_D => Some(rs1),
             _ => None,
         }
     }
@@ -36,7 +115,14 @@ pub mod bit_32 {
         let rs2: usize = inst.slice(24, 20) as usize;
 
         match opkind {
-            PrivOpcode::SFENCE_VMA => Some(rs2),
+            PrivOpcode::SFENCE_VMA
+            | PrivOpcode::SINVAL_VMA
+            | PrivOpcode::HFENCE_VVMA
+            | PrivOpcode::HFENCE_GVMA
+            | PrivOpcode::HSV_B
+            | PrivOpcode::HSV_H
+            | PrivOpcode::HSV_W
+            | PrivOpcode::HSV_D => Some(rs2),
             _ => None,
         }
     }
@@ -51,11 +137,11 @@ pub mod bit_32 {
 #[allow(unused_variables)]
 mod test_priv {
     #[test]
-    #[allow(overflowing_literals)]
+    #[allow(overflowing_literals, clippy::too_many_lines)]
     fn priv_decode_test() {
-        use crate::decode::inst_32::test_32_in_rv64;
+        use crate::decode::inst_32::{test_32_in_rv32, test_32_in_rv64};
         use crate::instruction::priv_extension::PrivOpcode;
-        use crate::OpcodeKind;
+        use crate::{Decode, DecodingError, Isa, OpcodeKind};
 
         test_32_in_rv64(
             0x10200073,
@@ -73,5 +159,114 @@ mod test_priv {
             None,
             None,
         );
+        test_32_in_rv64(
+            0x7020_0073,
+            OpcodeKind::Priv(PrivOpcode::MNRET),
+            None,
+            None,
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x7b20_0073,
+            OpcodeKind::Priv(PrivOpcode::DRET),
+            None,
+            None,
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x16b5_0073,
+            OpcodeKind::Priv(PrivOpcode::SINVAL_VMA),
+            None,
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv32(
+            0x1800_0073,
+            OpcodeKind::Priv(PrivOpcode::SFENCE_W_INVAL),
+            None,
+            None,
+            None,
+            None,
+        );
+        test_32_in_rv32(
+            0x1810_0073,
+            OpcodeKind::Priv(PrivOpcode::SFENCE_INVAL_IR),
+            None,
+            None,
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x22b5_0073,
+            OpcodeKind::Priv(PrivOpcode::HFENCE_VVMA),
+            None,
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv32(
+            0x62b5_0073,
+            OpcodeKind::Priv(PrivOpcode::HFENCE_GVMA),
+            None,
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv32(
+            0x6805_c573,
+            OpcodeKind::Priv(PrivOpcode::HLV_W),
+            Some(10),
+            Some(11),
+            None,
+            None,
+        );
+        test_32_in_rv32(
+            0x6435_c573,
+            OpcodeKind::Priv(PrivOpcode::HLVX_HU),
+            Some(10),
+            Some(11),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x6ea5_c073,
+            OpcodeKind::Priv(PrivOpcode::HSV_D),
+            None,
+            Some(11),
+            Some(10),
+            None,
+        );
+
+        // hlv.d, hlv.wu and hsv.d are only for Rv64.
+        assert_eq!(
+            0x6ea5_c073_u32.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+        assert_eq!(
+            0x6815_c573_u32.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+
+        // sinval.vma requires rd = 0, sfence.w.inval requires rd = rs1 = 0.
+        assert_eq!(
+            0x1698_09f3_u32.decode(Isa::Rv64),
+            Err(DecodingError::ReservedEncoding)
+        );
+        assert_eq!(
+            0x1805_0073_u32.decode(Isa::Rv64),
+            Err(DecodingError::ReservedEncoding)
+        );
+        // hfence.gvma and hsv.w require rd = 0.
+        assert_eq!(
+            0x633f_8573_u32.decode(Isa::Rv64),
+            Err(DecodingError::ReservedEncoding)
+        );
+        assert_eq!(
+            0x6ab5_40f3_u32.decode(Isa::Rv64),
+            Err(DecodingError::ReservedEncoding)
+        );
     }
 }
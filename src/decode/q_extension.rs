@@ -0,0 +1,275 @@
+pub mod bit_32 {
+    use super::super::{only_rv64, DecodeUtil, DecodingError};
+    use crate::instruction::{f_extension::RoundingMode, q_extension::QOpcode};
+    use crate::Isa;
+
+    pub fn parse_opcode(inst: u32, isa: Isa) -> Result<QOpcode, DecodingError> {
+        let opmap: u8 = u8::try_from(inst.slice(6, 0)).unwrap();
+        let funct3: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
+        let funct5: u8 = u8::try_from(inst.slice(24, 20)).unwrap();
+        let funct7: u8 = u8::try_from(inst.slice(31, 25)).unwrap();
+        let fmt: u8 = u8::try_from(inst.slice(26, 25)).unwrap();
+        let is_valid_rm = matches!(funct3, 0b000..=0b100 | 0b111);
+
+        match opmap {
+            0b000_0111 => match funct3 {
+                0b100 => Ok(QOpcode::FLQ),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            0b010_0111 => match funct3 {
+                0b100 => Ok(QOpcode::FSQ),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            0b100_0011 | 0b100_0111 | 0b100_1011 | 0b100_1111 if !is_valid_rm => {
+                Err(DecodingError::InvalidFunct3)
+            }
+            0b100_0011 | 0b100_0111 | 0b100_1011 | 0b100_1111 if fmt != 0b11 => {
+                Err(DecodingError::InvalidFunct7)
+            }
+            0b100_0011 => Ok(QOpcode::FMADD_Q),
+            0b100_0111 => Ok(QOpcode::FMSUB_Q),
+            0b100_1011 => Ok(QOpcode::FNMSUB_Q),
+            0b100_1111 => Ok(QOpcode::FNMADD_Q),
+            0b101_0011 => match funct7 {
+                0b000_0011 | 0b000_0111 | 0b000_1011 | 0b000_1111 | 0b010_1111 | 0b010_0000
+                | 0b010_0001 | 0b010_0011 | 0b110_0011 | 0b110_1011
+                    if !is_valid_rm =>
+                {
+                    Err(DecodingError::InvalidFunct3)
+                }
+                0b000_0011 => Ok(QOpcode::FADD_Q),
+                0b000_0111 => Ok(QOpcode::FSUB_Q),
+                0b000_1011 => Ok(QOpcode::FMUL_Q),
+                0b000_1111 => Ok(QOpcode::FDIV_Q),
+                0b010_1111 => match funct5 {
+                    0b00000 => Ok(QOpcode::FSQRT_Q),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b001_0011 => match funct3 {
+                    0b000 => Ok(QOpcode::FSGNJ_Q),
+                    0b001 => Ok(QOpcode::FSGNJN_Q),
+                    0b010 => Ok(QOpcode::FSGNJX_Q),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b001_0111 => match funct3 {
+                    0b000 => Ok(QOpcode::FMIN_Q),
+                    0b001 => Ok(QOpcode::FMAX_Q),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b010_0000 => match funct5 {
+                    0b00011 => Ok(QOpcode::FCVT_S_Q),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b010_0001 => match funct5 {
+                    0b00011 => Ok(QOpcode::FCVT_D_Q),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b010_0011 => match funct5 {
+                    0b00000 => Ok(QOpcode::FCVT_Q_S),
+                    0b00001 => Ok(QOpcode::FCVT_Q_D),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b101_0011 => match funct3 {
+                    0b010 => Ok(QOpcode::FEQ_Q),
+                    0b001 => Ok(QOpcode::FLT_Q),
+                    0b000 => Ok(QOpcode::FLE_Q),
+                    _ => Err(DecodingError::InvalidFunct3),
+                },
+                0b111_0011 => match (funct5, funct3) {
+                    (0b00000, 0b001) => Ok(QOpcode::FCLASS_Q),
+                    (0b00000, _) => Err(DecodingError::InvalidFunct3),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b110_0011 => match funct5 {
+                    0b00000 => Ok(QOpcode::FCVT_W_Q),
+                    0b00001 => Ok(QOpcode::FCVT_WU_Q),
+                    0b00010 => only_rv64(QOpcode::FCVT_L_Q, isa),
+                    0b00011 => only_rv64(QOpcode::FCVT_LU_Q, isa),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                0b110_1011 => match funct5 {
+                    0b00000 => Ok(QOpcode::FCVT_Q_W),
+                    0b00001 => Ok(QOpcode::FCVT_Q_WU),
+                    0b00010 => only_rv64(QOpcode::FCVT_Q_L, isa),
+                    0b00011 => only_rv64(QOpcode::FCVT_Q_LU, isa),
+                    _ => Err(DecodingError::InvalidFunct5),
+                },
+                _ => Err(DecodingError::InvalidFunct7),
+            },
+            _ => Err(DecodingError::InvalidOpcode),
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn parse_rd(inst: u32, opkind: &QOpcode) -> Option<usize> {
+        let rd: usize = inst.slice(11, 7) as usize;
+
+        match opkind {
+            QOpcode::FSQ => None,
+            _ => Some(rd),
+        }
+    }
+
+    #[allow(clippy::unnecessary_wraps)]
+    pub fn parse_rs1(inst: u32, _opkind: &QOpcode) -> Option<usize> {
+        let rs1: usize = inst.slice(19, 15) as usize;
+
+        Some(rs1)
+    }
+
+    pub fn parse_rs2(inst: u32, opkind: &QOpcode) -> Option<usize> {
+        let rs2: usize = inst.slice(24, 20) as usize;
+
+        match opkind {
+            QOpcode::FSQ
+            | QOpcode::FMADD_Q
+            | QOpcode::FMSUB_Q
+            | QOpcode::FNMSUB_Q
+            | QOpcode::FNMADD_Q
+            | QOpcode::FADD_Q
+            | QOpcode::FSUB_Q
+            | QOpcode::FMUL_Q
+            | QOpcode::FDIV_Q
+            | QOpcode::FSGNJ_Q
+            | QOpcode::FSGNJN_Q
+            | QOpcode::FSGNJX_Q
+            | QOpcode::FMIN_Q
+            | QOpcode::FMAX_Q
+            | QOpcode::FEQ_Q
+            | QOpcode::FLT_Q
+            | QOpcode::FLE_Q => Some(rs2),
+            _ => None,
+        }
+    }
+
+    pub fn parse_rs3(inst: u32, opkind: &QOpcode) -> Option<usize> {
+        let rs3: usize = inst.slice(31, 27) as usize;
+
+        match opkind {
+            QOpcode::FMADD_Q | QOpcode::FMSUB_Q | QOpcode::FNMSUB_Q | QOpcode::FNMADD_Q => {
+                Some(rs3)
+            }
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    #[allow(non_snake_case)]
+    pub fn parse_imm(inst: u32, opkind: &QOpcode) -> Option<i32> {
+        let I_type = || {
+            let imm32 = inst.slice(31, 20) as i32;
+            inst.to_signed_nbit(imm32, 12)
+        };
+        let S_type = || {
+            let imm32 = (inst.slice(11, 7).set(&[4, 3, 2, 1, 0])
+                | inst.slice(31, 25).set(&[11, 10, 9, 8, 7, 6, 5])) as i32;
+            inst.to_signed_nbit(imm32, 12)
+        };
+
+        match opkind {
+            QOpcode::FLQ => Some(I_type()),
+            QOpcode::FSQ => Some(S_type()),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn parse_rm(inst: u32, opkind: &QOpcode) -> Option<RoundingMode> {
+        match opkind {
+            QOpcode::FMADD_Q
+            | QOpcode::FMSUB_Q
+            | QOpcode::FNMSUB_Q
+            | QOpcode::FNMADD_Q
+            | QOpcode::FADD_Q
+            | QOpcode::FSUB_Q
+            | QOpcode::FMUL_Q
+            | QOpcode::FDIV_Q
+            | QOpcode::FSQRT_Q
+            | QOpcode::FCVT_S_Q
+            | QOpcode::FCVT_Q_S
+            | QOpcode::FCVT_D_Q
+            | QOpcode::FCVT_Q_D
+            | QOpcode::FCVT_W_Q
+            | QOpcode::FCVT_WU_Q
+            | QOpcode::FCVT_Q_W
+            | QOpcode::FCVT_Q_WU
+            | QOpcode::FCVT_L_Q
+            | QOpcode::FCVT_LU_Q
+            | QOpcode::FCVT_Q_L
+            | QOpcode::FCVT_Q_LU => RoundingMode::from_rm(inst.slice(14, 12) as i32),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+#[allow(unused_variables)]
+mod test_q {
+    #[test]
+    #[allow(overflowing_literals)]
+    fn q_decode_test() {
+        use crate::decode::inst_32::{test_32_in_rv32, test_32_in_rv64};
+        use crate::instruction::{f_extension::RoundingMode, q_extension::QOpcode};
+        use crate::{Decode, DecodingError, Isa, OpcodeKind};
+
+        test_32_in_rv32(
+            0x0105_4507,
+            OpcodeKind::Q(QOpcode::FLQ),
+            Some(10),
+            Some(10),
+            None,
+            Some(16),
+        );
+        test_32_in_rv64(
+            0x06b5_7553,
+            OpcodeKind::Q(QOpcode::FADD_Q),
+            Some(10),
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv64(
+            0x4035_f553,
+            OpcodeKind::Q(QOpcode::FCVT_S_Q),
+            Some(10),
+            Some(11),
+            None,
+            None,
+        );
+        test_32_in_rv32(
+            0x4615_8553,
+            OpcodeKind::Q(QOpcode::FCVT_Q_D),
+            Some(10),
+            Some(11),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0xd635_7553,
+            OpcodeKind::Q(QOpcode::FCVT_Q_LU),
+            Some(10),
+            Some(10),
+            None,
+            None,
+        );
+        assert_eq!(
+            0x06b5_7553_u32.decode(Isa::Rv32).unwrap().rm,
+            Some(RoundingMode::DYN)
+        );
+        assert_eq!(
+            0x4615_8553_u32.decode(Isa::Rv32).unwrap().rm,
+            Some(RoundingMode::RNE)
+        );
+
+        // fcvt.q.lu is only for Rv64.
+        assert_eq!(
+            0xd635_7553_u32.decode(Isa::Rv32),
+            Err(DecodingError::OnlyRv64Inst)
+        );
+        // Q has no fmv.x.q.
+        assert_eq!(
+            0xe605_0553_u32.decode(Isa::Rv64),
+            Err(DecodingError::InvalidFunct3)
+        );
+    }
+}
@@ -0,0 +1,198 @@
+//! Decoding of a little-endian byte stream.
+
+use super::{Decode, DecodingError};
+use crate::instruction::Instruction;
+use crate::{ExtensionSet, Isa};
+
+/// An instruction decoded from a byte stream.
+#[derive(Debug, PartialEq)]
+pub struct DecodedInst {
+    /// Byte offset from the beginning of the stream.
+    pub offset: usize,
+    /// Raw bits (compressed instructions are zero-extended).
+    pub raw: u32,
+    /// Instruction length in bytes (2 or 4).
+    pub len: usize,
+    /// Decoding result.
+    pub result: Result<Instruction, DecodingError>,
+}
+
+/// Iterator decoding instructions from a little-endian byte stream.
+///
+/// The instruction length is determined from the lowest two bits,
+/// so 16bit and 32bit instructions can be mixed.
+/// After an invalid instruction, decoding resumes at the next 16bit boundary.
+///
+/// # Example
+/// ```
+/// use raki::{BaseIOpcode, COpcode, Decoder, DecodingError, Isa, OpcodeKind};
+///
+/// // c.li a0, 1 / (invalid) / addi a0, a0, 1
+/// let bytes = [0x05, 0x45, 0x00, 0x00, 0x13, 0x05, 0x15, 0x00];
+/// let mut decoder = Decoder::new(&bytes, Isa::Rv64);
+///
+/// let inst = decoder.next().unwrap();
+/// assert_eq!((inst.offset, inst.raw, inst.len), (0, 0x4505, 2));
+/// assert_eq!(inst.result.unwrap().opc, OpcodeKind::C(COpcode::LI));
+///
+/// let inst = decoder.next().unwrap();
+/// assert_eq!((inst.offset, inst.len), (2, 2));
+/// assert_eq!(inst.result, Err(DecodingError::IllegalInstruction));
+///
+/// let inst = decoder.next().unwrap();
+/// assert_eq!((inst.offset, inst.raw, inst.len), (4, 0x0015_0513, 4));
+/// assert_eq!(inst.result.unwrap().opc, OpcodeKind::BaseI(BaseIOpcode::ADDI));
+///
+/// assert_eq!(decoder.next(), None);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Decoder<'a> {
+    bytes: &'a [u8],
+    offset: usize,
+    isa: Isa,
+    extensions: ExtensionSet,
+}
+
+impl<'a> Decoder<'a> {
+    /// Create a decoder for all supported extensions.
+    #[must_use]
+    pub fn new(bytes: &'a [u8], isa: Isa) -> Self {
+        Decoder {
+            bytes,
+            offset: 0,
+            isa,
+            extensions: ExtensionSet::all(),
+        }
+    }
+
+    /// Restrict decoding to the enabled extensions.
+    #[must_use]
+    pub fn with_extensions(self, extensions: ExtensionSet) -> Self {
+        Decoder { extensions, ..self }
+    }
+
+    /// Current byte offset from the beginning of the stream.
+    #[must_use]
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+impl Iterator for Decoder<'_> {
+    type Item = DecodedInst;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let rest = self.bytes.get(self.offset..).filter(|r| !r.is_empty())?;
+        let offset = self.offset;
+        let len = if rest[0] & 0b11 == 0b11 { 4 } else { 2 };
+
+        let (raw, result) = if rest.len() < len {
+            let raw = rest.iter().rev().fold(0, |acc, b| acc << 8 | u32::from(*b));
+            (raw, Err(DecodingError::TruncatedInst))
+        } else if len == 4 {
+            let raw = u32::from_le_bytes([rest[0], rest[1], rest[2], rest[3]]);
+            (raw, raw.decode_with(self.isa, self.extensions))
+        } else {
+            let raw = u16::from_le_bytes([rest[0], rest[1]]);
+            (u32::from(raw), raw.decode_with(self.isa, self.extensions))
+        };
+
+        // resync at the next 16bit boundary after an invalid instruction.
+        self.offset += if result.is_ok() { len } else { 2 };
+
+        Some(DecodedInst {
+            offset,
+            raw,
+            len,
+            result,
+        })
+    }
+}
+
+/// Decode instructions from a little-endian byte stream.
+///
+/// # Example
+/// ```
+/// use raki::{decode_stream, BaseIOpcode, COpcode, Isa, OpcodeKind};
+///
+/// // addi a0, a0, 1 / c.jr ra
+/// let bytes = [0x13, 0x05, 0x15, 0x00, 0x82, 0x80];
+/// let listing: Vec<_> = decode_stream(&bytes, Isa::Rv64)
+///     .map(|inst| (inst.offset, inst.result.unwrap().opc))
+///     .collect();
+///
+/// assert_eq!(
+///     listing,
+///     [
+///         (0, OpcodeKind::BaseI(BaseIOpcode::ADDI)),
+///         (4, OpcodeKind::C(COpcode::JR)),
+///     ]
+/// );
+/// ```
+#[must_use]
+pub fn decode_stream(bytes: &[u8], isa: Isa) -> Decoder<'_> {
+    Decoder::new(bytes, isa)
+}
+
+#[cfg(test)]
+mod test_stream {
+    #[test]
+    fn decode_stream_test() {
+        use super::{decode_stream, DecodedInst};
+        use crate::{DecodingError, ExtensionSet, Extensions, Isa};
+
+        // c.addi a0, 1 / mul a0, a0, a1 / c.nop
+        let bytes = [0x05, 0x05, 0x33, 0x05, 0xb5, 0x02, 0x01, 0x00];
+        let insts: Vec<(usize, u32, usize)> = decode_stream(&bytes, Isa::Rv32)
+            .map(|inst| (inst.offset, inst.raw, inst.len))
+            .collect();
+        assert_eq!(
+            insts,
+            vec![(0, 0x0505, 2), (2, 0x02b5_0533, 4), (6, 0x0001, 2)]
+        );
+
+        // disabled extension: resync after the first 16bit.
+        let rv32i = ExtensionSet::empty().with(Extensions::BaseI);
+        let mut decoder = decode_stream(&bytes, Isa::Rv32).with_extensions(rv32i);
+        assert_eq!(
+            decoder.next().map(|inst| inst.result),
+            Some(Err(DecodingError::DisabledExtension(Extensions::C)))
+        );
+        assert_eq!(decoder.offset(), 2);
+        assert_eq!(
+            decoder.next().map(|inst| inst.result),
+            Some(Err(DecodingError::DisabledExtension(Extensions::M)))
+        );
+        assert_eq!(decoder.offset(), 4);
+        // the upper half of `mul` is decoded as a compressed instruction.
+        assert_eq!(
+            decoder.next().map(|inst| (inst.offset, inst.raw)),
+            Some((4, 0x02b5))
+        );
+
+        // truncated 32bit instruction.
+        let bytes = [0x13, 0x05, 0x15];
+        let mut decoder = decode_stream(&bytes, Isa::Rv64);
+        assert_eq!(
+            decoder.next(),
+            Some(DecodedInst {
+                offset: 0,
+                raw: 0x15_0513,
+                len: 4,
+                result: Err(DecodingError::TruncatedInst),
+            })
+        );
+        assert_eq!(
+            decoder.next(),
+            Some(DecodedInst {
+                offset: 2,
+                raw: 0x15,
+                len: 2,
+                result: Err(DecodingError::TruncatedInst),
+            })
+        );
+        assert_eq!(decoder.next(), None);
+
+        assert_eq!(decode_stream(&[], Isa::Rv64).next(), None);
+    }
+}
@@ -0,0 +1,885 @@
+pub mod bit_32 {
+    use super::super::{DecodeUtil, DecodingError};
+    use crate::instruction::v_extension::VOpcode;
+    use crate::instruction::{InstFormat, Opcode};
+
+    fn parse_load(inst: u32) -> Result<VOpcode, DecodingError> {
+        let width: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
+        let lumop: u8 = u8::try_from(inst.slice(24, 20)).unwrap();
+        let mop: u8 = u8::try_from(inst.slice(27, 26)).unwrap();
+        let mew: u8 = u8::try_from(inst.slice(28, 28)).unwrap();
+        let nf: u8 = u8::try_from(inst.slice(31, 29)).unwrap();
+
+        if mew != 0b0 {
+            return Err(DecodingError::InvalidFunct6);
+        }
+
+        match (mop, nf, lumop) {
+            // unit-stride
+            (0b00, 0b000, 0b00000) => match width {
+                0b000 => Ok(VOpcode::VLE8_V),
+                0b101 => Ok(VOpcode::VLE16_V),
+                0b110 => Ok(VOpcode::VLE32_V),
+                0b111 => Ok(VOpcode::VLE64_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            (0b00, 0b000, 0b10000) => match width {
+                0b000 => Ok(VOpcode::VLE8FF_V),
+                0b101 => Ok(VOpcode::VLE16FF_V),
+                0b110 => Ok(VOpcode::VLE32FF_V),
+                0b111 => Ok(VOpcode::VLE64FF_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            (0b00, 0b000, 0b01011) => match width {
+                0b000 => Ok(VOpcode::VLM_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            // whole register
+            (0b00, _, 0b01000) => match (nf, width) {
+                (0b000, 0b000) => Ok(VOpcode::VL1RE8_V),
+                (0b000, 0b101) => Ok(VOpcode::VL1RE16_V),
+                (0b000, 0b110) => Ok(VOpcode::VL1RE32_V),
+                (0b000, 0b111) => Ok(VOpcode::VL1RE64_V),
+                (0b001, 0b000) => Ok(VOpcode::VL2RE8_V),
+                (0b001, 0b101) => Ok(VOpcode::VL2RE16_V),
+                (0b001, 0b110) => Ok(VOpcode::VL2RE32_V),
+                (0b001, 0b111) => Ok(VOpcode::VL2RE64_V),
+                (0b011, 0b000) => Ok(VOpcode::VL4RE8_V),
+                (0b011, 0b101) => Ok(VOpcode::VL4RE16_V),
+                (0b011, 0b110) => Ok(VOpcode::VL4RE32_V),
+                (0b011, 0b111) => Ok(VOpcode::VL4RE64_V),
+                (0b111, 0b000) => Ok(VOpcode::VL8RE8_V),
+                (0b111, 0b101) => Ok(VOpcode::VL8RE16_V),
+                (0b111, 0b110) => Ok(VOpcode::VL8RE32_V),
+                (0b111, 0b111) => Ok(VOpcode::VL8RE64_V),
+                (_, 0b000 | 0b101 | 0b110 | 0b111) => Err(DecodingError::InvalidFunct6),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            (0b00, 0b000, _) => Err(DecodingError::InvalidFunct5),
+            // indexed-unordered
+            (0b01, 0b000, _) => match width {
+                0b000 => Ok(VOpcode::VLUXEI8_V),
+                0b101 => Ok(VOpcode::VLUXEI16_V),
+                0b110 => Ok(VOpcode::VLUXEI32_V),
+                0b111 => Ok(VOpcode::VLUXEI64_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            // strided
+            (0b10, 0b000, _) => match width {
+                0b000 => Ok(VOpcode::VLSE8_V),
+                0b101 => Ok(VOpcode::VLSE16_V),
+                0b110 => Ok(VOpcode::VLSE32_V),
+                0b111 => Ok(VOpcode::VLSE64_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            // indexed-ordered
+            (0b11, 0b000, _) => match width {
+                0b000 => Ok(VOpcode::VLOXEI8_V),
+                0b101 => Ok(VOpcode::VLOXEI16_V),
+                0b110 => Ok(VOpcode::VLOXEI32_V),
+                0b111 => Ok(VOpcode::VLOXEI64_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            // segment loads are not supported.
+            _ => Err(DecodingError::InvalidFunct6),
+        }
+    }
+
+    fn parse_store(inst: u32) -> Result<VOpcode, DecodingError> {
+        let width: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
+        let sumop: u8 = u8::try_from(inst.slice(24, 20)).unwrap();
+        let mop: u8 = u8::try_from(inst.slice(27, 26)).unwrap();
+        let mew: u8 = u8::try_from(inst.slice(28, 28)).unwrap();
+        let nf: u8 = u8::try_from(inst.slice(31, 29)).unwrap();
+
+        if mew != 0b0 {
+            return Err(DecodingError::InvalidFunct6);
+        }
+
+        match (mop, nf, sumop) {
+            // unit-stride
+            (0b00, 0b000, 0b00000) => match width {
+                0b000 => Ok(VOpcode::VSE8_V),
+                0b101 => Ok(VOpcode::VSE16_V),
+                0b110 => Ok(VOpcode::VSE32_V),
+                0b111 => Ok(VOpcode::VSE64_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            (0b00, 0b000, 0b01011) => match width {
+                0b000 => Ok(VOpcode::VSM_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            // whole register
+            (0b00, _, 0b01000) => match (nf, width) {
+                (0b000, 0b000) => Ok(VOpcode::VS1R_V),
+                (0b001, 0b000) => Ok(VOpcode::VS2R_V),
+                (0b011, 0b000) => Ok(VOpcode::VS4R_V),
+                (0b111, 0b000) => Ok(VOpcode::VS8R_V),
+                (_, 0b000) => Err(DecodingError::InvalidFunct6),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            (0b00, 0b000, _) => Err(DecodingError::InvalidFunct5),
+            // indexed-unordered
+            (0b01, 0b000, _) => match width {
+                0b000 => Ok(VOpcode::VSUXEI8_V),
+                0b101 => Ok(VOpcode::VSUXEI16_V),
+                0b110 => Ok(VOpcode::VSUXEI32_V),
+                0b111 => Ok(VOpcode::VSUXEI64_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            // strided
+            (0b10, 0b000, _) => match width {
+                0b000 => Ok(VOpcode::VSSE8_V),
+                0b101 => Ok(VOpcode::VSSE16_V),
+                0b110 => Ok(VOpcode::VSSE32_V),
+                0b111 => Ok(VOpcode::VSSE64_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            // indexed-ordered
+            (0b11, 0b000, _) => match width {
+                0b000 => Ok(VOpcode::VSOXEI8_V),
+                0b101 => Ok(VOpcode::VSOXEI16_V),
+                0b110 => Ok(VOpcode::VSOXEI32_V),
+                0b111 => Ok(VOpcode::VSOXEI64_V),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
+            // segment stores are not supported.
+            _ => Err(DecodingError::InvalidFunct6),
+        }
+    }
+
+    fn parse_config(inst: u32) -> Result<VOpcode, DecodingError> {
+        let bit_31: u8 = u8::try_from(inst.slice(31, 31)).unwrap();
+        let bit_30: u8 = u8::try_from(inst.slice(30, 30)).unwrap();
+        let funct7: u8 = u8::try_from(inst.slice(31, 25)).unwrap();
+
+        match (bit_31, bit_30) {
+            (0b0, _) => Ok(VOpcode::VSETVLI),
+            (0b1, 0b1) => Ok(VOpcode::VSETIVLI),
+            _ => match funct7 {
+                0b100_0000 => Ok(VOpcode::VSETVL),
+                _ => Err(DecodingError::InvalidFunct7),
+            },
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    fn parse_arith(inst: u32) -> Result<VOpcode, DecodingError> {
+        let funct3: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
+        let vs1: u8 = u8::try_from(inst.slice(19, 15)).unwrap();
+        let vs2: u8 = u8::try_from(inst.slice(24, 20)).unwrap();
+        let vm: u8 = u8::try_from(inst.slice(25, 25)).unwrap();
+        let funct6: u8 = u8::try_from(inst.slice(31, 26)).unwrap();
+
+        match (funct3, funct6) {
+            (0b000, 0b00_0000) => Ok(VOpcode::VADD_VV),
+            (0b100, 0b00_0000) => Ok(VOpcode::VADD_VX),
+            (0b011, 0b00_0000) => Ok(VOpcode::VADD_VI),
+            (0b000, 0b00_0010) => Ok(VOpcode::VSUB_VV),
+            (0b100, 0b00_0010) => Ok(VOpcode::VSUB_VX),
+            (0b100, 0b00_0011) => Ok(VOpcode::VRSUB_VX),
+            (0b011, 0b00_0011) => Ok(VOpcode::VRSUB_VI),
+            (0b000, 0b00_0100) => Ok(VOpcode::VMINU_VV),
+            (0b100, 0b00_0100) => Ok(VOpcode::VMINU_VX),
+            (0b000, 0b00_0101) => Ok(VOpcode::VMIN_VV),
+            (0b100, 0b00_0101) => Ok(VOpcode::VMIN_VX),
+            (0b000, 0b00_0110) => Ok(VOpcode::VMAXU_VV),
+            (0b100, 0b00_0110) => Ok(VOpcode::VMAXU_VX),
+            (0b000, 0b00_0111) => Ok(VOpcode::VMAX_VV),
+            (0b100, 0b00_0111) => Ok(VOpcode::VMAX_VX),
+            (0b000, 0b00_1001) => Ok(VOpcode::VAND_VV),
+            (0b100, 0b00_1001) => Ok(VOpcode::VAND_VX),
+            (0b011, 0b00_1001) => Ok(VOpcode::VAND_VI),
+            (0b000, 0b00_1010) => Ok(VOpcode::VOR_VV),
+            (0b100, 0b00_1010) => Ok(VOpcode::VOR_VX),
+            (0b011, 0b00_1010) => Ok(VOpcode::VOR_VI),
+            (0b000, 0b00_1011) => Ok(VOpcode::VXOR_VV),
+            (0b100, 0b00_1011) => Ok(VOpcode::VXOR_VX),
+            (0b011, 0b00_1011) => Ok(VOpcode::VXOR_VI),
+            (0b000, 0b00_1100) => Ok(VOpcode::VRGATHER_VV),
+            (0b100, 0b00_1100) => Ok(VOpcode::VRGATHER_VX),
+            (0b011, 0b00_1100) => Ok(VOpcode::VRGATHER_VI),
+            (0b000, 0b00_1110) => Ok(VOpcode::VRGATHEREI16_VV),
+            (0b100, 0b00_1110) => Ok(VOpcode::VSLIDEUP_VX),
+            (0b011, 0b00_1110) => Ok(VOpcode::VSLIDEUP_VI),
+            (0b100, 0b00_1111) => Ok(VOpcode::VSLIDEDOWN_VX),
+            (0b011, 0b00_1111) => Ok(VOpcode::VSLIDEDOWN_VI),
+            (0b000, 0b01_0000) => match vm {
+                0b0 => Ok(VOpcode::VADC_VVM),
+                _ => Err(DecodingError::ReservedEncoding),
+            },
+            (0b100, 0b01_0000) => match vm {
+                0b0 => Ok(VOpcode::VADC_VXM),
+                _ => Err(DecodingError::ReservedEncoding),
+            },
+            (0b011, 0b01_0000) => match vm {
+                0b0 => Ok(VOpcode::VADC_VIM),
+                _ => Err(DecodingError::ReservedEncoding),
+            },
+            (0b000, 0b01_0001) => match vm {
+                0b0 => Ok(VOpcode::VMADC_VVM),
+                0b1 => Ok(VOpcode::VMADC_VV),
+                _ => unreachable!(),
+            },
+            (0b100, 0b01_0001) => match vm {
+                0b0 => Ok(VOpcode::VMADC_VXM),
+                0b1 => Ok(VOpcode::VMADC_VX),
+                _ => unreachable!(),
+            },
+            (0b011, 0b01_0001) => match vm {
+                0b0 => Ok(VOpcode::VMADC_VIM),
+                0b1 => Ok(VOpcode::VMADC_VI),
+                _ => unreachable!(),
+            },
+            (0b000, 0b01_0010) => match vm {
+                0b0 => Ok(VOpcode::VSBC_VVM),
+                _ => Err(DecodingError::ReservedEncoding),
+            },
+            (0b100, 0b01_0010) => match vm {
+                0b0 => Ok(VOpcode::VSBC_VXM),
+                _ => Err(DecodingError::ReservedEncoding),
+            },
+            (0b000, 0b01_0011) => match vm {
+                0b0 => Ok(VOpcode::VMSBC_VVM),
+                0b1 => Ok(VOpcode::VMSBC_VV),
+                _ => unreachable!(),
+            },
+            (0b100, 0b01_0011) => match vm {
+                0b0 => Ok(VOpcode::VMSBC_VXM),
+                0b1 => Ok(VOpcode::VMSBC_VX),
+                _ => unreachable!(),
+            },
+            (0b000, 0b01_0111) => match vm {
+                0b0 => Ok(VOpcode::VMERGE_VVM),
+                0b1 if vs2 == 0b00000 => Ok(VOpcode::VMV_V_V),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b100, 0b01_0111) => match vm {
+                0b0 => Ok(VOpcode::VMERGE_VXM),
+                0b1 if vs2 == 0b00000 => Ok(VOpcode::VMV_V_X),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b011, 0b01_0111) => match vm {
+                0b0 => Ok(VOpcode::VMERGE_VIM),
+                0b1 if vs2 == 0b00000 => Ok(VOpcode::VMV_V_I),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b000, 0b01_1000) => Ok(VOpcode::VMSEQ_VV),
+            (0b100, 0b01_1000) => Ok(VOpcode::VMSEQ_VX),
+            (0b011, 0b01_1000) => Ok(VOpcode::VMSEQ_VI),
+            (0b000, 0b01_1001) => Ok(VOpcode::VMSNE_VV),
+            (0b100, 0b01_1001) => Ok(VOpcode::VMSNE_VX),
+            (0b011, 0b01_1001) => Ok(VOpcode::VMSNE_VI),
+            (0b000, 0b01_1010) => Ok(VOpcode::VMSLTU_VV),
+            (0b100, 0b01_1010) => Ok(VOpcode::VMSLTU_VX),
+            (0b000, 0b01_1011) => Ok(VOpcode::VMSLT_VV),
+            (0b100, 0b01_1011) => Ok(VOpcode::VMSLT_VX),
+            (0b000, 0b01_1100) => Ok(VOpcode::VMSLEU_VV),
+            (0b100, 0b01_1100) => Ok(VOpcode::VMSLEU_VX),
+            (0b011, 0b01_1100) => Ok(VOpcode::VMSLEU_VI),
+            (0b000, 0b01_1101) => Ok(VOpcode::VMSLE_VV),
+            (0b100, 0b01_1101) => Ok(VOpcode::VMSLE_VX),
+            (0b011, 0b01_1101) => Ok(VOpcode::VMSLE_VI),
+            (0b100, 0b01_1110) => Ok(VOpcode::VMSGTU_VX),
+            (0b011, 0b01_1110) => Ok(VOpcode::VMSGTU_VI),
+            (0b100, 0b01_1111) => Ok(VOpcode::VMSGT_VX),
+            (0b011, 0b01_1111) => Ok(VOpcode::VMSGT_VI),
+            (0b000, 0b10_0000) => Ok(VOpcode::VSADDU_VV),
+            (0b100, 0b10_0000) => Ok(VOpcode::VSADDU_VX),
+            (0b011, 0b10_0000) => Ok(VOpcode::VSADDU_VI),
+            (0b000, 0b10_0001) => Ok(VOpcode::VSADD_VV),
+            (0b100, 0b10_0001) => Ok(VOpcode::VSADD_VX),
+            (0b011, 0b10_0001) => Ok(VOpcode::VSADD_VI),
+            (0b000, 0b10_0010) => Ok(VOpcode::VSSUBU_VV),
+            (0b100, 0b10_0010) => Ok(VOpcode::VSSUBU_VX),
+            (0b000, 0b10_0011) => Ok(VOpcode::VSSUB_VV),
+            (0b100, 0b10_0011) => Ok(VOpcode::VSSUB_VX),
+            (0b000, 0b10_0101) => Ok(VOpcode::VSLL_VV),
+            (0b100, 0b10_0101) => Ok(VOpcode::VSLL_VX),
+            (0b011, 0b10_0101) => Ok(VOpcode::VSLL_VI),
+            (0b000, 0b10_0111) => Ok(VOpcode::VSMUL_VV),
+            (0b100, 0b10_0111) => Ok(VOpcode::VSMUL_VX),
+            (0b011, 0b10_0111) => match vs1 {
+                0b00000 => Ok(VOpcode::VMV1R_V),
+                0b00001 => Ok(VOpcode::VMV2R_V),
+                0b00011 => Ok(VOpcode::VMV4R_V),
+                0b00111 => Ok(VOpcode::VMV8R_V),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b000, 0b10_1000) => Ok(VOpcode::VSRL_VV),
+            (0b100, 0b10_1000) => Ok(VOpcode::VSRL_VX),
+            (0b011, 0b10_1000) => Ok(VOpcode::VSRL_VI),
+            (0b000, 0b10_1001) => Ok(VOpcode::VSRA_VV),
+            (0b100, 0b10_1001) => Ok(VOpcode::VSRA_VX),
+            (0b011, 0b10_1001) => Ok(VOpcode::VSRA_VI),
+            (0b000, 0b10_1010) => Ok(VOpcode::VSSRL_VV),
+            (0b100, 0b10_1010) => Ok(VOpcode::VSSRL_VX),
+            (0b011, 0b10_1010) => Ok(VOpcode::VSSRL_VI),
+            (0b000, 0b10_1011) => Ok(VOpcode::VSSRA_VV),
+            (0b100, 0b10_1011) => Ok(VOpcode::VSSRA_VX),
+            (0b011, 0b10_1011) => Ok(VOpcode::VSSRA_VI),
+            (0b000, 0b10_1100) => Ok(VOpcode::VNSRL_WV),
+            (0b100, 0b10_1100) => Ok(VOpcode::VNSRL_WX),
+            (0b011, 0b10_1100) => Ok(VOpcode::VNSRL_WI),
+            (0b000, 0b10_1101) => Ok(VOpcode::VNSRA_WV),
+            (0b100, 0b10_1101) => Ok(VOpcode::VNSRA_WX),
+            (0b011, 0b10_1101) => Ok(VOpcode::VNSRA_WI),
+            (0b000, 0b10_1110) => Ok(VOpcode::VNCLIPU_WV),
+            (0b100, 0b10_1110) => Ok(VOpcode::VNCLIPU_WX),
+            (0b011, 0b10_1110) => Ok(VOpcode::VNCLIPU_WI),
+            (0b000, 0b10_1111) => Ok(VOpcode::VNCLIP_WV),
+            (0b100, 0b10_1111) => Ok(VOpcode::VNCLIP_WX),
+            (0b011, 0b10_1111) => Ok(VOpcode::VNCLIP_WI),
+            (0b000, 0b11_0000) => Ok(VOpcode::VWREDSUMU_VS),
+            (0b000, 0b11_0001) => Ok(VOpcode::VWREDSUM_VS),
+            (0b010, 0b00_0000) => Ok(VOpcode::VREDSUM_VS),
+            (0b010, 0b00_0001) => Ok(VOpcode::VREDAND_VS),
+            (0b010, 0b00_0010) => Ok(VOpcode::VREDOR_VS),
+            (0b010, 0b00_0011) => Ok(VOpcode::VREDXOR_VS),
+            (0b010, 0b00_0100) => Ok(VOpcode::VREDMINU_VS),
+            (0b010, 0b00_0101) => Ok(VOpcode::VREDMIN_VS),
+            (0b010, 0b00_0110) => Ok(VOpcode::VREDMAXU_VS),
+            (0b010, 0b00_0111) => Ok(VOpcode::VREDMAX_VS),
+            (0b010, 0b00_1000) => Ok(VOpcode::VAADDU_VV),
+            (0b110, 0b00_1000) => Ok(VOpcode::VAADDU_VX),
+            (0b010, 0b00_1001) => Ok(VOpcode::VAADD_VV),
+            (0b110, 0b00_1001) => Ok(VOpcode::VAADD_VX),
+            (0b010, 0b00_1010) => Ok(VOpcode::VASUBU_VV),
+            (0b110, 0b00_1010) => Ok(VOpcode::VASUBU_VX),
+            (0b010, 0b00_1011) => Ok(VOpcode::VASUB_VV),
+            (0b110, 0b00_1011) => Ok(VOpcode::VASUB_VX),
+            (0b110, 0b00_1110) => Ok(VOpcode::VSLIDE1UP_VX),
+            (0b110, 0b00_1111) => Ok(VOpcode::VSLIDE1DOWN_VX),
+            (0b010, 0b01_0000) => match vs1 {
+                0b00000 => Ok(VOpcode::VMV_X_S),
+                0b10000 => Ok(VOpcode::VCPOP_M),
+                0b10001 => Ok(VOpcode::VFIRST_M),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b110, 0b01_0000) if vs2 == 0b00000 => Ok(VOpcode::VMV_S_X),
+            (0b010, 0b01_0010) => match vs1 {
+                0b00010 => Ok(VOpcode::VZEXT_VF8),
+                0b00011 => Ok(VOpcode::VSEXT_VF8),
+                0b00100 => Ok(VOpcode::VZEXT_VF4),
+                0b00101 => Ok(VOpcode::VSEXT_VF4),
+                0b00110 => Ok(VOpcode::VZEXT_VF2),
+                0b00111 => Ok(VOpcode::VSEXT_VF2),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b010, 0b01_0100) => match vs1 {
+                0b00001 => Ok(VOpcode::VMSBF_M),
+                0b00010 => Ok(VOpcode::VMSOF_M),
+                0b00011 => Ok(VOpcode::VMSIF_M),
+                0b10000 => Ok(VOpcode::VIOTA_M),
+                0b10001 if vs2 == 0b00000 => Ok(VOpcode::VID_V),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b010, 0b01_0111) => Ok(VOpcode::VCOMPRESS_VM),
+            (0b010, 0b01_1000) => Ok(VOpcode::VMANDN_MM),
+            (0b010, 0b01_1001) => Ok(VOpcode::VMAND_MM),
+            (0b010, 0b01_1010) => Ok(VOpcode::VMOR_MM),
+            (0b010, 0b01_1011) => Ok(VOpcode::VMXOR_MM),
+            (0b010, 0b01_1100) => Ok(VOpcode::VMORN_MM),
+            (0b010, 0b01_1101) => Ok(VOpcode::VMNAND_MM),
+            (0b010, 0b01_1110) => Ok(VOpcode::VMNOR_MM),
+            (0b010, 0b01_1111) => Ok(VOpcode::VMXNOR_MM),
+            (0b010, 0b10_0000) => Ok(VOpcode::VDIVU_VV),
+            (0b110, 0b10_0000) => Ok(VOpcode::VDIVU_VX),
+            (0b010, 0b10_0001) => Ok(VOpcode::VDIV_VV),
+            (0b110, 0b10_0001) => Ok(VOpcode::VDIV_VX),
+            (0b010, 0b10_0010) => Ok(VOpcode::VREMU_VV),
+            (0b110, 0b10_0010) => Ok(VOpcode::VREMU_VX),
+            (0b010, 0b10_0011) => Ok(VOpcode::VREM_VV),
+            (0b110, 0b10_0011) => Ok(VOpcode::VREM_VX),
+            (0b010, 0b10_0100) => Ok(VOpcode::VMULHU_VV),
+            (0b110, 0b10_0100) => Ok(VOpcode::VMULHU_VX),
+            (0b010, 0b10_0101) => Ok(VOpcode::VMUL_VV),
+            (0b110, 0b10_0101) => Ok(VOpcode::VMUL_VX),
+            (0b010, 0b10_0110) => Ok(VOpcode::VMULHSU_VV),
+            (0b110, 0b10_0110) => Ok(VOpcode::VMULHSU_VX),
+            (0b010, 0b10_0111) => Ok(VOpcode::VMULH_VV),
+            (0b110, 0b10_0111) => Ok(VOpcode::VMULH_VX),
+            (0b010, 0b10_1001) => Ok(VOpcode::VMADD_VV),
+            (0b110, 0b10_1001) => Ok(VOpcode::VMADD_VX),
+            (0b010, 0b10_1011) => Ok(VOpcode::VNMSUB_VV),
+            (0b110, 0b10_1011) => Ok(VOpcode::VNMSUB_VX),
+            (0b010, 0b10_1101) => Ok(VOpcode::VMACC_VV),
+            (0b110, 0b10_1101) => Ok(VOpcode::VMACC_VX),
+            (0b010, 0b10_1111) => Ok(VOpcode::VNMSAC_VV),
+            (0b110, 0b10_1111) => Ok(VOpcode::VNMSAC_VX),
+            (0b010, 0b11_0000) => Ok(VOpcode::VWADDU_VV),
+            (0b110, 0b11_0000) => Ok(VOpcode::VWADDU_VX),
+            (0b010, 0b11_0001) => Ok(VOpcode::VWADD_VV),
+            (0b110, 0b11_0001) => Ok(VOpcode::VWADD_VX),
+            (0b010, 0b11_0010) => Ok(VOpcode::VWSUBU_VV),
+            (0b110, 0b11_0010) => Ok(VOpcode::VWSUBU_VX),
+            (0b010, 0b11_0011) => Ok(VOpcode::VWSUB_VV),
+            (0b110, 0b11_0011) => Ok(VOpcode::VWSUB_VX),
+            (0b010, 0b11_0100) => Ok(VOpcode::VWADDU_WV),
+            (0b110, 0b11_0100) => Ok(VOpcode::VWADDU_WX),
+            (0b010, 0b11_0101) => Ok(VOpcode::VWADD_WV),
+            (0b110, 0b11_0101) => Ok(VOpcode::VWADD_WX),
+            (0b010, 0b11_0110) => Ok(VOpcode::VWSUBU_WV),
+            (0b110, 0b11_0110) => Ok(VOpcode::VWSUBU_WX),
+            (0b010, 0b11_0111) => Ok(VOpcode::VWSUB_WV),
+            (0b110, 0b11_0111) => Ok(VOpcode::VWSUB_WX),
+            (0b010, 0b11_1000) => Ok(VOpcode::VWMULU_VV),
+            (0b110, 0b11_1000) => Ok(VOpcode::VWMULU_VX),
+            (0b010, 0b11_1010) => Ok(VOpcode::VWMULSU_VV),
+            (0b110, 0b11_1010) => Ok(VOpcode::VWMULSU_VX),
+            (0b010, 0b11_1011) => Ok(VOpcode::VWMUL_VV),
+            (0b110, 0b11_1011) => Ok(VOpcode::VWMUL_VX),
+            (0b010, 0b11_1100) => Ok(VOpcode::VWMACCU_VV),
+            (0b110, 0b11_1100) => Ok(VOpcode::VWMACCU_VX),
+            (0b010, 0b11_1101) => Ok(VOpcode::VWMACC_VV),
+            (0b110, 0b11_1101) => Ok(VOpcode::VWMACC_VX),
+            (0b110, 0b11_1110) => Ok(VOpcode::VWMACCUS_VX),
+            (0b010, 0b11_1111) => Ok(VOpcode::VWMACCSU_VV),
+            (0b110, 0b11_1111) => Ok(VOpcode::VWMACCSU_VX),
+            (0b001, 0b00_0000) => Ok(VOpcode::VFADD_VV),
+            (0b101, 0b00_0000) => Ok(VOpcode::VFADD_VF),
+            (0b001, 0b00_0010) => Ok(VOpcode::VFSUB_VV),
+            (0b101, 0b00_0010) => Ok(VOpcode::VFSUB_VF),
+            (0b001, 0b00_0100) => Ok(VOpcode::VFMIN_VV),
+            (0b101, 0b00_0100) => Ok(VOpcode::VFMIN_VF),
+            (0b001, 0b00_0110) => Ok(VOpcode::VFMAX_VV),
+            (0b101, 0b00_0110) => Ok(VOpcode::VFMAX_VF),
+            (0b001, 0b00_1000) => Ok(VOpcode::VFSGNJ_VV),
+            (0b101, 0b00_1000) => Ok(VOpcode::VFSGNJ_VF),
+            (0b001, 0b00_1001) => Ok(VOpcode::VFSGNJN_VV),
+            (0b101, 0b00_1001) => Ok(VOpcode::VFSGNJN_VF),
+            (0b001, 0b00_1010) => Ok(VOpcode::VFSGNJX_VV),
+            (0b101, 0b00_1010) => Ok(VOpcode::VFSGNJX_VF),
+            (0b001, 0b00_0001) => Ok(VOpcode::VFREDUSUM_VS),
+            (0b001, 0b00_0011) => Ok(VOpcode::VFREDOSUM_VS),
+            (0b001, 0b00_0101) => Ok(VOpcode::VFREDMIN_VS),
+            (0b001, 0b00_0111) => Ok(VOpcode::VFREDMAX_VS),
+            (0b101, 0b00_1110) => Ok(VOpcode::VFSLIDE1UP_VF),
+            (0b101, 0b00_1111) => Ok(VOpcode::VFSLIDE1DOWN_VF),
+            (0b001, 0b01_0000) => match vs1 {
+                0b00000 => Ok(VOpcode::VFMV_F_S),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b101, 0b01_0000) if vs2 == 0b00000 => Ok(VOpcode::VFMV_S_F),
+            (0b001, 0b01_0010) => match vs1 {
+                0b00000 => Ok(VOpcode::VFCVT_XU_F_V),
+                0b00001 => Ok(VOpcode::VFCVT_X_F_V),
+                0b00010 => Ok(VOpcode::VFCVT_F_XU_V),
+                0b00011 => Ok(VOpcode::VFCVT_F_X_V),
+                0b00110 => Ok(VOpcode::VFCVT_RTZ_XU_F_V),
+                0b00111 => Ok(VOpcode::VFCVT_RTZ_X_F_V),
+                0b01000 => Ok(VOpcode::VFWCVT_XU_F_V),
+                0b01001 => Ok(VOpcode::VFWCVT_X_F_V),
+                0b01010 => Ok(VOpcode::VFWCVT_F_XU_V),
+                0b01011 => Ok(VOpcode::VFWCVT_F_X_V),
+                0b01100 => Ok(VOpcode::VFWCVT_F_F_V),
+                0b01110 => Ok(VOpcode::VFWCVT_RTZ_XU_F_V),
+                0b01111 => Ok(VOpcode::VFWCVT_RTZ_X_F_V),
+                0b10000 => Ok(VOpcode::VFNCVT_XU_F_W),
+                0b10001 => Ok(VOpcode::VFNCVT_X_F_W),
+                0b10010 => Ok(VOpcode::VFNCVT_F_XU_W),
+                0b10011 => Ok(VOpcode::VFNCVT_F_X_W),
+                0b10100 => Ok(VOpcode::VFNCVT_F_F_W),
+                0b10101 => Ok(VOpcode::VFNCVT_ROD_F_F_W),
+                0b10110 => Ok(VOpcode::VFNCVT_RTZ_XU_F_W),
+                0b10111 => Ok(VOpcode::VFNCVT_RTZ_X_F_W),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b001, 0b01_0011) => match vs1 {
+                0b00000 => Ok(VOpcode::VFSQRT_V),
+                0b00100 => Ok(VOpcode::VFRSQRT7_V),
+                0b00101 => Ok(VOpcode::VFREC7_V),
+                0b10000 => Ok(VOpcode::VFCLASS_V),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b101, 0b01_0111) => match vm {
+                0b0 => Ok(VOpcode::VFMERGE_VFM),
+                0b1 if vs2 == 0b00000 => Ok(VOpcode::VFMV_V_F),
+                _ => Err(DecodingError::InvalidFunct5),
+            },
+            (0b001, 0b01_1000) => Ok(VOpcode::VMFEQ_VV),
+            (0b101, 0b01_1000) => Ok(VOpcode::VMFEQ_VF),
+            (0b001, 0b01_1001) => Ok(VOpcode::VMFLE_VV),
+            (0b101, 0b01_1001) => Ok(VOpcode::VMFLE_VF),
+            (0b001, 0b01_1011) => Ok(VOpcode::VMFLT_VV),
+            (0b101, 0b01_1011) => Ok(VOpcode::VMFLT_VF),
+            (0b001, 0b01_1100) => Ok(VOpcode::VMFNE_VV),
+            (0b101, 0b01_1100) => Ok(VOpcode::VMFNE_VF),
+            (0b101, 0b01_1101) => Ok(VOpcode::VMFGT_VF),
+            (0b101, 0b01_1111) => Ok(VOpcode::VMFGE_VF),
+            (0b001, 0b10_0000) => Ok(VOpcode::VFDIV_VV),
+            (0b101, 0b10_0000) => Ok(VOpcode::VFDIV_VF),
+            (0b101, 0b10_0001) => Ok(VOpcode::VFRDIV_VF),
+            (0b001, 0b10_0100) => Ok(VOpcode::VFMUL_VV),
+            (0b101, 0b10_0100) => Ok(VOpcode::VFMUL_VF),
+            (0b101, 0b10_0111) => Ok(VOpcode::VFRSUB_VF),
+            (0b001, 0b10_1000) => Ok(VOpcode::VFMADD_VV),
+            (0b101, 0b10_1000) => Ok(VOpcode::VFMADD_VF),
+            (0b001, 0b10_1001) => Ok(VOpcode::VFNMADD_VV),
+            (0b101, 0b10_1001) => Ok(VOpcode::VFNMADD_VF),
+            (0b001, 0b10_1010) => Ok(VOpcode::VFMSUB_VV),
+            (0b101, 0b10_1010) => Ok(VOpcode::VFMSUB_VF),
+            (0b001, 0b10_1011) => Ok(VOpcode::VFNMSUB_VV),
+            (0b101, 0b10_1011) => Ok(VOpcode::VFNMSUB_VF),
+            (0b001, 0b10_1100) => Ok(VOpcode::VFMACC_VV),
+            (0b101, 0b10_1100) => Ok(VOpcode::VFMACC_VF),
+            (0b001, 0b10_1101) => Ok(VOpcode::VFNMACC_VV),
+            (0b101, 0b10_1101) => Ok(VOpcode::VFNMACC_VF),
+            (0b001, 0b10_1110) => Ok(VOpcode::VFMSAC_VV),
+            (0b101, 0b10_1110) => Ok(VOpcode::VFMSAC_VF),
+            (0b001, 0b10_1111) => Ok(VOpcode::VFNMSAC_VV),
+            (0b101, 0b10_1111) => Ok(VOpcode::VFNMSAC_VF),
+            (0b001, 0b11_0000) => Ok(VOpcode::VFWADD_VV),
+            (0b101, 0b11_0000) => Ok(VOpcode::VFWADD_VF),
+            (0b001, 0b11_0010) => Ok(VOpcode::VFWSUB_VV),
+            (0b101, 0b11_0010) => Ok(VOpcode::VFWSUB_VF),
+            (0b001, 0b11_0001) => Ok(VOpcode::VFWREDUSUM_VS),
+            (0b001, 0b11_0011) => Ok(VOpcode::VFWREDOSUM_VS),
+            (0b001, 0b11_0100) => Ok(VOpcode::VFWADD_WV),
+            (0b101, 0b11_0100) => Ok(VOpcode::VFWADD_WF),
+            (0b001, 0b11_0110) => Ok(VOpcode::VFWSUB_WV),
+            (0b101, 0b11_0110) => Ok(VOpcode::VFWSUB_WF),
+            (0b001, 0b11_1000) => Ok(VOpcode::VFWMUL_VV),
+            (0b101, 0b11_1000) => Ok(VOpcode::VFWMUL_VF),
+            (0b001, 0b11_1100) => Ok(VOpcode::VFWMACC_VV),
+            (0b101, 0b11_1100) => Ok(VOpcode::VFWMACC_VF),
+            (0b001, 0b11_1101) => Ok(VOpcode::VFWNMACC_VV),
+            (0b101, 0b11_1101) => Ok(VOpcode::VFWNMACC_VF),
+            (0b001, 0b11_1110) => Ok(VOpcode::VFWMSAC_VV),
+            (0b101, 0b11_1110) => Ok(VOpcode::VFWMSAC_VF),
+            (0b001, 0b11_1111) => Ok(VOpcode::VFWNMSAC_VV),
+            (0b101, 0b11_1111) => Ok(VOpcode::VFWNMSAC_VF),
+            _ => Err(DecodingError::InvalidFunct6),
+        }
+    }
+
+    pub fn parse_opcode(inst: u32) -> Result<VOpcode, DecodingError> {
+        let opmap: u8 = u8::try_from(inst.slice(6, 0)).unwrap();
+        let funct3: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
+
+        let opkind = match opmap {
+            0b000_0111 => parse_load(inst),
+            0b010_0111 => parse_store(inst),
+            0b101_0111 => match funct3 {
+                0b111 => parse_config(inst),
+                _ => parse_arith(inst),
+            },
+            _ => Err(DecodingError::InvalidOpcode),
+        }?;
+
+        if inst.slice(25, 25) == 0b0 && opkind.is_unmasked_only() {
+            return Err(DecodingError::ReservedEncoding);
+        }
+        Ok(opkind)
+    }
+
+    pub fn parse_rd(inst: u32, opkind: &VOpcode) -> Option<usize> {
+        let rd: usize = inst.slice(11, 7) as usize;
+
+        match opkind.get_format() {
+            InstFormat::VsFormat | InstFormat::VssFormat | InstFormat::VsxFormat => None,
+            _ => Some(rd),
+        }
+    }
+
+    pub fn parse_rs1(inst: u32, opkind: &VOpcode) -> Option<usize> {
+        let rs1: usize = inst.slice(19, 15) as usize;
+
+        // vs1 field of unary instructions is a part of opcode.
+        match opkind.get_format() {
+            InstFormat::ViFormat
+            | InstFormat::VUnaryFormat
+            | InstFormat::VToXFormat
+            | InstFormat::VToFFormat => None,
+            _ => Some(rs1),
+        }
+    }
+
+    pub fn parse_rs2(inst: u32, opkind: &VOpcode) -> Option<usize> {
+        let rs2: usize = inst.slice(24, 20) as usize;
+
+        match opkind {
+            VOpcode::VSETVLI
+            | VOpcode::VSETIVLI
+            | VOpcode::VMV_V_V
+            | VOpcode::VMV_V_X
+            | VOpcode::VMV_V_I
+            | VOpcode::VMV_S_X
+            | VOpcode::VID_V
+            | VOpcode::VFMV_S_F
+            | VOpcode::VFMV_V_F => None,
+            _ => match opkind.get_format() {
+                InstFormat::VlFormat | InstFormat::VsFormat => None,
+                _ => Some(rs2),
+            },
+        }
+    }
+
+    pub fn parse_rs3(inst: u32, opkind: &VOpcode) -> Option<usize> {
+        let vs3: usize = inst.slice(11, 7) as usize;
+
+        match opkind.get_format() {
+            InstFormat::VsFormat | InstFormat::VssFormat | InstFormat::VsxFormat => Some(vs3),
+            _ => None,
+        }
+    }
+
+    #[allow(clippy::cast_possible_wrap)]
+    pub fn parse_imm(inst: u32, opkind: &VOpcode) -> Option<i32> {
+        let zimm11 = || inst.slice(30, 20) as i32;
+        let zimm10 = || inst.slice(29, 20) as i32;
+        let simm5 = || inst.to_signed_nbit(inst.slice(19, 15) as i32, 5);
+        let uimm5 = || inst.slice(19, 15) as i32;
+
+        match opkind {
+            VOpcode::VSETVLI => Some(zimm11()),
+            VOpcode::VSETIVLI => Some(zimm10()),
+            VOpcode::VADD_VI
+            | VOpcode::VRSUB_VI
+            | VOpcode::VAND_VI
+            | VOpcode::VOR_VI
+            | VOpcode::VXOR_VI
+            | VOpcode::VADC_VIM
+            | VOpcode::VMADC_VIM
+            | VOpcode::VMADC_VI
+            | VOpcode::VMERGE_VIM
+            | VOpcode::VMV_V_I
+            | VOpcode::VMSEQ_VI
+            | VOpcode::VMSNE_VI
+            | VOpcode::VMSLEU_VI
+            | VOpcode::VMSLE_VI
+            | VOpcode::VMSGTU_VI
+            | VOpcode::VMSGT_VI
+            | VOpcode::VSADDU_VI
+            | VOpcode::VSADD_VI => Some(simm5()),
+            VOpcode::VRGATHER_VI
+            | VOpcode::VSLIDEUP_VI
+            | VOpcode::VSLIDEDOWN_VI
+            | VOpcode::VSLL_VI
+            | VOpcode::VSRL_VI
+            | VOpcode::VSRA_VI
+            | VOpcode::VSSRL_VI
+            | VOpcode::VSSRA_VI
+            | VOpcode::VNSRL_WI
+            | VOpcode::VNSRA_WI
+            | VOpcode::VNCLIPU_WI
+            | VOpcode::VNCLIP_WI => Some(uimm5()),
+            _ => None,
+        }
+    }
+
+    pub fn parse_vm(inst: u32, opkind: &VOpcode) -> Option<bool> {
+        let vm: u32 = inst.slice(25, 25);
+
+        match opkind {
+            VOpcode::VSETVLI | VOpcode::VSETIVLI | VOpcode::VSETVL => None,
+            _ => Some(vm == 0b1),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_v {
+    #[test]
+    #[allow(overflowing_literals, clippy::too_many_lines)]
+    fn v_decode_test() {
+        use crate::decode::inst_32::{test_32_in_rv32, test_32_in_rv64};
+        use crate::instruction::v_extension::VOpcode;
+        use crate::{Decode, DecodingError, Isa, OpcodeKind};
+
+        test_32_in_rv64(
+            0x0d05_f557,
+            OpcodeKind::V(VOpcode::VSETVLI),
+            Some(10),
+            Some(11),
+            None,
+            Some(0xd0),
+        );
+        test_32_in_rv32(
+            0xc078_7557,
+            OpcodeKind::V(VOpcode::VSETIVLI),
+            Some(10),
+            Some(16),
+            None,
+            Some(0x7),
+        );
+        test_32_in_rv64(
+            0x80c5_f557,
+            OpcodeKind::V(VOpcode::VSETVL),
+            Some(10),
+            Some(11),
+            Some(12),
+            None,
+        );
+        test_32_in_rv64(
+            0x0205_6407,
+            OpcodeKind::V(VOpcode::VLE32_V),
+            Some(8),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x0ab5_5407,
+            OpcodeKind::V(VOpcode::VLSE16_V),
+            Some(8),
+            Some(10),
+            Some(11),
+            None,
+        );
+        test_32_in_rv64(
+            0x0d05_0407,
+            OpcodeKind::V(VOpcode::VLOXEI8_V),
+            Some(8),
+            Some(10),
+            Some(16),
+            None,
+        );
+        test_32_in_rv64(
+            0x0205_6427,
+            OpcodeKind::V(VOpcode::VSE32_V),
+            None,
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x2285_6407,
+            OpcodeKind::V(VOpcode::VL2RE32_V),
+            Some(8),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x030c_0457,
+            OpcodeKind::V(VOpcode::VADD_VV),
+            Some(8),
+            Some(24),
+            Some(16),
+            None,
+        );
+        test_32_in_rv64(
+            0x030e_b457,
+            OpcodeKind::V(VOpcode::VADD_VI),
+            Some(8),
+            None,
+            Some(16),
+            Some(-3),
+        );
+        test_32_in_rv64(
+            0x970f_b457,
+            OpcodeKind::V(VOpcode::VSLL_VI),
+            Some(8),
+            None,
+            Some(16),
+            Some(31),
+        );
+        test_32_in_rv64(
+            0x5e05_4457,
+            OpcodeKind::V(VOpcode::VMV_V_X),
+            Some(8),
+            Some(10),
+            None,
+            None,
+        );
+        test_32_in_rv64(
+            0x9f00_b457,
+            OpcodeKind::V(VOpcode::VMV2R_V),
+            Some(8),
+            None,
+            Some(16),
+            None,
+        );
+        test_32_in_rv64(
+            0x4300_2557,
+            OpcodeKind::V(VOpcode::VMV_X_S),
+            Some(10),
+            None,
+            Some(16),
+            None,
+        );
+        test_32_in_rv64(
+            0xb788_2457,
+            OpcodeKind::V(VOpcode::VMACC_VV),
+            Some(8),
+            Some(16),
+            Some(24),
+            None,
+        );
+        test_32_in_rv64(
+            0x4300_1557,
+            OpcodeKind::V(VOpcode::VFMV_F_S),
+            Some(10),
+            None,
+            Some(16),
+            None,
+        );
+        test_32_in_rv64(
+            0x4b03_9457,
+            OpcodeKind::V(VOpcode::VFCVT_RTZ_X_F_V),
+            Some(8),
+            None,
+            Some(16),
+            None,
+        );
+
+        // the store data register is placed in rs3.
+        assert_eq!(0x0205_6427_u32.decode(Isa::Rv64).unwrap().rs3, Some(8));
+        // vm = 0 means the instruction is masked by v0.
+        assert_eq!(0x0105_4457_u32.decode(Isa::Rv64).unwrap().vm, Some(false));
+        assert_eq!(0x030c_0457_u32.decode(Isa::Rv64).unwrap().vm, Some(true));
+        assert_eq!(0x0d05_f557_u32.decode(Isa::Rv64).unwrap().vm, None);
+
+        // segment loads/stores (nf != 0) are not supported.
+        assert_eq!(
+            0x2205_6407_u32.decode(Isa::Rv64),
+            Err(DecodingError::InvalidFunct6)
+        );
+        // vmv.s.x requires vs2 = 0.
+        assert_eq!(
+            0x4225_6457_u32.decode(Isa::Rv64),
+            Err(DecodingError::InvalidFunct6)
+        );
+        // vadc.vvm requires vm = 0.
+        assert_eq!(
+            0x4295_0457_u32.decode(Isa::Rv64),
+            Err(DecodingError::ReservedEncoding)
+        );
+        // vmand.mm, vmv1r.v, vl1re8.v and vmv.x.s require vm = 1.
+        assert_eq!(
+            0x645d_a157_u32.decode(Isa::Rv64),
+            Err(DecodingError::ReservedEncoding)
+        );
+        assert_eq!(
+            0x9c60_3e57_u32.decode(Isa::Rv64),
+            Err(DecodingError::ReservedEncoding)
+        );
+        assert_eq!(
+            0x0085_0407_u32.decode(Isa::Rv64),
+            Err(DecodingError::ReservedEncoding)
+        );
+        assert_eq!(
+            0x4080_2557_u32.decode(Isa::Rv64),
+            Err(DecodingError::ReservedEncoding)
+        );
+    }
+
+    #[test]
+    #[allow(overflowing_literals)]
+    fn v_display_test() {
+        use crate::{Decode, Isa};
+
+        let display = |inst: u32| inst.decode(Isa::Rv64).unwrap().to_string();
+
+        assert_eq!(display(0x0d05_f557), "vsetvli a0, a1, e32, m1, ta, ma");
+        assert_eq!(display(0xc078_7557), "vsetivli a0, 16, e8, mf2, tu, mu");
+        assert_eq!(display(0x0105_4457), "vadd.vx v8, v16, a0, v0.t");
+        assert_eq!(display(0x0d05_0407), "vloxei8.v v8, (a0), v16, v0.t");
+        assert_eq!(display(0x08b5_7427), "vsse64.v v8, (a0), a1, v0.t");
+        assert_eq!(display(0x5d0c_0457), "vmerge.vvm v8, v16, v24, v0");
+        assert_eq!(display(0xb785_6457), "vmacc.vx v8, a0, v24");
+        assert_eq!(display(0x5008_a457), "vid.v v8, v0.t");
+        assert_eq!(display(0x4300_1557), "vfmv.f.s fa0, v16");
+    }
+}
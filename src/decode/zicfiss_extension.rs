@@ -67,13 +67,12 @@ pub mod bit_32 {
     }
 
     #[allow(clippy::cast_possible_wrap, clippy::unnecessary_wraps)]
-    pub fn parse_imm(_inst: u32, opkind: &ZicfissOpcode) -> Option<i32> {
+    pub fn parse_imm(inst: u32, opkind: &ZicfissOpcode) -> Option<i32> {
+        let aq_and_rl = || inst.slice(26, 25) as i32;
+
         match opkind {
-            ZicfissOpcode::SSPUSH
-            | ZicfissOpcode::SSPOPCHK
-            | ZicfissOpcode::SSRDP
-            | ZicfissOpcode::SSAMOSWAP_W
-            | ZicfissOpcode::SSAMOSWAP_D => None,
+            ZicfissOpcode::SSPUSH | ZicfissOpcode::SSPOPCHK | ZicfissOpcode::SSRDP => None,
+            ZicfissOpcode::SSAMOSWAP_W | ZicfissOpcode::SSAMOSWAP_D => Some(aq_and_rl()),
             ZicfissOpcode::C_SSPUSH | ZicfissOpcode::C_SSPOPCHK => unreachable!(),
         }
     }
@@ -169,7 +168,7 @@ mod test_zicfiss {
             Some(3),
             Some(5),
             Some(12),
-            None,
+            Some(0),
         );
 
         test_32_in_rv64(
@@ -178,7 +177,7 @@ mod test_zicfiss {
             Some(3),
             Some(14),
             Some(12),
-            None,
+            Some(0),
         );
 
         test_32_in_rv64(
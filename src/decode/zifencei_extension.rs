@@ -4,9 +4,13 @@ pub mod bit_32 {
 
     pub fn parse_opcode(inst: u32) -> Result<ZifenceiOpcode, DecodingError> {
         let opmap: u8 = u8::try_from(inst.slice(6, 0)).unwrap();
+        let funct3: u8 = u8::try_from(inst.slice(14, 12)).unwrap();
 
         match opmap {
-            0b000_1111 => Ok(ZifenceiOpcode::FENCE),
+            0b000_1111 => match funct3 {
+                0b001 => Ok(ZifenceiOpcode::FENCE_I),
+                _ => Err(DecodingError::InvalidFunct3),
+            },
             _ => Err(DecodingError::InvalidOpcode),
         }
     }
@@ -16,7 +20,7 @@ pub mod bit_32 {
         let rd: usize = inst.slice(11, 7) as usize;
 
         match opkind {
-            ZifenceiOpcode::FENCE => Some(rd),
+            ZifenceiOpcode::FENCE_I => Some(rd),
         }
     }
 
@@ -25,22 +29,22 @@ pub mod bit_32 {
         let rs1: usize = inst.slice(19, 15) as usize;
 
         match opkind {
-            ZifenceiOpcode::FENCE => Some(rs1),
+            ZifenceiOpcode::FENCE_I => Some(rs1),
         }
     }
 
     #[allow(clippy::unnecessary_wraps)]
     pub fn parse_rs2(_inst: u32, opkind: &ZifenceiOpcode) -> Option<usize> {
         match opkind {
-            ZifenceiOpcode::FENCE => None,
+            ZifenceiOpcode::FENCE_I => None,
         }
     }
 
     #[allow(clippy::cast_possible_wrap, clippy::unnecessary_wraps)]
     pub fn parse_imm(inst: u32, opkind: &ZifenceiOpcode) -> Option<i32> {
-        let fm_pred_succ: u32 = inst.slice(31, 20);
+        let imm: u32 = inst.slice(31, 20);
         match opkind {
-            ZifenceiOpcode::FENCE => Some(fm_pred_succ as i32),
+            ZifenceiOpcode::FENCE_I => Some(imm as i32),
         }
     }
 }
@@ -56,12 +60,12 @@ mod test_zifenci {
         use crate::OpcodeKind;
 
         test_32_in_rv64(
-            0b0000_0011_0011_0000_0000_0000_0000_1111,
-            OpcodeKind::Zifencei(ZifenceiOpcode::FENCE),
+            0x0000_100f,
+            OpcodeKind::Zifencei(ZifenceiOpcode::FENCE_I),
             Some(0),
             Some(0),
             None,
-            Some(0b0011_0011),
+            Some(0),
         );
     }
 }
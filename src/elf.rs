@@ -0,0 +1,857 @@
+//! Reader of RISC-V ELF files and linear-sweep disassembler.
+
+use alloc::vec::Vec;
+use core::fmt::{self, Display, Formatter};
+use core::str;
+
+use crate::{
+    DecodedInst, Decoder, DecodingError, ExtensionSet, FormatOptions, Isa, SymbolLookup, Symbolizer,
+};
+
+/// `e_machine` value of RISC-V.
+const EM_RISCV: u16 = 243;
+/// `e_type` value of relocatable files.
+const ET_REL: u16 = 1;
+/// Section type of the symbol table.
+const SHT_SYMTAB: u32 = 2;
+/// Section type without file data (e.g. `.bss`).
+const SHT_NOBITS: u32 = 8;
+/// Section type of `.riscv.attributes`.
+const SHT_RISCV_ATTRIBUTES: u32 = 0x7000_0003;
+/// Section flag of executable sections.
+const SHF_EXECINSTR: u64 = 0x4;
+/// Symbol types that name code or data (`STT_NOTYPE`, `STT_OBJECT`, `STT_FUNC`).
+const STT_NAMED: [u8; 3] = [0, 1, 2];
+/// Attribute tag of the file scope.
+const TAG_FILE: u64 = 1;
+/// Attribute tag of the ISA string.
+const TAG_RISCV_ARCH: u64 = 5;
+
+/// Cause of ELF parsing error.
+#[derive(Debug, PartialEq)]
+pub enum ElfError {
+    /// The file does not start with `\x7fELF`.
+    InvalidMagic,
+    /// `EI_CLASS` is neither `ELFCLASS32` nor `ELFCLASS64`.
+    InvalidClass,
+    /// The file is not little-endian.
+    NotLittleEndian,
+    /// `e_machine` is not `EM_RISCV`.
+    NotRiscv,
+    /// The file ends in the middle of the ELF header or the section header table.
+    Truncated,
+    /// The section header at this index is out of range or has an invalid name.
+    InvalidSection(usize),
+}
+
+/// Section of an ELF file.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Section<'a> {
+    /// Section name.
+    pub name: &'a str,
+    /// Section type (`sh_type`).
+    pub sh_type: u32,
+    /// Section flags (`sh_flags`).
+    pub flags: u64,
+    /// Virtual address of the section.
+    pub addr: u64,
+    /// Section contents (empty for `SHT_NOBITS`).
+    pub data: &'a [u8],
+    /// Index of the related section (`sh_link`).
+    pub link: u32,
+}
+
+/// Symbol of an ELF file.
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Symbol<'a> {
+    /// Symbol name.
+    pub name: &'a str,
+    /// Symbol value (address).
+    pub addr: u64,
+    /// Index of the section that defines the symbol.
+    pub section: usize,
+}
+
+impl Section<'_> {
+    /// Return true if the section contains instructions.
+    #[must_use]
+    pub fn is_executable(&self) -> bool {
+        self.flags & SHF_EXECINSTR != 0
+    }
+}
+
+/// RISC-V ELF file.
+///
+/// # Example
+/// ```
+/// use raki::{ElfFile, Isa};
+///
+/// let bytes = include_bytes!("../tests/fixtures/hello64.o");
+/// let elf = ElfFile::parse(bytes).unwrap();
+/// assert_eq!(elf.isa(), Isa::Rv64);
+/// assert_eq!(elf.arch(), Some("rv64i2p0_m2p0_a2p0_c2p0"));
+///
+/// let text = elf.executable_sections().next().unwrap();
+/// assert_eq!(text.name, ".text");
+/// ```
+#[derive(Debug, Copy, Clone)]
+pub struct ElfFile<'a> {
+    bytes: &'a [u8],
+    isa: Isa,
+    /// Symbol values are offsets from their sections.
+    is_relocatable: bool,
+    shoff: usize,
+    shentsize: usize,
+    shnum: usize,
+    shstrndx: usize,
+}
+
+impl<'a> ElfFile<'a> {
+    /// Parse the ELF header and the section header table.
+    ///
+    /// # Errors
+    /// It will throws an error if the file is not a little-endian RISC-V ELF file
+    /// or its section header table is broken.
+    pub fn parse(bytes: &'a [u8]) -> Result<Self, ElfError> {
+        if bytes.get(..4) != Some(b"\x7fELF") {
+            return Err(ElfError::InvalidMagic);
+        }
+        let isa = match bytes.get(4) {
+            Some(1) => Isa::Rv32,
+            Some(2) => Isa::Rv64,
+            _ => return Err(ElfError::InvalidClass),
+        };
+        if bytes.get(5) != Some(&1) {
+            return Err(ElfError::NotLittleEndian);
+        }
+        if read_u16(bytes, 18).ok_or(ElfError::Truncated)? != EM_RISCV {
+            return Err(ElfError::NotRiscv);
+        }
+
+        let (shoff, shentsize) = match isa {
+            Isa::Rv32 => (read_u32(bytes, 32).map(u64::from), 46),
+            Isa::Rv64 => (read_u64(bytes, 40), 58),
+        };
+        let field = |offset| {
+            read_u16(bytes, offset)
+                .map(usize::from)
+                .ok_or(ElfError::Truncated)
+        };
+        let elf = ElfFile {
+            bytes,
+            isa,
+            is_relocatable: read_u16(bytes, 16) == Some(ET_REL),
+            shoff: shoff
+                .and_then(|off| usize::try_from(off).ok())
+                .ok_or(ElfError::Truncated)?,
+            shentsize: field(shentsize)?,
+            shnum: field(shentsize + 2)?,
+            shstrndx: field(shentsize + 4)?,
+        };
+
+        let table_size = elf.shentsize.checked_mul(elf.shnum);
+        if table_size
+            .and_then(|size| elf.shoff.checked_add(size))
+            .is_none_or(|end| end > bytes.len())
+        {
+            return Err(ElfError::Truncated);
+        }
+        for index in 0..elf.shnum {
+            elf.section(index)?;
+        }
+
+        Ok(elf)
+    }
+
+    /// Isa width taken from `EI_CLASS`.
+    #[must_use]
+    pub fn isa(&self) -> Isa {
+        self.isa
+    }
+
+    /// Return all sections.
+    pub fn sections(&self) -> impl Iterator<Item = Section<'a>> + '_ {
+        // every section header has been validated by `parse`.
+        (0..self.shnum).filter_map(|index| self.section(index).ok())
+    }
+
+    /// Return sections that contain instructions.
+    pub fn executable_sections(&self) -> impl Iterator<Item = Section<'a>> + '_ {
+        self.sections().filter(Section::is_executable)
+    }
+
+    /// Return the section that has the given name.
+    #[must_use]
+    pub fn section_by_name(&self, name: &str) -> Option<Section<'a>> {
+        self.sections().find(|section| section.name == name)
+    }
+
+    /// ISA string recorded in `.riscv.attributes` (e.g. `rv64i2p1_m2p0_a2p1_c2p0`).
+    #[must_use]
+    pub fn arch(&self) -> Option<&'a str> {
+        self.sections()
+            .find(|section| section.sh_type == SHT_RISCV_ATTRIBUTES)
+            .and_then(|section| parse_arch_attribute(section.data))
+    }
+
+    /// Return symbols of functions, objects and labels defined in the file.
+    ///
+    /// Mapping symbols (e.g. `$x`) are skipped.
+    pub fn symbols(&self) -> impl Iterator<Item = Symbol<'a>> + '_ {
+        let symtab = self
+            .sections()
+            .find(|section| section.sh_type == SHT_SYMTAB);
+        let strtab = symtab.and_then(|symtab| {
+            let link = usize::try_from(symtab.link).ok()?;
+            self.section(link).ok().map(|strtab| strtab.data)
+        });
+        let entsize = match self.isa {
+            Isa::Rv32 => 16,
+            Isa::Rv64 => 24,
+        };
+
+        symtab
+            .zip(strtab)
+            .into_iter()
+            .flat_map(move |(symtab, strtab)| {
+                // the first entry is reserved.
+                let entries = symtab.data.chunks_exact(entsize).skip(1);
+                entries.map(move |entry| (entry, strtab))
+            })
+            .filter_map(move |(entry, strtab)| self.symbol(entry, strtab))
+    }
+
+    /// Disassemble all executable sections like `objdump -d`.
+    ///
+    /// # Example
+    /// ```
+    /// use raki::ElfFile;
+    ///
+    /// let bytes = include_bytes!("../tests/fixtures/hello64.o");
+    /// let elf = ElfFile::parse(bytes).unwrap();
+    /// let listing = elf.disassemble().to_string();
+    /// assert!(listing.starts_with("Disassembly of section .text:\n"));
+    /// assert!(listing.contains("       4:\t02b50533\tmul a0, a0, a1\n"));
+    /// ```
+    #[must_use]
+    pub fn disassemble(&self) -> Listing<'_, 'a> {
+        Listing {
+            elf: self,
+            isa: self.isa,
+            extensions: ExtensionSet::all(),
+            opts: FormatOptions::new().with_aliases(true),
+            objdump: false,
+        }
+    }
+
+    /// Return the bytes from the section header at `index`.
+    fn section_header(&self, index: usize) -> Option<&'a [u8]> {
+        if index >= self.shnum {
+            return None;
+        }
+        let offset = index.checked_mul(self.shentsize)?.checked_add(self.shoff)?;
+        self.bytes.get(offset..)
+    }
+
+    fn section(&self, index: usize) -> Result<Section<'a>, ElfError> {
+        let invalid = || ElfError::InvalidSection(index);
+        let header = self.section_header(index).ok_or_else(invalid)?;
+
+        let (sh_name, sh_type) = (read_u32(header, 0), read_u32(header, 4));
+        let (flags, addr, offset, size, link) = match self.isa {
+            Isa::Rv32 => (
+                read_u32(header, 8).map(u64::from),
+                read_u32(header, 12).map(u64::from),
+                read_u32(header, 16).map(u64::from),
+                read_u32(header, 20).map(u64::from),
+                read_u32(header, 24),
+            ),
+            Isa::Rv64 => (
+                read_u64(header, 8),
+                read_u64(header, 16),
+                read_u64(header, 24),
+                read_u64(header, 32),
+                read_u32(header, 40),
+            ),
+        };
+        let (
+            Some(sh_name),
+            Some(sh_type),
+            Some(flags),
+            Some(addr),
+            Some(offset),
+            Some(size),
+            Some(link),
+        ) = (sh_name, sh_type, flags, addr, offset, size, link)
+        else {
+            return Err(invalid());
+        };
+
+        let data = if sh_type == SHT_NOBITS {
+            &[]
+        } else {
+            slice(self.bytes, offset, size).ok_or_else(invalid)?
+        };
+        let name = if index == 0 {
+            ""
+        } else {
+            self.section_name(sh_name).ok_or_else(invalid)?
+        };
+
+        Ok(Section {
+            name,
+            sh_type,
+            flags,
+            addr,
+            data,
+            link,
+        })
+    }
+
+    fn symbol(&self, entry: &[u8], strtab: &'a [u8]) -> Option<Symbol<'a>> {
+        let (info, shndx, addr) = match self.isa {
+            Isa::Rv32 => (
+                entry[12],
+                read_u16(entry, 14)?,
+                u64::from(read_u32(entry, 4)?),
+            ),
+            Isa::Rv64 => (entry[4], read_u16(entry, 6)?, read_u64(entry, 8)?),
+        };
+        // skip undefined and special (e.g. `SHN_ABS`) section indexes.
+        if shndx == 0 || shndx >= 0xff00 || !STT_NAMED.contains(&(info & 0xf)) {
+            return None;
+        }
+
+        let name = read_str(strtab.get(usize::try_from(read_u32(entry, 0)?).ok()?..)?)?;
+        if name.is_empty() || name.starts_with('$') {
+            return None;
+        }
+        let addr = if self.is_relocatable {
+            self.section(usize::from(shndx))
+                .ok()?
+                .addr
+                .wrapping_add(addr)
+        } else {
+            addr
+        };
+
+        Some(Symbol {
+            name,
+            addr,
+            section: usize::from(shndx),
+        })
+    }
+
+    fn section_name(&self, sh_name: u32) -> Option<&'a str> {
+        let header = self.section_header(self.shstrndx)?;
+        let (offset, size) = match self.isa {
+            Isa::Rv32 => (
+                u64::from(read_u32(header, 16)?),
+                u64::from(read_u32(header, 20)?),
+            ),
+            Isa::Rv64 => (read_u64(header, 24)?, read_u64(header, 32)?),
+        };
+        let strtab = slice(self.bytes, offset, size)?;
+        read_str(strtab.get(usize::try_from(sh_name).ok()?..)?)
+    }
+}
+
+impl SymbolLookup for ElfFile<'_> {
+    fn lookup(&self, addr: u64) -> Option<(&str, u64)> {
+        nearest(self.symbols(), addr)
+    }
+}
+
+/// Symbols used to resolve addresses in a section.
+///
+/// Sections of relocatable files overlap, so only the symbols of the section are used.
+struct SectionSymbols<'a> {
+    /// Symbols sorted by address (symbols at the same address keep the symbol table order).
+    symbols: Vec<Symbol<'a>>,
+}
+
+impl<'a> SectionSymbols<'a> {
+    fn new(elf: &ElfFile<'a>, index: usize) -> Self {
+        let mut symbols: Vec<Symbol<'a>> = elf
+            .symbols()
+            .filter(|symbol| !elf.is_relocatable || symbol.section == index)
+            .collect();
+        symbols.sort_by_key(|symbol| symbol.addr);
+        SectionSymbols { symbols }
+    }
+
+    /// Find the first symbol of the section `index` defined at `addr`.
+    fn label(&self, index: usize, addr: u64) -> Option<&'a str> {
+        let first = self.symbols.partition_point(|symbol| symbol.addr < addr);
+        self.symbols[first..]
+            .iter()
+            .take_while(|symbol| symbol.addr == addr)
+            .find(|symbol| symbol.section == index)
+            .map(|symbol| symbol.name)
+    }
+}
+
+impl SymbolLookup for SectionSymbols<'_> {
+    fn lookup(&self, addr: u64) -> Option<(&str, u64)> {
+        let end = self.symbols.partition_point(|symbol| symbol.addr <= addr);
+        let nearest = self.symbols.get(end.checked_sub(1)?)?.addr;
+        // the first symbol wins if several symbols share the address.
+        let first = self.symbols.partition_point(|symbol| symbol.addr < nearest);
+        let symbol = &self.symbols[first];
+        Some((symbol.name, addr - symbol.addr))
+    }
+}
+
+/// Find the symbol at or before `addr` and the offset from it.
+fn nearest<'a>(symbols: impl Iterator<Item = Symbol<'a>>, addr: u64) -> Option<(&'a str, u64)> {
+    symbols
+        .filter(|symbol| symbol.addr <= addr)
+        .fold(None, |nearest: Option<Symbol>, symbol| match nearest {
+            Some(nearest) if nearest.addr >= symbol.addr => Some(nearest),
+            _ => Some(symbol),
+        })
+        .map(|symbol| (symbol.name, addr - symbol.addr))
+}
+
+/// Address-annotated listing of executable sections.
+///
+/// Symbols are printed as labels and used to resolve branch/jump targets.
+/// Undecodable 16bit units are printed as `.2byte`, then disassembling resumes at the next 16bit boundary.
+/// Pseudo-instructions are printed by default like `objdump`.
+/// `with_objdump` switches to the exact layout of `objdump -d -M no-aliases`.
+pub struct Listing<'e, 'a> {
+    elf: &'e ElfFile<'a>,
+    isa: Isa,
+    extensions: ExtensionSet,
+    opts: FormatOptions,
+    objdump: bool,
+}
+
+impl Listing<'_, '_> {
+    /// Decode instructions for `isa` instead of the one taken from `EI_CLASS`.
+    #[must_use]
+    pub fn with_isa(self, isa: Isa) -> Self {
+        Listing { isa, ..self }
+    }
+
+    /// Restrict decoding to the enabled extensions.
+    #[must_use]
+    pub fn with_extensions(self, extensions: ExtensionSet) -> Self {
+        Listing { extensions, ..self }
+    }
+
+    /// Print pseudo-instructions (e.g. `li`, `ret`) if `aliases` is true.
+    #[must_use]
+    pub fn with_aliases(self, aliases: bool) -> Self {
+        Listing {
+            opts: self.opts.with_aliases(aliases),
+            ..self
+        }
+    }
+
+    /// Format instructions with `opts` (which also decides whether pseudo-instructions are printed).
+    #[must_use]
+    pub fn with_options(self, opts: &FormatOptions) -> Self {
+        Listing {
+            opts: *opts,
+            ..self
+        }
+    }
+
+    /// Print the listing like `objdump -d -M no-aliases` if `objdump` is true.
+    #[must_use]
+    pub fn with_objdump(self, objdump: bool) -> Self {
+        Listing { objdump, ..self }
+    }
+
+    /// Print an instruction line in the layout of objdump.
+    fn fmt_objdump_line(
+        f: &mut Formatter,
+        symbolizer: &mut Symbolizer<'_, SectionSymbols<'_>>,
+        inst: &DecodedInst,
+        addr: u64,
+        width: usize,
+    ) -> fmt::Result {
+        write!(f, "{addr:>width$x}:\t")?;
+        match (&inst.result, inst.len) {
+            (Ok(decoded), 4) => writeln!(
+                f,
+                "{:08x}          \t{}",
+                inst.raw,
+                symbolizer.format(decoded, addr)
+            ),
+            (Ok(decoded), _) => writeln!(
+                f,
+                "{:04x}                \t{}",
+                inst.raw,
+                symbolizer.format(decoded, addr)
+            ),
+            (Err(DecodingError::TruncatedInst), _) => {
+                writeln!(
+                    f,
+                    "{:02x}                  \t.byte\t0x{:x}",
+                    inst.raw, inst.raw
+                )
+            }
+            (Err(_), 4) => writeln!(f, "{:08x}          \t.4byte\t0x{:x}", inst.raw, inst.raw),
+            (Err(_), _) => writeln!(
+                f,
+                "{:04x}                \t.2byte\t0x{:x}",
+                inst.raw, inst.raw
+            ),
+        }
+    }
+}
+
+/// Width of the address column of objdump.
+///
+/// Leading zeros of the section end address are dropped in chunks of 4 digits,
+/// leaving at least one zero.
+/// The width is clamped to the isa width if the section ends beyond the address space.
+fn objdump_addr_width(isa: Isa, section: &Section) -> usize {
+    let width = match isa {
+        Isa::Rv32 => 8,
+        Isa::Rv64 => 16,
+    };
+    let Some(end) = section.addr.checked_add(section.data.len() as u64) else {
+        return width;
+    };
+    match width.saturating_sub((64 - end.leading_zeros() as usize).div_ceil(4)) {
+        0 => width,
+        zeros => width - ((zeros - 1) & !3),
+    }
+}
+
+impl Display for Listing<'_, '_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let executable = self
+            .elf
+            .sections()
+            .enumerate()
+            .filter(|(_, section)| section.is_executable());
+        for (nth, (index, section)) in executable.enumerate() {
+            if nth != 0 {
+                writeln!(f)?;
+            }
+            writeln!(f, "Disassembly of section {}:", section.name)?;
+
+            let symbols = SectionSymbols::new(self.elf, index);
+            let mut symbolizer = Symbolizer::new(self.isa)
+                .with_symbols(&symbols)
+                .with_options(&self.opts)
+                .with_objdump(self.objdump);
+            let width = objdump_addr_width(self.isa, &section);
+            // objdump skips the whole invalid instruction instead of resyncing.
+            let mut next = 0;
+            for inst in Decoder::new(section.data, self.isa).with_extensions(self.extensions) {
+                if inst.offset < next {
+                    continue;
+                }
+                let addr = section.addr.wrapping_add(inst.offset as u64);
+                let label = symbols
+                    .label(index, addr)
+                    // objdump labels the section start by the section name.
+                    .or(Some(section.name).filter(|_| self.objdump && inst.offset == 0));
+                match (label, self.isa) {
+                    (Some(label), Isa::Rv32) => writeln!(f, "\n{addr:08x} <{label}>:")?,
+                    (Some(label), Isa::Rv64) => writeln!(f, "\n{addr:016x} <{label}>:")?,
+                    (None, _) if inst.offset == 0 => writeln!(f)?,
+                    (None, _) => (),
+                }
+
+                if self.objdump {
+                    next = inst.offset + inst.len;
+                    Self::fmt_objdump_line(f, &mut symbolizer, &inst, addr, width)?;
+                    continue;
+                }
+
+                match inst.result {
+                    Ok(decoded) if inst.len == 4 => writeln!(
+                        f,
+                        "{addr:8x}:\t{:08x}\t{}",
+                        inst.raw,
+                        symbolizer.format(&decoded, addr)
+                    )?,
+                    Ok(decoded) => writeln!(
+                        f,
+                        "{addr:8x}:\t{:04x}    \t{}",
+                        inst.raw,
+                        symbolizer.format(&decoded, addr)
+                    )?,
+                    Err(DecodingError::TruncatedInst) if section.data.len() - inst.offset == 1 => {
+                        writeln!(
+                            f,
+                            "{addr:8x}:\t{:02x}      \t.byte 0x{:x}",
+                            inst.raw, inst.raw
+                        )?;
+                    }
+                    Err(_) => {
+                        let half = inst.raw & 0xffff;
+                        writeln!(f, "{addr:8x}:\t{half:04x}    \t.2byte 0x{half:x}")?;
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Find `Tag_RISCV_arch` in the contents of `.riscv.attributes`.
+fn parse_arch_attribute(data: &[u8]) -> Option<&str> {
+    let (format, mut rest) = data.split_first()?;
+    if *format != b'A' {
+        return None;
+    }
+
+    while !rest.is_empty() {
+        // <length: u32> <vendor-name: NTBS> <sub-subsections>
+        let subsection = slice(rest, 0, u64::from(read_u32(rest, 0)?))?;
+        rest = &rest[subsection.len()..];
+        let vendor = read_str(subsection.get(4..)?)?;
+        if vendor != "riscv" {
+            continue;
+        }
+
+        let mut attrs = &subsection[4 + vendor.len() + 1..];
+        while !attrs.is_empty() {
+            // <tag: uleb128> <length: u32> <attributes>
+            let (tag, tag_len) = read_uleb128(attrs)?;
+            let size = usize::try_from(read_u32(attrs, tag_len)?).ok()?;
+            // the length includes the tag and itself.
+            if size < tag_len + 4 {
+                return None;
+            }
+            let sub = attrs.get(..size)?;
+            attrs = &attrs[size..];
+            if tag != TAG_FILE {
+                continue;
+            }
+
+            let mut body = sub.get(tag_len + 4..)?;
+            while !body.is_empty() {
+                let (tag, tag_len) = read_uleb128(body)?;
+                body = &body[tag_len..];
+                // odd tags have a NTBS value and even tags have a uleb128 value.
+                if tag % 2 == 1 {
+                    let value = read_str(body)?;
+                    if tag == TAG_RISCV_ARCH {
+                        return Some(value);
+                    }
+                    body = &body[value.len() + 1..];
+                } else {
+                    body = &body[read_uleb128(body)?.1..];
+                }
+            }
+        }
+    }
+
+    None
+}
+
+fn slice(bytes: &[u8], offset: u64, size: u64) -> Option<&[u8]> {
+    let start = usize::try_from(offset).ok()?;
+    let end = start.checked_add(usize::try_from(size).ok()?)?;
+    bytes.get(start..end)
+}
+
+fn read_u16(bytes: &[u8], offset: usize) -> Option<u16> {
+    Some(u16::from_le_bytes(
+        bytes.get(offset..offset + 2)?.try_into().ok()?,
+    ))
+}
+
+fn read_u32(bytes: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_le_bytes(
+        bytes.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+fn read_u64(bytes: &[u8], offset: usize) -> Option<u64> {
+    Some(u64::from_le_bytes(
+        bytes.get(offset..offset + 8)?.try_into().ok()?,
+    ))
+}
+
+/// Read a NUL-terminated string.
+fn read_str(bytes: &[u8]) -> Option<&str> {
+    let len = bytes.iter().position(|c| *c == 0)?;
+    str::from_utf8(&bytes[..len]).ok()
+}
+
+/// Read a uleb128 value and return it with the number of bytes read.
+fn read_uleb128(bytes: &[u8]) -> Option<(u64, usize)> {
+    let mut value = 0;
+    for (i, byte) in bytes.iter().enumerate().take(10) {
+        value |= u64::from(byte & 0x7f) << (7 * i);
+        if byte & 0x80 == 0 {
+            return Some((value, i + 1));
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test_elf {
+    use super::{objdump_addr_width, parse_arch_attribute, ElfError, ElfFile, Section, Symbol};
+    use crate::{FormatOptions, Isa, SymbolLookup};
+
+    const HELLO32: &[u8] = include_bytes!("../tests/fixtures/hello32.o");
+    const HELLO64: &[u8] = include_bytes!("../tests/fixtures/hello64.o");
+
+    #[test]
+    fn elf_parse_test() {
+        let elf = ElfFile::parse(HELLO32).unwrap();
+        assert_eq!(elf.isa(), Isa::Rv32);
+        assert_eq!(elf.arch(), Some("rv32i2p0_m2p0_a2p0_c2p0"));
+        let names: Vec<&str> = elf.executable_sections().map(|s| s.name).collect();
+        assert_eq!(names, vec![".text", ".text.init"]);
+
+        // a sub-subsection shorter than its own header is rejected.
+        let attributes = b"A\x0f\0\0\0riscv\0\x02\0\0\0\0";
+        assert_eq!(parse_arch_attribute(attributes), None);
+        let attributes = b"A\x13\0\0\0riscv\0\x01\x09\0\0\0\x05rv\0";
+        assert_eq!(parse_arch_attribute(attributes), Some("rv"));
+
+        let elf = ElfFile::parse(HELLO64).unwrap();
+        assert_eq!(elf.isa(), Isa::Rv64);
+        assert_eq!(elf.section_by_name(".text").unwrap().data.len(), 28);
+        assert_eq!(elf.section_by_name(".data"), None);
+        let symbols: Vec<Symbol> = elf.symbols().collect();
+        assert_eq!(
+            symbols,
+            vec![
+                Symbol {
+                    name: "memcpy",
+                    addr: 0x14,
+                    section: 2
+                },
+                Symbol {
+                    name: "_start",
+                    addr: 0,
+                    section: 2
+                },
+            ]
+        );
+
+        assert_eq!(
+            ElfFile::parse(&HELLO64[1..]).err(),
+            Some(ElfError::InvalidMagic)
+        );
+        assert_eq!(
+            ElfFile::parse(&HELLO64[..0x40]).err(),
+            Some(ElfError::Truncated)
+        );
+        let mut big_endian = HELLO64.to_vec();
+        big_endian[5] = 2;
+        assert_eq!(
+            ElfFile::parse(&big_endian).err(),
+            Some(ElfError::NotLittleEndian)
+        );
+        let mut x86 = HELLO64.to_vec();
+        x86[18] = 62;
+        assert_eq!(ElfFile::parse(&x86).err(), Some(ElfError::NotRiscv));
+        // e_shstrndx out of the section header table.
+        let mut shstrndx = HELLO64.to_vec();
+        shstrndx[62..64].copy_from_slice(&0xffff_u16.to_le_bytes());
+        assert_eq!(
+            ElfFile::parse(&shstrndx).err(),
+            Some(ElfError::InvalidSection(1))
+        );
+    }
+
+    #[test]
+    fn objdump_addr_width_test() {
+        let section = |addr, data| Section {
+            name: ".text",
+            sh_type: 1,
+            flags: 0x6,
+            addr,
+            data,
+            link: 0,
+        };
+
+        assert_eq!(objdump_addr_width(Isa::Rv64, &section(0, &[0; 28])), 4);
+        assert_eq!(
+            objdump_addr_width(Isa::Rv64, &section(0x8000_0000, &[0; 28])),
+            12
+        );
+        assert_eq!(objdump_addr_width(Isa::Rv32, &section(0, &[])), 4);
+        // the section ends at 2^32 on RV32 or at 2^64 on RV64.
+        assert_eq!(
+            objdump_addr_width(Isa::Rv32, &section(0xffff_fff0, &[0; 16])),
+            8
+        );
+        assert_eq!(
+            objdump_addr_width(Isa::Rv64, &section(u64::MAX - 15, &[0; 16])),
+            16
+        );
+    }
+
+    #[test]
+    fn elf_disassemble_test() {
+        // relocate `.text` (the section header #2) to 0x8000_0000.
+        let mut bytes = HELLO64.to_vec();
+        let shoff = usize::try_from(u64::from_le_bytes(bytes[40..48].try_into().unwrap())).unwrap();
+        let sh_addr = shoff + 2 * 64 + 16;
+        bytes[sh_addr..sh_addr + 8].copy_from_slice(&0x8000_0000_u64.to_le_bytes());
+
+        let elf = ElfFile::parse(&bytes).unwrap();
+        assert_eq!(elf.lookup(0x8000_0016), Some(("memcpy", 2)));
+        let listing = elf.disassemble().to_string();
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(lines[0], "Disassembly of section .text:");
+        assert_eq!(lines[2], "0000000080000000 <_start>:");
+        assert_eq!(lines[5], "80000004:\t02b50533\tmul a0, a0, a1");
+        assert_eq!(lines[6], "80000008:\t0000    \t.2byte 0x0");
+        assert_eq!(lines[8], "8000000e:\t006000ef\tjal 0x80000014 <memcpy>");
+        assert_eq!(lines[11], "0000000080000014 <memcpy>:");
+        assert_eq!(lines[14], "80000018:\tbff5    \tj 0x80000014 <memcpy>");
+        assert_eq!(lines[17], "Disassembly of section .text.init:");
+        assert_eq!(lines[19], "       0:\t05d00893\tli a7, 93");
+        assert_eq!(lines.len(), 21);
+
+        let listing = elf.disassemble().with_aliases(false).to_string();
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(lines[8], "8000000e:\t006000ef\tjal ra, 0x80000014 <memcpy>");
+        assert_eq!(lines[14], "80000018:\tbff5    \tC.j 0x80000014 <memcpy>");
+        assert_eq!(lines[19], "       0:\t05d00893\taddi a7, zero, 93");
+
+        let opts = FormatOptions::new()
+            .with_numeric_names(true)
+            .with_aliases(true);
+        let listing = elf.disassemble().with_options(&opts).to_string();
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(lines[5], "80000004:\t02b50533\tmul x10, x10, x11");
+        assert_eq!(lines[19], "       0:\t05d00893\tli x17, 93");
+
+        let listing = elf.disassemble().with_objdump(true).to_string();
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(
+            lines[3],
+            "    80000000:\t0505                \tc.addi\ta0,1"
+        );
+        assert_eq!(lines[6], "    80000008:\t0000                \t.2byte\t0x0");
+        assert_eq!(
+            lines[8],
+            "    8000000e:\t006000ef          \tjal\tra,80000014 <memcpy>"
+        );
+        assert_eq!(lines[19], "0000000000000000 <.text.init>:");
+        assert_eq!(lines[20], "   0:\t05d00893          \taddi\ta7,zero,93");
+
+        // `.text` wraps around the end of the address space.
+        bytes[sh_addr..sh_addr + 8].copy_from_slice(&(u64::MAX - 3).to_le_bytes());
+        let elf = ElfFile::parse(&bytes).unwrap();
+        let listing = elf.disassemble().with_objdump(true).to_string();
+        let lines: Vec<&str> = listing.lines().collect();
+        assert_eq!(
+            lines[3],
+            "fffffffffffffffc:\t0505                \tc.addi\ta0,1"
+        );
+        assert_eq!(
+            lines[5],
+            "               0:\t02b50533          \tmul\ta0,a0,a1"
+        );
+    }
+}
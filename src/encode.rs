@@ -0,0 +1,153 @@
+//! Implementation of encoder.
+
+mod inst_16;
+mod inst_32;
+
+mod a_extension;
+mod b_extension;
+mod base_i;
+mod c_extension;
+mod d_extension;
+mod f_extension;
+mod m_extension;
+mod priv_extension;
+mod q_extension;
+mod v_extension;
+mod zicboz_extension;
+mod zicfiss_extension;
+mod zicntr_extension;
+mod zicsr_extension;
+mod zifencei_extension;
+
+use crate::instruction::Instruction;
+use crate::Isa;
+
+/// Return Err if given opcode is only available on Rv64.
+fn only_rv64(isa: Isa) -> Result<(), EncodingError> {
+    match isa {
+        Isa::Rv32 => Err(EncodingError::OnlyRv64Inst),
+        Isa::Rv64 => Ok(()),
+    }
+}
+
+/// Cause of encoding error.
+///
+/// # Example
+/// ```
+/// use raki::{BaseIOpcode, Encode, EncodingError, InstFormat, Instruction, Isa, OpcodeKind};
+///
+/// // try to encode the immediate that does not fit in 12 bits.
+/// let inst = Instruction {
+///     opc: OpcodeKind::BaseI(BaseIOpcode::ADDI),
+///     rd: Some(10),
+///     rs1: Some(10),
+///     rs2: None,
+///     rs3: None,
+///     imm: Some(4096),
+///     vm: None,
+///     rm: None,
+///     inst_format: InstFormat::IFormat,
+///     is_compressed: false,
+/// };
+/// assert_eq!(u32::encode(&inst, Isa::Rv64), Err(EncodingError::ImmediateOutOfRange));
+/// ```
+#[derive(Debug, PartialEq)]
+pub enum EncodingError {
+    /// 16bit encoding is requested, but it is not a compressed instruction.
+    Not16BitInst,
+    /// 32bit encoding is requested, but it is a compressed instruction.
+    Not32BitInst,
+    /// A register or immediate required by the instruction is `None`.
+    MissingOperand,
+    /// The register number cannot be encoded in the instruction.
+    InvalidRegister,
+    /// The immediate does not fit in the instruction field.
+    ImmediateOutOfRange,
+    /// The immediate is not aligned to the granularity of the instruction field.
+    MisalignedImmediate,
+    /// This instruction is only for Rv64 but is encoded for Rv32.
+    OnlyRv64Inst,
+    /// This instruction is only for Rv32 but is encoded for Rv64.
+    OnlyRv32Inst,
+    /// The operands select an encoding reserved by the spec (e.g. a masked `vmand.mm`).
+    ReservedEncoding,
+}
+
+/// A trait to encode an instruction into u16/u32.
+/// This trait provides public api.
+///
+/// # Usage
+/// `encode` is implemented for u16/u32 as the inverse of `Decode::decode`.
+/// ```
+/// use raki::{Decode, Encode, Isa};
+///
+/// let bits: u32 = 0b1110_1110_1100_0010_1000_0010_1001_0011;
+/// let inst = bits.decode(Isa::Rv64).unwrap();
+/// assert_eq!(u32::encode(&inst, Isa::Rv64), Ok(bits));
+/// ```
+pub trait Encode: Sized {
+    /// Encode an instruction into u16/u32.
+    ///
+    /// # Errors
+    /// It will throws an error if the instruction cannot be represented in this width,
+    /// or if its registers or immediate are out of range.
+    fn encode(inst: &Instruction, isa: Isa) -> Result<Self, EncodingError>;
+}
+
+/// Get the register number, checking that it fits in 5 bits.
+fn reg(field: Option<usize>) -> Result<u32, EncodingError> {
+    match field {
+        Some(num @ 0..=31) => Ok(u32::try_from(num).unwrap()),
+        Some(_) => Err(EncodingError::InvalidRegister),
+        None => Err(EncodingError::MissingOperand),
+    }
+}
+
+/// Get the immediate value.
+fn imm(field: Option<i32>) -> Result<i32, EncodingError> {
+    field.ok_or(EncodingError::MissingOperand)
+}
+
+/// Check that the immediate fits in `bit_size` bits as a signed number
+/// and is a multiple of `align`.
+#[allow(clippy::cast_sign_loss)]
+fn signed(imm: i32, bit_size: u32, align: i32) -> Result<u32, EncodingError> {
+    let limit = 1_i64 << (bit_size - 1);
+    if !(-limit..limit).contains(&i64::from(imm)) {
+        return Err(EncodingError::ImmediateOutOfRange);
+    }
+    if imm % align != 0 {
+        return Err(EncodingError::MisalignedImmediate);
+    }
+
+    Ok(imm as u32 & ((1 << bit_size) - 1))
+}
+
+/// Check that the immediate fits in `bit_size` bits as an unsigned number
+/// and is a multiple of `align`.
+#[allow(clippy::cast_sign_loss)]
+fn unsigned(imm: i32, bit_size: u32, align: i32) -> Result<u32, EncodingError> {
+    if imm < 0 || i64::from(imm) >= 1_i64 << bit_size {
+        return Err(EncodingError::ImmediateOutOfRange);
+    }
+    if imm % align != 0 {
+        return Err(EncodingError::MisalignedImmediate);
+    }
+
+    Ok(imm as u32)
+}
+
+/// The inverse of `DecodeUtil::set`.
+/// The bits of `value` at the array value positions are gathered in order from the highest to the lowest.
+/// ```ignore
+/// let gathered = gather(0b1111_1000, &[7, 5, 3, 2, 0, 6, 4, 1]);
+/// assert_eq!(gathered, 0b1010_1101);
+/// ```
+fn gather(value: u32, mask: &[u32]) -> u32 {
+    let mut field: u32 = 0;
+    for (i, m) in mask.iter().rev().enumerate() {
+        field |= ((value >> m) & 0x1) << i;
+    }
+
+    field
+}
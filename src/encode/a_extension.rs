@@ -0,0 +1,68 @@
+pub mod bit_32 {
+    use super::super::inst_32::r_type;
+    use super::super::{only_rv64, reg, unsigned, EncodingError};
+    use crate::instruction::{a_extension::AOpcode, Instruction};
+    use crate::Isa;
+
+    pub fn encode(inst: &Instruction, opc: &AOpcode, isa: Isa) -> Result<u32, EncodingError> {
+        // aq and rl bits are stored in imm.
+        let aq_and_rl = || unsigned(inst.imm.unwrap_or(0), 2, 1);
+        let amo = |funct3, funct5: u32, rs2| {
+            Ok(r_type(
+                0b010_1111,
+                reg(inst.rd)?,
+                funct3,
+                reg(inst.rs1)?,
+                rs2,
+                funct5 << 2 | aq_and_rl()?,
+            ))
+        };
+        let amo_w = |funct5| amo(0b010, funct5, reg(inst.rs2)?);
+        let amo_d = |funct5| {
+            only_rv64(isa)?;
+            amo(0b011, funct5, reg(inst.rs2)?)
+        };
+
+        match opc {
+            AOpcode::LR_W => amo(0b010, 0b00010, 0),
+            AOpcode::SC_W => amo_w(0b00011),
+            AOpcode::AMOSWAP_W => amo_w(0b00001),
+            AOpcode::AMOADD_W => amo_w(0b00000),
+            AOpcode::AMOXOR_W => amo_w(0b00100),
+            AOpcode::AMOAND_W => amo_w(0b01100),
+            AOpcode::AMOOR_W => amo_w(0b01000),
+            AOpcode::AMOMIN_W => amo_w(0b10000),
+            AOpcode::AMOMAX_W => amo_w(0b10100),
+            AOpcode::AMOMINU_W => amo_w(0b11000),
+            AOpcode::AMOMAXU_W => amo_w(0b11100),
+            AOpcode::LR_D => {
+                only_rv64(isa)?;
+                amo(0b011, 0b00010, 0)
+            }
+            AOpcode::SC_D => amo_d(0b00011),
+            AOpcode::AMOSWAP_D => amo_d(0b00001),
+            AOpcode::AMOADD_D => amo_d(0b00000),
+            AOpcode::AMOXOR_D => amo_d(0b00100),
+            AOpcode::AMOAND_D => amo_d(0b01100),
+            AOpcode::AMOOR_D => amo_d(0b01000),
+            AOpcode::AMOMIN_D => amo_d(0b10000),
+            AOpcode::AMOMAX_D => amo_d(0b10100),
+            AOpcode::AMOMINU_D => amo_d(0b11000),
+            AOpcode::AMOMAXU_D => amo_d(0b11100),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_a {
+    #[test]
+    fn a_round_trip_test() {
+        use crate::encode::inst_32::{test_round_trip_32_in_rv32, test_round_trip_32_in_rv64};
+
+        test_round_trip_32_in_rv64(0x04d7_27af); // amoadd.w.aq a5, a3, (a4)
+        test_round_trip_32_in_rv64(0x1007_b62f); // lr.d a2, (a5)
+        test_round_trip_32_in_rv64(0x60f6_302f); // amoand.d zero, a5, (a2)
+        test_round_trip_32_in_rv32(0x1605_252f); // lr.w.aqrl a0, (a0)
+        test_round_trip_32_in_rv32(0x18b5_252f); // sc.w a0, a1, (a0)
+    }
+}
@@ -0,0 +1,142 @@
+pub mod bit_32 {
+    use super::super::inst_32::{i_type, r_type};
+    use super::super::{imm, only_rv64, reg, unsigned, EncodingError};
+    use crate::instruction::{b_extension::BOpcode, Instruction};
+    use crate::Isa;
+
+    #[allow(clippy::too_many_lines)]
+    pub fn encode(inst: &Instruction, opc: &BOpcode, isa: Isa) -> Result<u32, EncodingError> {
+        let rd = || reg(inst.rd);
+        let rs1 = || reg(inst.rs1);
+        let rs2 = || reg(inst.rs2);
+        let shamt = || match isa {
+            Isa::Rv32 => unsigned(imm(inst.imm)?, 5, 1),
+            Isa::Rv64 => unsigned(imm(inst.imm)?, 6, 1),
+        };
+
+        let op = |funct3, funct7| Ok(r_type(0b011_0011, rd()?, funct3, rs1()?, rs2()?, funct7));
+        let op_32 = |funct3, funct7| {
+            only_rv64(isa)?;
+            Ok(r_type(0b011_1011, rd()?, funct3, rs1()?, rs2()?, funct7))
+        };
+        // the rs2 field is used as funct5 or a part of funct12.
+        let op_imm_unary =
+            |opcode, funct3, funct12: u32| Ok(i_type(opcode, rd()?, funct3, rs1()?, funct12));
+        let op_shift = |funct3, funct6: u32| {
+            Ok(i_type(
+                0b001_0011,
+                rd()?,
+                funct3,
+                rs1()?,
+                funct6 << 6 | shamt()?,
+            ))
+        };
+
+        match opc {
+            BOpcode::SH1ADD => op(0b010, 0b001_0000),
+            BOpcode::SH2ADD => op(0b100, 0b001_0000),
+            BOpcode::SH3ADD => op(0b110, 0b001_0000),
+            BOpcode::ANDN => op(0b111, 0b010_0000),
+            BOpcode::ORN => op(0b110, 0b010_0000),
+            BOpcode::XNOR => op(0b100, 0b010_0000),
+            BOpcode::CLZ => op_imm_unary(0b001_0011, 0b001, 0b0110_0000_0000),
+            BOpcode::CTZ => op_imm_unary(0b001_0011, 0b001, 0b0110_0000_0001),
+            BOpcode::CPOP => op_imm_unary(0b001_0011, 0b001, 0b0110_0000_0010),
+            BOpcode::MAX => op(0b110, 0b000_0101),
+            BOpcode::MAXU => op(0b111, 0b000_0101),
+            BOpcode::MIN => op(0b100, 0b000_0101),
+            BOpcode::MINU => op(0b101, 0b000_0101),
+            BOpcode::SEXT_B => op_imm_unary(0b001_0011, 0b001, 0b0110_0000_0100),
+            BOpcode::SEXT_H => op_imm_unary(0b001_0011, 0b001, 0b0110_0000_0101),
+            BOpcode::ZEXT_H => match isa {
+                Isa::Rv32 => op_imm_unary(0b011_0011, 0b100, 0b0000_1000_0000),
+                Isa::Rv64 => op_imm_unary(0b011_1011, 0b100, 0b0000_1000_0000),
+            },
+            BOpcode::ROL => op(0b001, 0b011_0000),
+            BOpcode::ROR => op(0b101, 0b011_0000),
+            BOpcode::RORI => op_shift(0b101, 0b01_1000),
+            BOpcode::ORC_B => op_imm_unary(0b001_0011, 0b101, 0b0010_1000_0111),
+            BOpcode::REV8 => match isa {
+                Isa::Rv32 => op_imm_unary(0b001_0011, 0b101, 0b0110_1001_1000),
+                Isa::Rv64 => op_imm_unary(0b001_0011, 0b101, 0b0110_1011_1000),
+            },
+            BOpcode::CLMUL => op(0b001, 0b000_0101),
+            BOpcode::CLMULH => op(0b011, 0b000_0101),
+            BOpcode::CLMULR => op(0b010, 0b000_0101),
+            BOpcode::BCLR => op(0b001, 0b010_0100),
+            BOpcode::BCLRI => op_shift(0b001, 0b01_0010),
+            BOpcode::BEXT => op(0b101, 0b010_0100),
+            BOpcode::BEXTI => op_shift(0b101, 0b01_0010),
+            BOpcode::BINV => op(0b001, 0b011_0100),
+            BOpcode::BINVI => op_shift(0b001, 0b01_1010),
+            BOpcode::BSET => op(0b001, 0b001_0100),
+            BOpcode::BSETI => op_shift(0b001, 0b00_1010),
+            BOpcode::ADD_UW => op_32(0b000, 0b000_0100),
+            BOpcode::SH1ADD_UW => op_32(0b010, 0b001_0000),
+            BOpcode::SH2ADD_UW => op_32(0b100, 0b001_0000),
+            BOpcode::SH3ADD_UW => op_32(0b110, 0b001_0000),
+            BOpcode::SLLI_UW => {
+                only_rv64(isa)?;
+                let shamt = unsigned(imm(inst.imm)?, 6, 1)?;
+                Ok(i_type(
+                    0b001_1011,
+                    rd()?,
+                    0b001,
+                    rs1()?,
+                    0b00_0010 << 6 | shamt,
+                ))
+            }
+            BOpcode::CLZW => {
+                only_rv64(isa)?;
+                op_imm_unary(0b001_1011, 0b001, 0b0110_0000_0000)
+            }
+            BOpcode::CTZW => {
+                only_rv64(isa)?;
+                op_imm_unary(0b001_1011, 0b001, 0b0110_0000_0001)
+            }
+            BOpcode::CPOPW => {
+                only_rv64(isa)?;
+                op_imm_unary(0b001_1011, 0b001, 0b0110_0000_0010)
+            }
+            BOpcode::ROLW => op_32(0b001, 0b011_0000),
+            BOpcode::RORW => op_32(0b101, 0b011_0000),
+            BOpcode::RORIW => {
+                only_rv64(isa)?;
+                let shamt = unsigned(imm(inst.imm)?, 5, 1)?;
+                Ok(i_type(
+                    0b001_1011,
+                    rd()?,
+                    0b101,
+                    rs1()?,
+                    0b011_0000 << 5 | shamt,
+                ))
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_b {
+    #[test]
+    #[allow(overflowing_literals)]
+    fn b_round_trip_test() {
+        use crate::encode::inst_32::{test_round_trip_32_in_rv32, test_round_trip_32_in_rv64};
+
+        test_round_trip_32_in_rv64(0x20b5_2533); // sh1add a0, a0, a1
+        test_round_trip_32_in_rv64(0x40b5_7533); // andn a0, a0, a1
+        test_round_trip_32_in_rv64(0x6005_1513); // clz a0, a0
+        test_round_trip_32_in_rv64(0x6b85_5513); // rev8 a0, a0
+        test_round_trip_32_in_rv64(0x0ab5_1533); // clmul a0, a0, a1
+        test_round_trip_32_in_rv64(0x28b5_1533); // bset a0, a0, a1
+        test_round_trip_32_in_rv64(0x6255_5513); // rori a0, a0, 37
+        test_round_trip_32_in_rv64(0x0805_453b); // zext.h a0, a0
+        test_round_trip_32_in_rv64(0x0a05_151b); // slli.uw a0, a0, 32
+        test_round_trip_32_in_rv64(0x08b5_053b); // add.uw a0, a0, a1
+        test_round_trip_32_in_rv64(0x2875_5513); // orc.b a0, a0
+        test_round_trip_32_in_rv64(0x6055_551b); // roriw a0, a0, 5
+        test_round_trip_32_in_rv64(0x4a85_5513); // bexti a0, a0, 40
+        test_round_trip_32_in_rv32(0x6985_5513); // rev8 a0, a0
+        test_round_trip_32_in_rv32(0x0805_4533); // zext.h a0, a0
+        test_round_trip_32_in_rv32(0x69f5_1513); // binvi a0, a0, 31
+    }
+}
@@ -0,0 +1,268 @@
+pub mod bit_32 {
+    use super::super::inst_32::{b_type, i_type, j_type, r_type, s_type, u_type};
+    use super::super::{imm, only_rv64, reg, signed, unsigned, EncodingError};
+    use crate::instruction::{base_i::BaseIOpcode, Instruction};
+    use crate::Isa;
+
+    #[allow(non_snake_case, clippy::too_many_lines)]
+    pub fn encode(inst: &Instruction, opc: &BaseIOpcode, isa: Isa) -> Result<u32, EncodingError> {
+        let rd = || reg(inst.rd);
+        let rs1 = || reg(inst.rs1);
+        let rs2 = || reg(inst.rs2);
+        let U_imm = || unsigned(imm(inst.imm)?, 20, 1);
+        let I_imm = || signed(imm(inst.imm)?, 12, 1);
+        let S_imm = || signed(imm(inst.imm)?, 12, 1);
+        let B_imm = || signed(imm(inst.imm)?, 13, 2);
+        let J_imm = || signed(imm(inst.imm)?, 21, 2);
+        let shamt = || match isa {
+            Isa::Rv32 => unsigned(imm(inst.imm)?, 5, 1),
+            Isa::Rv64 => unsigned(imm(inst.imm)?, 6, 1),
+        };
+        let shamt5 = || unsigned(imm(inst.imm)?, 5, 1);
+
+        let branch = |funct3| Ok(b_type(0b110_0011, funct3, rs1()?, rs2()?, B_imm()?));
+        let load = |funct3| Ok(i_type(0b000_0011, rd()?, funct3, rs1()?, I_imm()?));
+        let store = |funct3| Ok(s_type(0b010_0011, funct3, rs1()?, rs2()?, S_imm()?));
+        let op_imm = |funct3| Ok(i_type(0b001_0011, rd()?, funct3, rs1()?, I_imm()?));
+        let op_shift = |funct3, funct6: u32| {
+            Ok(i_type(
+                0b001_0011,
+                rd()?,
+                funct3,
+                rs1()?,
+                funct6 << 6 | shamt()?,
+            ))
+        };
+        let op = |funct3, funct7| Ok(r_type(0b011_0011, rd()?, funct3, rs1()?, rs2()?, funct7));
+        let op_shift_32 = |funct3, funct7: u32| {
+            only_rv64(isa)?;
+            Ok(i_type(
+                0b001_1011,
+                rd()?,
+                funct3,
+                rs1()?,
+                funct7 << 5 | shamt5()?,
+            ))
+        };
+        let op_32 = |funct3, funct7| {
+            only_rv64(isa)?;
+            Ok(r_type(0b011_1011, rd()?, funct3, rs1()?, rs2()?, funct7))
+        };
+
+        match opc {
+            BaseIOpcode::LUI => Ok(u_type(0b011_0111, rd()?, U_imm()?)),
+            BaseIOpcode::AUIPC => Ok(u_type(0b001_0111, rd()?, U_imm()?)),
+            BaseIOpcode::JAL => Ok(j_type(0b110_1111, rd()?, J_imm()?)),
+            BaseIOpcode::JALR => Ok(i_type(0b110_0111, rd()?, 0b000, rs1()?, I_imm()?)),
+            BaseIOpcode::BEQ => branch(0b000),
+            BaseIOpcode::BNE => branch(0b001),
+            BaseIOpcode::BLT => branch(0b100),
+            BaseIOpcode::BGE => branch(0b101),
+            BaseIOpcode::BLTU => branch(0b110),
+            BaseIOpcode::BGEU => branch(0b111),
+            BaseIOpcode::LB => load(0b000),
+            BaseIOpcode::LH => load(0b001),
+            BaseIOpcode::LW => load(0b010),
+            BaseIOpcode::LBU => load(0b100),
+            BaseIOpcode::LHU => load(0b101),
+            BaseIOpcode::SB => store(0b000),
+            BaseIOpcode::SH => store(0b001),
+            BaseIOpcode::SW => store(0b010),
+            BaseIOpcode::ADDI => op_imm(0b000),
+            BaseIOpcode::SLTI => op_imm(0b010),
+            BaseIOpcode::SLTIU => op_imm(0b011),
+            BaseIOpcode::XORI => op_imm(0b100),
+            BaseIOpcode::ORI => op_imm(0b110),
+            BaseIOpcode::ANDI => op_imm(0b111),
+            BaseIOpcode::SLLI => op_shift(0b001, 0b00_0000),
+            BaseIOpcode::SRLI => op_shift(0b101, 0b00_0000),
+            BaseIOpcode::SRAI => op_shift(0b101, 0b01_0000),
+            BaseIOpcode::ADD => op(0b000, 0b000_0000),
+            BaseIOpcode::SUB => op(0b000, 0b010_0000),
+            BaseIOpcode::SLL => op(0b001, 0b000_0000),
+            BaseIOpcode::SLT => op(0b010, 0b000_0000),
+            BaseIOpcode::SLTU => op(0b011, 0b000_0000),
+            BaseIOpcode::XOR => op(0b100, 0b000_0000),
+            BaseIOpcode::SRL => op(0b101, 0b000_0000),
+            BaseIOpcode::SRA => op(0b101, 0b010_0000),
+            BaseIOpcode::OR => op(0b110, 0b000_0000),
+            BaseIOpcode::AND => op(0b111, 0b000_0000),
+            // fm, pred and succ are stored in imm.
+            BaseIOpcode::FENCE => Ok(i_type(
+                0b000_1111,
+                rd()?,
+                0b000,
+                rs1()?,
+                unsigned(imm(inst.imm)?, 12, 1)?,
+            )),
+            BaseIOpcode::FENCE_TSO => {
+                Ok(i_type(0b000_1111, rd()?, 0b000, rs1()?, 0b1000_0011_0011))
+            }
+            BaseIOpcode::PAUSE => Ok(0b0000_0001_0000_0000_0000_0000_0000_1111),
+            BaseIOpcode::ECALL => Ok(0b0000_0000_0000_0000_0000_0000_0111_0011),
+            BaseIOpcode::EBREAK => Ok(0b0000_0000_0001_0000_0000_0000_0111_0011),
+            BaseIOpcode::LWU => {
+                only_rv64(isa)?;
+                load(0b110)
+            }
+            BaseIOpcode::LD => {
+                only_rv64(isa)?;
+                load(0b011)
+            }
+            BaseIOpcode::SD => {
+                only_rv64(isa)?;
+                store(0b011)
+            }
+            BaseIOpcode::ADDIW => {
+                only_rv64(isa)?;
+                Ok(i_type(0b001_1011, rd()?, 0b000, rs1()?, I_imm()?))
+            }
+            BaseIOpcode::SLLIW => op_shift_32(0b001, 0b000_0000),
+            BaseIOpcode::SRLIW => op_shift_32(0b101, 0b000_0000),
+            BaseIOpcode::SRAIW => op_shift_32(0b101, 0b010_0000),
+            BaseIOpcode::ADDW => op_32(0b000, 0b000_0000),
+            BaseIOpcode::SUBW => op_32(0b000, 0b010_0000),
+            BaseIOpcode::SLLW => op_32(0b001, 0b000_0000),
+            BaseIOpcode::SRLW => op_32(0b101, 0b000_0000),
+            BaseIOpcode::SRAW => op_32(0b101, 0b010_0000),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_basei {
+    #[test]
+    #[allow(overflowing_literals)]
+    fn basei_round_trip_test() {
+        use crate::encode::inst_32::{test_round_trip_32_in_rv32, test_round_trip_32_in_rv64};
+
+        test_round_trip_32_in_rv64(0xfdea_d737); // lui a4, 0xfdead
+        test_round_trip_32_in_rv64(0x013f_9517); // auipc a0, 0x13f9
+        test_round_trip_32_in_rv64(0x9d3f_f0ef); // jal ra, -1582
+        test_round_trip_32_in_rv64(0x31e1_60ef); // jal ra, 90910
+        test_round_trip_32_in_rv64(0x0000_8067); // jalr zero, 0(ra)
+        test_round_trip_32_in_rv64(0x02e7_8263); // beq a5, a4, 36
+        test_round_trip_32_in_rv64(0x10ec_eb63); // bltu s9, a4, 278
+        test_round_trip_32_in_rv64(0xfe20_8ea3); // sb sp, -3(ra)
+        test_round_trip_32_in_rv64(0xeec2_8293); // addi t0, t0, -276
+        test_round_trip_32_in_rv64(0x3307_3983); // ld s3, 816(a4)
+        test_round_trip_32_in_rv64(0x4170_04b3); // sub s1, zero, s7
+        test_round_trip_32_in_rv64(0x00a9_3933); // sltu s2, s2, a0
+        test_round_trip_32_in_rv64(0x4035_d593); // srai a1, a1, 3
+        test_round_trip_32_in_rv64(0x03f5_1513); // slli a0, a0, 63
+        test_round_trip_32_in_rv64(0x0330_000f); // fence rw, rw
+        test_round_trip_32_in_rv64(0x0ff0_000f); // fence iorw, iorw
+        test_round_trip_32_in_rv64(0x8330_000f); // fence.tso
+        test_round_trip_32_in_rv64(0x0100_000f); // pause
+        test_round_trip_32_in_rv64(0x4025_551b); // sraiw a0, a0, 2
+        test_round_trip_32_in_rv64(0x40b5_053b); // subw a0, a0, a1
+        test_round_trip_32_in_rv64(0x0000_0073); // ecall
+        test_round_trip_32_in_rv64(0x0010_0073); // ebreak
+        test_round_trip_32_in_rv32(0x01f5_1513); // slli a0, a0, 31
+        test_round_trip_32_in_rv32(0x0005_2503); // lw a0, 0(a0)
+    }
+
+    #[test]
+    fn basei_encode_error_test() {
+        use crate::instruction::{base_i::BaseIOpcode, InstFormat, Instruction, OpcodeKind};
+        use crate::{Encode, EncodingError, Isa};
+
+        let inst = |opc, rd, rs1, rs2, imm, inst_format| Instruction {
+            opc: OpcodeKind::BaseI(opc),
+            rd,
+            rs1,
+            rs2,
+            rs3: None,
+            imm,
+            vm: None,
+            rm: None,
+            inst_format,
+            is_compressed: false,
+        };
+
+        assert_eq!(
+            u32::encode(
+                &inst(
+                    BaseIOpcode::ADDI,
+                    Some(1),
+                    Some(1),
+                    None,
+                    Some(2048),
+                    InstFormat::IFormat
+                ),
+                Isa::Rv64
+            ),
+            Err(EncodingError::ImmediateOutOfRange)
+        );
+        assert_eq!(
+            u32::encode(
+                &inst(
+                    BaseIOpcode::ADD,
+                    Some(1),
+                    Some(32),
+                    Some(1),
+                    None,
+                    InstFormat::RFormat
+                ),
+                Isa::Rv64
+            ),
+            Err(EncodingError::InvalidRegister)
+        );
+        assert_eq!(
+            u32::encode(
+                &inst(
+                    BaseIOpcode::BEQ,
+                    None,
+                    Some(1),
+                    Some(2),
+                    Some(3),
+                    InstFormat::BFormat
+                ),
+                Isa::Rv64
+            ),
+            Err(EncodingError::MisalignedImmediate)
+        );
+        assert_eq!(
+            u32::encode(
+                &inst(
+                    BaseIOpcode::SLLI,
+                    Some(1),
+                    Some(1),
+                    None,
+                    Some(32),
+                    InstFormat::RShamtFormat
+                ),
+                Isa::Rv32
+            ),
+            Err(EncodingError::ImmediateOutOfRange)
+        );
+        assert_eq!(
+            u32::encode(
+                &inst(
+                    BaseIOpcode::LD,
+                    Some(1),
+                    Some(1),
+                    None,
+                    Some(0),
+                    InstFormat::IFormat
+                ),
+                Isa::Rv32
+            ),
+            Err(EncodingError::OnlyRv64Inst)
+        );
+        assert_eq!(
+            u32::encode(
+                &inst(
+                    BaseIOpcode::JAL,
+                    Some(1),
+                    None,
+                    None,
+                    None,
+                    InstFormat::JFormat
+                ),
+                Isa::Rv64
+            ),
+            Err(EncodingError::MissingOperand)
+        );
+    }
+}
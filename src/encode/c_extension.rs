@@ -0,0 +1,318 @@
+pub mod bit_16 {
+    use super::super::{gather, imm, only_rv64, reg, signed, unsigned, EncodingError};
+    use crate::instruction::{c_extension::COpcode, Instruction};
+    use crate::Isa;
+
+    /// Get the compressed register number (x8 - x15) as 3 bits.
+    fn creg(field: Option<usize>) -> Result<u32, EncodingError> {
+        match reg(field)? {
+            num @ 8..=15 => Ok(num - 8),
+            _ => Err(EncodingError::InvalidRegister),
+        }
+    }
+
+    /// Get the register number that must not be x0.
+    fn nonzero_reg(field: Option<usize>) -> Result<u32, EncodingError> {
+        match reg(field)? {
+            0 => Err(EncodingError::InvalidRegister),
+            num => Ok(num),
+        }
+    }
+
+    /// Check that the immediate is not zero.
+    fn nonzero(imm: i32) -> Result<i32, EncodingError> {
+        match imm {
+            0 => Err(EncodingError::ImmediateOutOfRange),
+            imm => Ok(imm),
+        }
+    }
+
+    #[allow(clippy::too_many_lines)]
+    pub fn encode(inst: &Instruction, opc: &COpcode, isa: Isa) -> Result<u16, EncodingError> {
+        // Quadrant 0: [15:13] funct3, [12:10] imm, [9:7] rs1', [6:5] imm, [4:2] rd'/rs2'
+        let q0_mem = |funct3: u32, uimm: u32, mask: &[u32; 2], rd_or_rs2| {
+            Ok(funct3 << 13
+                | gather(uimm, &[5, 4, 3]) << 10
+                | creg(inst.rs1)? << 7
+                | gather(uimm, mask) << 5
+                | creg(rd_or_rs2)? << 2)
+        };
+        // Quadrant 1/2: [15:13] funct3, [12] imm[5], [11:7] rd, [6:2] imm[4:0]
+        let ci = |funct3: u32, rd: u32, imm6: u32, op: u32| {
+            funct3 << 13 | (imm6 >> 5 & 0x1) << 12 | rd << 7 | (imm6 & 0x1f) << 2 | op
+        };
+        // Quadrant 1: [15:13] funct3, [12:10] funct2, [9:7] rd'/rs1', [6:2] imm[4:0]
+        let cb_alu = |funct2: u32, imm6: u32| {
+            Ok(0b100 << 13
+                | (imm6 >> 5 & 0x1) << 12
+                | funct2 << 10
+                | creg(inst.rd)? << 7
+                | (imm6 & 0x1f) << 2
+                | 0b01)
+        };
+        let ca = |bit_12: u32, funct2: u32| {
+            Ok(0b100 << 13
+                | bit_12 << 12
+                | 0b11 << 10
+                | creg(inst.rd)? << 7
+                | funct2 << 5
+                | creg(inst.rs2)? << 2
+                | 0b01)
+        };
+        let cj = |funct3: u32| {
+            let offset = signed(imm(inst.imm)?, 12, 2)?;
+            Ok(funct3 << 13 | gather(offset, &[11, 4, 9, 8, 10, 6, 7, 3, 2, 1, 5]) << 2 | 0b01)
+        };
+        let cb_branch = |funct3: u32| {
+            let offset = signed(imm(inst.imm)?, 9, 2)?;
+            Ok(funct3 << 13
+                | gather(offset, &[8, 4, 3]) << 10
+                | creg(inst.rs1)? << 7
+                | gather(offset, &[7, 6, 2, 1, 5]) << 2
+                | 0b01)
+        };
+        let cr = |bit_12: u32, rd_rs1: u32, rs2: u32| {
+            0b100 << 13 | bit_12 << 12 | rd_rs1 << 7 | rs2 << 2 | 0b10
+        };
+        let css = |funct3: u32, uimm: u32, mask: &[u32; 6]| {
+            Ok(funct3 << 13 | gather(uimm, mask) << 7 | reg(inst.rs2)? << 2 | 0b10)
+        };
+
+        let bits: u32 = match opc {
+            // Quadrant 0
+            COpcode::ADDI4SPN => {
+                let nzuimm = unsigned(nonzero(imm(inst.imm)?)?, 10, 4)?;
+                gather(nzuimm, &[5, 4, 9, 8, 7, 6, 2, 3]) << 5 | creg(inst.rd)? << 2
+            }
+            COpcode::LW => q0_mem(0b010, unsigned(imm(inst.imm)?, 7, 4)?, &[2, 6], inst.rd)?,
+            COpcode::FLD => q0_mem(0b001, unsigned(imm(inst.imm)?, 8, 8)?, &[7, 6], inst.rd)?,
+            COpcode::FLW => match isa {
+                Isa::Rv32 => q0_mem(0b011, unsigned(imm(inst.imm)?, 7, 4)?, &[2, 6], inst.rd)?,
+                Isa::Rv64 => return Err(EncodingError::OnlyRv32Inst),
+            },
+            COpcode::LD => {
+                only_rv64(isa)?;
+                q0_mem(0b011, unsigned(imm(inst.imm)?, 8, 8)?, &[7, 6], inst.rd)?
+            }
+            COpcode::SW => q0_mem(0b110, unsigned(imm(inst.imm)?, 7, 4)?, &[2, 6], inst.rs2)?,
+            COpcode::FSD => q0_mem(0b101, unsigned(imm(inst.imm)?, 8, 8)?, &[7, 6], inst.rs2)?,
+            COpcode::FSW => match isa {
+                Isa::Rv32 => q0_mem(0b111, unsigned(imm(inst.imm)?, 7, 4)?, &[2, 6], inst.rs2)?,
+                Isa::Rv64 => return Err(EncodingError::OnlyRv32Inst),
+            },
+            COpcode::SD => {
+                only_rv64(isa)?;
+                q0_mem(0b111, unsigned(imm(inst.imm)?, 8, 8)?, &[7, 6], inst.rs2)?
+            }
+
+            // Quadrant 1
+            COpcode::NOP => ci(0b000, 0, signed(imm(inst.imm)?, 6, 1)?, 0b01),
+            COpcode::ADDI => ci(
+                0b000,
+                nonzero_reg(inst.rd)?,
+                signed(imm(inst.imm)?, 6, 1)?,
+                0b01,
+            ),
+            COpcode::JAL => match isa {
+                Isa::Rv32 => cj(0b001)?,
+                Isa::Rv64 => return Err(EncodingError::OnlyRv32Inst),
+            },
+            COpcode::ADDIW => {
+                only_rv64(isa)?;
+                ci(
+                    0b001,
+                    nonzero_reg(inst.rd)?,
+                    signed(imm(inst.imm)?, 6, 1)?,
+                    0b01,
+                )
+            }
+            COpcode::LI => ci(0b010, reg(inst.rd)?, signed(imm(inst.imm)?, 6, 1)?, 0b01),
+            COpcode::ADDI16SP => {
+                if reg(inst.rd)? != 2 {
+                    return Err(EncodingError::InvalidRegister);
+                }
+                let nzimm = signed(nonzero(imm(inst.imm)?)?, 10, 16)?;
+                0b011 << 13
+                    | (nzimm >> 9 & 0x1) << 12
+                    | 2 << 7
+                    | gather(nzimm, &[4, 6, 8, 7, 5]) << 2
+                    | 0b01
+            }
+            COpcode::LUI => match reg(inst.rd)? {
+                0 | 2 => return Err(EncodingError::InvalidRegister),
+                rd => ci(
+                    0b011,
+                    rd,
+                    signed(nonzero(imm(inst.imm)?)?, 18, 0x1000)? >> 12,
+                    0b01,
+                ),
+            },
+            COpcode::SRLI => cb_alu(0b00, unsigned(imm(inst.imm)?, 6, 1)?)?,
+            COpcode::SRAI => cb_alu(0b01, unsigned(imm(inst.imm)?, 6, 1)?)?,
+            COpcode::ANDI => cb_alu(0b10, signed(imm(inst.imm)?, 6, 1)?)?,
+            COpcode::SUB => ca(0b0, 0b00)?,
+            COpcode::XOR => ca(0b0, 0b01)?,
+            COpcode::OR => ca(0b0, 0b10)?,
+            COpcode::AND => ca(0b0, 0b11)?,
+            COpcode::SUBW => {
+                only_rv64(isa)?;
+                ca(0b1, 0b00)?
+            }
+            COpcode::ADDW => {
+                only_rv64(isa)?;
+                ca(0b1, 0b01)?
+            }
+            COpcode::J => cj(0b101)?,
+            COpcode::BEQZ => cb_branch(0b110)?,
+            COpcode::BNEZ => cb_branch(0b111)?,
+
+            // Quadrant 2
+            COpcode::SLLI => ci(0b000, reg(inst.rd)?, unsigned(imm(inst.imm)?, 6, 1)?, 0b10),
+            COpcode::LWSP => {
+                let uimm = unsigned(imm(inst.imm)?, 8, 4)?;
+                ci(
+                    0b010,
+                    nonzero_reg(inst.rd)?,
+                    gather(uimm, &[5, 4, 3, 2, 7, 6]),
+                    0b10,
+                )
+            }
+            COpcode::FLDSP => {
+                let uimm = unsigned(imm(inst.imm)?, 9, 8)?;
+                ci(
+                    0b001,
+                    reg(inst.rd)?,
+                    gather(uimm, &[5, 4, 3, 8, 7, 6]),
+                    0b10,
+                )
+            }
+            COpcode::FLWSP => match isa {
+                Isa::Rv32 => {
+                    let uimm = unsigned(imm(inst.imm)?, 8, 4)?;
+                    ci(
+                        0b011,
+                        reg(inst.rd)?,
+                        gather(uimm, &[5, 4, 3, 2, 7, 6]),
+                        0b10,
+                    )
+                }
+                Isa::Rv64 => return Err(EncodingError::OnlyRv32Inst),
+            },
+            COpcode::LDSP => {
+                only_rv64(isa)?;
+                let uimm = unsigned(imm(inst.imm)?, 9, 8)?;
+                ci(
+                    0b011,
+                    nonzero_reg(inst.rd)?,
+                    gather(uimm, &[5, 4, 3, 8, 7, 6]),
+                    0b10,
+                )
+            }
+            COpcode::JR => cr(0b0, nonzero_reg(inst.rs1)?, 0),
+            COpcode::MV => cr(0b0, nonzero_reg(inst.rd)?, nonzero_reg(inst.rs2)?),
+            COpcode::EBREAK => cr(0b1, 0, 0),
+            COpcode::JALR => cr(0b1, nonzero_reg(inst.rs1)?, 0),
+            COpcode::ADD => cr(0b1, nonzero_reg(inst.rd)?, nonzero_reg(inst.rs2)?),
+            COpcode::SWSP => css(0b110, unsigned(imm(inst.imm)?, 8, 4)?, &[5, 4, 3, 2, 7, 6])?,
+            COpcode::FSDSP => css(0b101, unsigned(imm(inst.imm)?, 9, 8)?, &[5, 4, 3, 8, 7, 6])?,
+            COpcode::FSWSP => match isa {
+                Isa::Rv32 => css(0b111, unsigned(imm(inst.imm)?, 8, 4)?, &[5, 4, 3, 2, 7, 6])?,
+                Isa::Rv64 => return Err(EncodingError::OnlyRv32Inst),
+            },
+            COpcode::SDSP => {
+                only_rv64(isa)?;
+                css(0b111, unsigned(imm(inst.imm)?, 9, 8)?, &[5, 4, 3, 8, 7, 6])?
+            }
+        };
+
+        Ok(u16::try_from(bits).unwrap())
+    }
+}
+
+#[cfg(test)]
+mod test_c {
+    #[test]
+    fn c_round_trip_test() {
+        use crate::encode::inst_16::{test_round_trip_16_in_rv32, test_round_trip_16_in_rv64};
+
+        test_round_trip_16_in_rv64(0b0000_0000_0000_0001); // c.nop
+        test_round_trip_16_in_rv64(0b0110_0011_1000_0101); // c.lui t2, 1
+        test_round_trip_16_in_rv64(0b1000_0010_1100_0001); // c.srli a3, 16
+        test_round_trip_16_in_rv64(0x4521); // c.li a0, 8
+        test_round_trip_16_in_rv64(0xb5e5); // c.j -280
+        test_round_trip_16_in_rv64(0x6105); // c.addi16sp sp, 32
+        test_round_trip_16_in_rv64(0x8082); // c.jr ra
+        test_round_trip_16_in_rv64(0xe29d); // c.bnez a3, 38
+        test_round_trip_16_in_rv64(0xc05c); // c.sw a5, 4(s0)
+        test_round_trip_16_in_rv64(0x9002); // c.ebreak
+        test_round_trip_16_in_rv64(0x880a); // c.mv a6, sp
+        test_round_trip_16_in_rv64(0x8585); // c.srai a1, 1
+        test_round_trip_16_in_rv64(0x0808); // c.addi4spn a0, sp, 16
+        test_round_trip_16_in_rv64(0x6398); // c.ld a4, 0(a5)
+        test_round_trip_16_in_rv64(0xe398); // c.sd a4, 0(a5)
+        test_round_trip_16_in_rv64(0x1161); // c.addi sp, -8
+        test_round_trip_16_in_rv64(0x2505); // c.addiw a0, 1
+        test_round_trip_16_in_rv64(0x7179); // c.addi16sp sp, -48
+        test_round_trip_16_in_rv64(0x757d); // c.lui a0, -4096
+        test_round_trip_16_in_rv64(0x8979); // c.andi a0, 30
+        test_round_trip_16_in_rv64(0x8d0d); // c.sub a0, a1
+        test_round_trip_16_in_rv64(0x9d2d); // c.addw a0, a1
+        test_round_trip_16_in_rv64(0xc119); // c.beqz a0, 6
+        test_round_trip_16_in_rv64(0x050e); // c.slli a0, 3
+        test_round_trip_16_in_rv64(0x4502); // c.lwsp a0, 0(sp)
+        test_round_trip_16_in_rv64(0x70a2); // c.ldsp ra, 40(sp)
+        test_round_trip_16_in_rv64(0x9782); // c.jalr a5
+        test_round_trip_16_in_rv64(0x952e); // c.add a0, a1
+        test_round_trip_16_in_rv64(0xc02a); // c.swsp a0, 0(sp)
+        test_round_trip_16_in_rv64(0xf406); // c.sdsp ra, 40(sp)
+        test_round_trip_16_in_rv32(0x2011); // c.jal 4
+        test_round_trip_16_in_rv64(0x2588); // c.fld fa0, 8(a1)
+        test_round_trip_16_in_rv64(0xbc7c); // c.fsd fa5, 248(s0)
+        test_round_trip_16_in_rv64(0x307e); // c.fldsp ft0, 504(sp)
+        test_round_trip_16_in_rv64(0xa422); // c.fsdsp fs0, 8(sp)
+        test_round_trip_16_in_rv32(0x61c8); // c.flw fa0, 4(a1)
+        test_round_trip_16_in_rv32(0xfc7c); // c.fsw fa5, 124(s0)
+        test_round_trip_16_in_rv32(0x70fe); // c.flwsp ft1, 252(sp)
+        test_round_trip_16_in_rv32(0xe222); // c.fswsp fs0, 4(sp)
+    }
+
+    #[test]
+    fn c_encode_error_test() {
+        use crate::instruction::{c_extension::COpcode, InstFormat, Instruction, OpcodeKind};
+        use crate::{Encode, EncodingError, Isa};
+
+        let c_lw = |rd, rs1, imm| Instruction {
+            opc: OpcodeKind::C(COpcode::LW),
+            rd,
+            rs1,
+            rs2: None,
+            rs3: None,
+            imm,
+            vm: None,
+            rm: None,
+            inst_format: InstFormat::ClFormat,
+            is_compressed: true,
+        };
+
+        assert_eq!(
+            u16::encode(&c_lw(Some(8), Some(9), Some(4)), Isa::Rv64),
+            Ok(0x40c0)
+        );
+        assert_eq!(
+            u16::encode(&c_lw(Some(1), Some(9), Some(4)), Isa::Rv64),
+            Err(EncodingError::InvalidRegister)
+        );
+        assert_eq!(
+            u16::encode(&c_lw(Some(8), Some(9), Some(128)), Isa::Rv64),
+            Err(EncodingError::ImmediateOutOfRange)
+        );
+        assert_eq!(
+            u16::encode(&c_lw(Some(8), Some(9), Some(2)), Isa::Rv64),
+            Err(EncodingError::MisalignedImmediate)
+        );
+        assert_eq!(
+            u32::encode(&c_lw(Some(8), Some(9), Some(4)), Isa::Rv64),
+            Err(EncodingError::Not32BitInst)
+        );
+    }
+}
@@ -0,0 +1,113 @@
+pub mod bit_32 {
+    use super::super::inst_32::{i_type, r_type, s_type};
+    use super::super::{imm, only_rv64, reg, signed, EncodingError};
+    use crate::instruction::{d_extension::DOpcode, f_extension::RoundingMode, Instruction};
+    use crate::Isa;
+
+    #[allow(non_snake_case)]
+    pub fn encode(inst: &Instruction, opc: &DOpcode, isa: Isa) -> Result<u32, EncodingError> {
+        let rd = || reg(inst.rd);
+        let rs1 = || reg(inst.rs1);
+        let rs2 = || reg(inst.rs2);
+        let rs3 = || reg(inst.rs3);
+        let I_imm = || signed(imm(inst.imm)?, 12, 1);
+        let S_imm = || signed(imm(inst.imm)?, 12, 1);
+        let rm = || {
+            inst.rm
+                .map(RoundingMode::to_rm)
+                .ok_or(EncodingError::MissingOperand)
+        };
+
+        let r4 = |opcode| {
+            Ok(r_type(
+                opcode,
+                rd()?,
+                rm()?,
+                rs1()?,
+                rs2()?,
+                rs3()? << 2 | 0b01,
+            ))
+        };
+        let op_fp = |funct7| Ok(r_type(0b101_0011, rd()?, rm()?, rs1()?, rs2()?, funct7));
+        let op_fp_funct3 =
+            |funct3, funct7| Ok(r_type(0b101_0011, rd()?, funct3, rs1()?, rs2()?, funct7));
+        let op_fp_unary =
+            |funct7, funct5| Ok(r_type(0b101_0011, rd()?, rm()?, rs1()?, funct5, funct7));
+        let op_fp_unary_funct3 =
+            |funct3, funct7, funct5| Ok(r_type(0b101_0011, rd()?, funct3, rs1()?, funct5, funct7));
+
+        match opc {
+            DOpcode::FLD => Ok(i_type(0b000_0111, rd()?, 0b011, rs1()?, I_imm()?)),
+            DOpcode::FSD => Ok(s_type(0b010_0111, 0b011, rs1()?, rs2()?, S_imm()?)),
+            DOpcode::FMADD_D => r4(0b100_0011),
+            DOpcode::FMSUB_D => r4(0b100_0111),
+            DOpcode::FNMSUB_D => r4(0b100_1011),
+            DOpcode::FNMADD_D => r4(0b100_1111),
+            DOpcode::FADD_D => op_fp(0b000_0001),
+            DOpcode::FSUB_D => op_fp(0b000_0101),
+            DOpcode::FMUL_D => op_fp(0b000_1001),
+            DOpcode::FDIV_D => op_fp(0b000_1101),
+            DOpcode::FSQRT_D => op_fp_unary(0b010_1101, 0b00000),
+            DOpcode::FSGNJ_D => op_fp_funct3(0b000, 0b001_0001),
+            DOpcode::FSGNJN_D => op_fp_funct3(0b001, 0b001_0001),
+            DOpcode::FSGNJX_D => op_fp_funct3(0b010, 0b001_0001),
+            DOpcode::FMIN_D => op_fp_funct3(0b000, 0b001_0101),
+            DOpcode::FMAX_D => op_fp_funct3(0b001, 0b001_0101),
+            DOpcode::FCVT_S_D => op_fp_unary(0b010_0000, 0b00001),
+            DOpcode::FCVT_D_S => op_fp_unary(0b010_0001, 0b00000),
+            DOpcode::FEQ_D => op_fp_funct3(0b010, 0b101_0001),
+            DOpcode::FLT_D => op_fp_funct3(0b001, 0b101_0001),
+            DOpcode::FLE_D => op_fp_funct3(0b000, 0b101_0001),
+            DOpcode::FCLASS_D => op_fp_unary_funct3(0b001, 0b111_0001, 0b00000),
+            DOpcode::FCVT_W_D => op_fp_unary(0b110_0001, 0b00000),
+            DOpcode::FCVT_WU_D => op_fp_unary(0b110_0001, 0b00001),
+            DOpcode::FCVT_D_W => op_fp_unary(0b110_1001, 0b00000),
+            DOpcode::FCVT_D_WU => op_fp_unary(0b110_1001, 0b00001),
+            DOpcode::FCVT_L_D => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_0001, 0b00010)
+            }
+            DOpcode::FCVT_LU_D => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_0001, 0b00011)
+            }
+            DOpcode::FCVT_D_L => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_1001, 0b00010)
+            }
+            DOpcode::FCVT_D_LU => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_1001, 0b00011)
+            }
+            DOpcode::FMV_X_D => {
+                only_rv64(isa)?;
+                op_fp_unary_funct3(0b000, 0b111_0001, 0b00000)
+            }
+            DOpcode::FMV_D_X => {
+                only_rv64(isa)?;
+                op_fp_unary_funct3(0b000, 0b111_1001, 0b00000)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_d {
+    #[test]
+    #[allow(overflowing_literals)]
+    fn d_round_trip_test() {
+        use crate::encode::inst_32::{test_round_trip_32_in_rv32, test_round_trip_32_in_rv64};
+
+        test_round_trip_32_in_rv64(0x0085_3507); // fld fa0, 8(a0)
+        test_round_trip_32_in_rv64(0xfea1_3c27); // fsd fa0, -8(sp)
+        test_round_trip_32_in_rv64(0x02b5_7553); // fadd.d fa0, fa0, fa1
+        test_round_trip_32_in_rv64(0x62b5_1543); // fmadd.d fa0, fa0, fa1, fa2, rtz
+        test_round_trip_32_in_rv64(0x4015_f553); // fcvt.s.d fa0, fa1
+        test_round_trip_32_in_rv64(0x4205_8553); // fcvt.d.s fa0, fa1
+        test_round_trip_32_in_rv64(0xc205_1553); // fcvt.w.d a0, fa0, rtz
+        test_round_trip_32_in_rv64(0xe205_0553); // fmv.x.d a0, fa0
+        test_round_trip_32_in_rv64(0xf205_0553); // fmv.d.x fa0, a0
+        test_round_trip_32_in_rv32(0xa2b5_2553); // feq.d a0, fa0, fa1
+        test_round_trip_32_in_rv32(0xe205_1553); // fclass.d a0, fa0
+    }
+}
@@ -0,0 +1,98 @@
+pub mod bit_32 {
+    use super::super::inst_32::{i_type, r_type, s_type};
+    use super::super::{imm, only_rv64, reg, signed, EncodingError};
+    use crate::instruction::{
+        f_extension::{FOpcode, RoundingMode},
+        Instruction,
+    };
+    use crate::Isa;
+
+    #[allow(non_snake_case)]
+    pub fn encode(inst: &Instruction, opc: &FOpcode, isa: Isa) -> Result<u32, EncodingError> {
+        let rd = || reg(inst.rd);
+        let rs1 = || reg(inst.rs1);
+        let rs2 = || reg(inst.rs2);
+        let rs3 = || reg(inst.rs3);
+        let I_imm = || signed(imm(inst.imm)?, 12, 1);
+        let S_imm = || signed(imm(inst.imm)?, 12, 1);
+        let rm = || {
+            inst.rm
+                .map(RoundingMode::to_rm)
+                .ok_or(EncodingError::MissingOperand)
+        };
+
+        let r4 = |opcode| Ok(r_type(opcode, rd()?, rm()?, rs1()?, rs2()?, rs3()? << 2));
+        let op_fp = |funct7| Ok(r_type(0b101_0011, rd()?, rm()?, rs1()?, rs2()?, funct7));
+        let op_fp_funct3 =
+            |funct3, funct7| Ok(r_type(0b101_0011, rd()?, funct3, rs1()?, rs2()?, funct7));
+        let op_fp_unary =
+            |funct7, funct5| Ok(r_type(0b101_0011, rd()?, rm()?, rs1()?, funct5, funct7));
+        let op_fp_unary_funct3 =
+            |funct3, funct7, funct5| Ok(r_type(0b101_0011, rd()?, funct3, rs1()?, funct5, funct7));
+
+        match opc {
+            FOpcode::FLW => Ok(i_type(0b000_0111, rd()?, 0b010, rs1()?, I_imm()?)),
+            FOpcode::FSW => Ok(s_type(0b010_0111, 0b010, rs1()?, rs2()?, S_imm()?)),
+            FOpcode::FMADD_S => r4(0b100_0011),
+            FOpcode::FMSUB_S => r4(0b100_0111),
+            FOpcode::FNMSUB_S => r4(0b100_1011),
+            FOpcode::FNMADD_S => r4(0b100_1111),
+            FOpcode::FADD_S => op_fp(0b000_0000),
+            FOpcode::FSUB_S => op_fp(0b000_0100),
+            FOpcode::FMUL_S => op_fp(0b000_1000),
+            FOpcode::FDIV_S => op_fp(0b000_1100),
+            FOpcode::FSQRT_S => op_fp_unary(0b010_1100, 0b00000),
+            FOpcode::FSGNJ_S => op_fp_funct3(0b000, 0b001_0000),
+            FOpcode::FSGNJN_S => op_fp_funct3(0b001, 0b001_0000),
+            FOpcode::FSGNJX_S => op_fp_funct3(0b010, 0b001_0000),
+            FOpcode::FMIN_S => op_fp_funct3(0b000, 0b001_0100),
+            FOpcode::FMAX_S => op_fp_funct3(0b001, 0b001_0100),
+            FOpcode::FCVT_W_S => op_fp_unary(0b110_0000, 0b00000),
+            FOpcode::FCVT_WU_S => op_fp_unary(0b110_0000, 0b00001),
+            FOpcode::FMV_X_W => op_fp_unary_funct3(0b000, 0b111_0000, 0b00000),
+            FOpcode::FEQ_S => op_fp_funct3(0b010, 0b101_0000),
+            FOpcode::FLT_S => op_fp_funct3(0b001, 0b101_0000),
+            FOpcode::FLE_S => op_fp_funct3(0b000, 0b101_0000),
+            FOpcode::FCLASS_S => op_fp_unary_funct3(0b001, 0b111_0000, 0b00000),
+            FOpcode::FCVT_S_W => op_fp_unary(0b110_1000, 0b00000),
+            FOpcode::FCVT_S_WU => op_fp_unary(0b110_1000, 0b00001),
+            FOpcode::FMV_W_X => op_fp_unary_funct3(0b000, 0b111_1000, 0b00000),
+            FOpcode::FCVT_L_S => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_0000, 0b00010)
+            }
+            FOpcode::FCVT_LU_S => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_0000, 0b00011)
+            }
+            FOpcode::FCVT_S_L => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_1000, 0b00010)
+            }
+            FOpcode::FCVT_S_LU => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_1000, 0b00011)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_f {
+    #[test]
+    #[allow(overflowing_literals)]
+    fn f_round_trip_test() {
+        use crate::encode::inst_32::{test_round_trip_32_in_rv32, test_round_trip_32_in_rv64};
+
+        test_round_trip_32_in_rv64(0x0085_2507); // flw fa0, 8(a0)
+        test_round_trip_32_in_rv64(0xfea5_ae27); // fsw fa0, -4(a1)
+        test_round_trip_32_in_rv64(0x00b5_7553); // fadd.s fa0, fa0, fa1
+        test_round_trip_32_in_rv64(0xc005_1553); // fcvt.w.s a0, fa0, rtz
+        test_round_trip_32_in_rv64(0xa0b5_2553); // feq.s a0, fa0, fa1
+        test_round_trip_32_in_rv64(0xe005_1553); // fclass.s a0, fa0
+        test_round_trip_32_in_rv64(0x60b5_7543); // fmadd.s fa0, fa0, fa1, fa2
+        test_round_trip_32_in_rv64(0xc025_1553); // fcvt.l.s a0, fa0, rtz
+        test_round_trip_32_in_rv32(0xf005_0553); // fmv.w.x fa0, a0
+        test_round_trip_32_in_rv32(0x20b5_0553); // fsgnj.s fa0, fa0, fa1
+    }
+}
@@ -0,0 +1,41 @@
+use super::{c_extension, zicfiss_extension};
+use super::{Encode, EncodingError};
+use crate::instruction::{Instruction, OpcodeKind};
+use crate::Isa;
+
+impl Encode for u16 {
+    fn encode(inst: &Instruction, isa: Isa) -> Result<Self, EncodingError> {
+        match &inst.opc {
+            OpcodeKind::C(opc) => c_extension::bit_16::encode(inst, opc, isa),
+            OpcodeKind::Zicfiss(opc) => zicfiss_extension::bit_16::encode(opc),
+            _ => Err(EncodingError::Not16BitInst),
+        }
+    }
+}
+
+#[cfg(test)]
+#[track_caller]
+pub fn test_round_trip_16(isa: Isa, inst_16: u16) {
+    use crate::decode::Decode;
+
+    let inst = inst_16
+        .decode(isa)
+        .unwrap_or_else(|e| panic!("{e:?}: failed to decode {inst_16:016b}"));
+    assert_eq!(
+        u16::encode(&inst, isa),
+        Ok(inst_16),
+        "encoded value does not match: {inst:?} ({inst_16:#06x})"
+    );
+}
+
+#[cfg(test)]
+#[track_caller]
+pub fn test_round_trip_16_in_rv32(inst_16: u16) {
+    test_round_trip_16(Isa::Rv32, inst_16);
+}
+
+#[cfg(test)]
+#[track_caller]
+pub fn test_round_trip_16_in_rv64(inst_16: u16) {
+    test_round_trip_16(Isa::Rv64, inst_16);
+}
@@ -0,0 +1,100 @@
+use super::{
+    a_extension, b_extension, base_i, d_extension, f_extension, m_extension, priv_extension,
+    q_extension, v_extension, zicboz_extension, zicfiss_extension, zicntr_extension,
+    zicsr_extension, zifencei_extension,
+};
+use super::{Encode, EncodingError};
+use crate::instruction::{Instruction, OpcodeKind};
+use crate::Isa;
+
+impl Encode for u32 {
+    fn encode(inst: &Instruction, isa: Isa) -> Result<Self, EncodingError> {
+        match &inst.opc {
+            OpcodeKind::BaseI(opc) => base_i::bit_32::encode(inst, opc, isa),
+            OpcodeKind::M(opc) => m_extension::bit_32::encode(inst, opc, isa),
+            OpcodeKind::A(opc) => a_extension::bit_32::encode(inst, opc, isa),
+            OpcodeKind::F(opc) => f_extension::bit_32::encode(inst, opc, isa),
+            OpcodeKind::D(opc) => d_extension::bit_32::encode(inst, opc, isa),
+            OpcodeKind::Q(opc) => q_extension::bit_32::encode(inst, opc, isa),
+            OpcodeKind::Zifencei(opc) => zifencei_extension::bit_32::encode(inst, opc),
+            OpcodeKind::Zicsr(opc) => zicsr_extension::bit_32::encode(inst, opc),
+            OpcodeKind::Zicfiss(opc) => zicfiss_extension::bit_32::encode(inst, opc, isa),
+            OpcodeKind::Zicntr(opc) => zicntr_extension::bit_32::encode(inst, opc),
+            OpcodeKind::Zicboz(opc) => zicboz_extension::bit_32::encode(inst, opc),
+            OpcodeKind::Priv(opc) => priv_extension::bit_32::encode(inst, opc, isa),
+            OpcodeKind::B(opc) => b_extension::bit_32::encode(inst, opc, isa),
+            OpcodeKind::V(opc) => v_extension::bit_32::encode(inst, opc),
+            OpcodeKind::C(_) => Err(EncodingError::Not32BitInst),
+        }
+    }
+}
+
+/// Assemble R-type instruction.
+pub fn r_type(opcode: u32, rd: u32, funct3: u32, rs1: u32, rs2: u32, funct7: u32) -> u32 {
+    funct7 << 25 | rs2 << 20 | rs1 << 15 | funct3 << 12 | rd << 7 | opcode
+}
+
+/// Assemble I-type instruction.
+/// `imm` is already truncated to 12 bits.
+pub fn i_type(opcode: u32, rd: u32, funct3: u32, rs1: u32, imm: u32) -> u32 {
+    imm << 20 | rs1 << 15 | funct3 << 12 | rd << 7 | opcode
+}
+
+/// Assemble S-type instruction.
+/// `imm` is already truncated to 12 bits.
+pub fn s_type(opcode: u32, funct3: u32, rs1: u32, rs2: u32, imm: u32) -> u32 {
+    (imm >> 5) << 25 | rs2 << 20 | rs1 << 15 | funct3 << 12 | (imm & 0x1f) << 7 | opcode
+}
+
+/// Assemble B-type instruction.
+/// `imm` is already truncated to 13 bits.
+pub fn b_type(opcode: u32, funct3: u32, rs1: u32, rs2: u32, imm: u32) -> u32 {
+    let imm_hi = super::gather(imm, &[12, 10, 9, 8, 7, 6, 5]);
+    let imm_lo = super::gather(imm, &[4, 3, 2, 1, 11]);
+    imm_hi << 25 | rs2 << 20 | rs1 << 15 | funct3 << 12 | imm_lo << 7 | opcode
+}
+
+/// Assemble U-type instruction.
+/// `imm` is already truncated to 20 bits.
+pub fn u_type(opcode: u32, rd: u32, imm: u32) -> u32 {
+    imm << 12 | rd << 7 | opcode
+}
+
+/// Assemble J-type instruction.
+/// `imm` is already truncated to 21 bits.
+pub fn j_type(opcode: u32, rd: u32, imm: u32) -> u32 {
+    let imm = super::gather(
+        imm,
+        &[
+            20, 10, 9, 8, 7, 6, 5, 4, 3, 2, 1, 11, 19, 18, 17, 16, 15, 14, 13, 12,
+        ],
+    );
+    imm << 12 | rd << 7 | opcode
+}
+
+#[cfg(test)]
+#[track_caller]
+pub fn test_round_trip_32(isa: Isa, inst_32: u32) {
+    use crate::decode::Decode;
+
+    let inst = inst_32
+        .decode(isa)
+        .unwrap_or_else(|e| panic!("{e:?}: failed to decode {inst_32:032b}"));
+    assert_eq!(
+        u32::encode(&inst, isa),
+        Ok(inst_32),
+        "encoded value does not match: {inst:?} ({inst_32:#010x})"
+    );
+}
+
+#[cfg(test)]
+#[track_caller]
+pub fn test_round_trip_32_in_rv32(inst_32: u32) {
+    test_round_trip_32(Isa::Rv32, inst_32);
+}
+
+#[cfg(test)]
+#[track_caller]
+pub fn test_round_trip_32_in_rv64(inst_32: u32) {
+    test_round_trip_32(Isa::Rv64, inst_32);
+}
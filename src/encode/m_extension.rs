@@ -0,0 +1,60 @@
+pub mod bit_32 {
+    use super::super::inst_32::r_type;
+    use super::super::{only_rv64, reg, EncodingError};
+    use crate::instruction::{m_extension::MOpcode, Instruction};
+    use crate::Isa;
+
+    pub fn encode(inst: &Instruction, opc: &MOpcode, isa: Isa) -> Result<u32, EncodingError> {
+        let op = |funct3| {
+            Ok(r_type(
+                0b011_0011,
+                reg(inst.rd)?,
+                funct3,
+                reg(inst.rs1)?,
+                reg(inst.rs2)?,
+                0b000_0001,
+            ))
+        };
+        let op_32 = |funct3| {
+            only_rv64(isa)?;
+            Ok(r_type(
+                0b011_1011,
+                reg(inst.rd)?,
+                funct3,
+                reg(inst.rs1)?,
+                reg(inst.rs2)?,
+                0b000_0001,
+            ))
+        };
+
+        match opc {
+            MOpcode::MUL => op(0b000),
+            MOpcode::MULH => op(0b001),
+            MOpcode::MULHSU => op(0b010),
+            MOpcode::MULHU => op(0b011),
+            MOpcode::DIV => op(0b100),
+            MOpcode::DIVU => op(0b101),
+            MOpcode::REM => op(0b110),
+            MOpcode::REMU => op(0b111),
+            MOpcode::MULW => op_32(0b000),
+            MOpcode::DIVW => op_32(0b100),
+            MOpcode::DIVUW => op_32(0b101),
+            MOpcode::REMW => op_32(0b110),
+            MOpcode::REMUW => op_32(0b111),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_m {
+    #[test]
+    fn m_round_trip_test() {
+        use crate::encode::inst_32::{test_round_trip_32_in_rv32, test_round_trip_32_in_rv64};
+
+        test_round_trip_32_in_rv64(0x02d7_06b3); // mul a3, a4, a3
+        test_round_trip_32_in_rv64(0x0289_7933); // remu s2, s2, s0
+        test_round_trip_32_in_rv64(0x0289_5933); // divu s2, s2, s0
+        test_round_trip_32_in_rv64(0x02b5_053b); // mulw a0, a0, a1
+        test_round_trip_32_in_rv32(0x02b5_2533); // mulhsu a0, a0, a1
+    }
+}
@@ -0,0 +1,78 @@
+pub mod bit_32 {
+    use super::super::inst_32::r_type;
+    use super::super::{only_rv64, reg, EncodingError};
+    use crate::instruction::{priv_extension::PrivOpcode, Instruction};
+    use crate::Isa;
+
+    pub fn encode(inst: &Instruction, opc: &PrivOpcode, isa: Isa) -> Result<u32, EncodingError> {
+        let rd = || reg(inst.rd);
+        let rs1 = || reg(inst.rs1);
+        let rs2 = || reg(inst.rs2);
+
+        let fence = |funct7| Ok(r_type(0b111_0011, 0, 0b000, rs1()?, rs2()?, funct7));
+        // the rs2 field of hlv/hlvx is used as funct5.
+        let hlv = |funct7, funct5| Ok(r_type(0b111_0011, rd()?, 0b100, rs1()?, funct5, funct7));
+        let hsv = |funct7| Ok(r_type(0b111_0011, 0, 0b100, rs1()?, rs2()?, funct7));
+
+        match opc {
+            PrivOpcode::SRET => Ok(0b0001_0000_0010_0000_0000_0000_0111_0011),
+            PrivOpcode::MRET => Ok(0b0011_0000_0010_0000_0000_0000_0111_0011),
+            PrivOpcode::WFI => Ok(0b0001_0000_0101_0000_0000_0000_0111_0011),
+            PrivOpcode::MNRET => Ok(0b0111_0000_0010_0000_0000_0000_0111_0011),
+            PrivOpcode::DRET => Ok(0b0111_1011_0010_0000_0000_0000_0111_0011),
+            PrivOpcode::SFENCE_W_INVAL => Ok(0b0001_1000_0000_0000_0000_0000_0111_0011),
+            PrivOpcode::SFENCE_INVAL_IR => Ok(0b0001_1000_0001_0000_0000_0000_0111_0011),
+            PrivOpcode::SFENCE_VMA => fence(0b000_1001),
+            PrivOpcode::SINVAL_VMA => fence(0b000_1011),
+            PrivOpcode::HFENCE_VVMA => fence(0b001_0001),
+            PrivOpcode::HFENCE_GVMA => fence(0b011_0001),
+            PrivOpcode::HLV_B => hlv(0b011_0000, 0b00000),
+            PrivOpcode::HLV_BU => hlv(0b011_0000, 0b00001),
+            PrivOpcode::HLV_H => hlv(0b011_0010, 0b00000),
+            PrivOpcode::HLV_HU => hlv(0b011_0010, 0b00001),
+            PrivOpcode::HLVX_HU => hlv(0b011_0010, 0b00011),
+            PrivOpcode::HLV_W => hlv(0b011_0100, 0b00000),
+            PrivOpcode::HLVX_WU => hlv(0b011_0100, 0b00011),
+            PrivOpcode::HSV_B => hsv(0b011_0001),
+            PrivOpcode::HSV_H => hsv(0b011_0011),
+            PrivOpcode::HSV_W => hsv(0b011_0101),
+            PrivOpcode::HLV_WU => {
+                only_rv64(isa)?;
+                hlv(0b011_0100, 0b00001)
+            }
+            PrivOpcode::HLV_D => {
+                only_rv64(isa)?;
+                hlv(0b011_0110, 0b00000)
+            }
+            PrivOpcode::HSV_D => {
+                only_rv64(isa)?;
+                hsv(0b011_0111)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_priv {
+    #[test]
+    fn priv_round_trip_test() {
+        use crate::encode::inst_32::{test_round_trip_32_in_rv32, test_round_trip_32_in_rv64};
+
+        test_round_trip_32_in_rv64(0x1020_0073); // sret
+        test_round_trip_32_in_rv64(0x3020_0073); // mret
+        test_round_trip_32_in_rv64(0x1050_0073); // wfi
+        test_round_trip_32_in_rv64(0x12b5_0073); // sfence.vma a0, a1
+        test_round_trip_32_in_rv64(0x7020_0073); // mnret
+        test_round_trip_32_in_rv64(0x7b20_0073); // dret
+        test_round_trip_32_in_rv64(0x16b5_0073); // sinval.vma a0, a1
+        test_round_trip_32_in_rv64(0x1800_0073); // sfence.w.inval
+        test_round_trip_32_in_rv64(0x1810_0073); // sfence.inval.ir
+        test_round_trip_32_in_rv64(0x22b5_0073); // hfence.vvma a0, a1
+        test_round_trip_32_in_rv64(0x62b5_0073); // hfence.gvma a0, a1
+        test_round_trip_32_in_rv64(0x6815_c573); // hlv.wu a0, (a1)
+        test_round_trip_32_in_rv64(0x6ea5_c073); // hsv.d a0, (a1)
+        test_round_trip_32_in_rv32(0x6805_c573); // hlv.w a0, (a1)
+        test_round_trip_32_in_rv32(0x6435_c573); // hlvx.hu a0, (a1)
+        test_round_trip_32_in_rv32(0x62a5_c073); // hsv.b a0, (a1)
+    }
+}
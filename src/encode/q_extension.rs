@@ -0,0 +1,102 @@
+pub mod bit_32 {
+    use super::super::inst_32::{i_type, r_type, s_type};
+    use super::super::{imm, only_rv64, reg, signed, EncodingError};
+    use crate::instruction::{f_extension::RoundingMode, q_extension::QOpcode, Instruction};
+    use crate::Isa;
+
+    #[allow(non_snake_case)]
+    pub fn encode(inst: &Instruction, opc: &QOpcode, isa: Isa) -> Result<u32, EncodingError> {
+        let rd = || reg(inst.rd);
+        let rs1 = || reg(inst.rs1);
+        let rs2 = || reg(inst.rs2);
+        let rs3 = || reg(inst.rs3);
+        let I_imm = || signed(imm(inst.imm)?, 12, 1);
+        let S_imm = || signed(imm(inst.imm)?, 12, 1);
+        let rm = || {
+            inst.rm
+                .map(RoundingMode::to_rm)
+                .ok_or(EncodingError::MissingOperand)
+        };
+
+        let r4 = |opcode| {
+            Ok(r_type(
+                opcode,
+                rd()?,
+                rm()?,
+                rs1()?,
+                rs2()?,
+                rs3()? << 2 | 0b11,
+            ))
+        };
+        let op_fp = |funct7| Ok(r_type(0b101_0011, rd()?, rm()?, rs1()?, rs2()?, funct7));
+        let op_fp_funct3 =
+            |funct3, funct7| Ok(r_type(0b101_0011, rd()?, funct3, rs1()?, rs2()?, funct7));
+        let op_fp_unary =
+            |funct7, funct5| Ok(r_type(0b101_0011, rd()?, rm()?, rs1()?, funct5, funct7));
+        let op_fp_unary_funct3 =
+            |funct3, funct7, funct5| Ok(r_type(0b101_0011, rd()?, funct3, rs1()?, funct5, funct7));
+
+        match opc {
+            QOpcode::FLQ => Ok(i_type(0b000_0111, rd()?, 0b100, rs1()?, I_imm()?)),
+            QOpcode::FSQ => Ok(s_type(0b010_0111, 0b100, rs1()?, rs2()?, S_imm()?)),
+            QOpcode::FMADD_Q => r4(0b100_0011),
+            QOpcode::FMSUB_Q => r4(0b100_0111),
+            QOpcode::FNMSUB_Q => r4(0b100_1011),
+            QOpcode::FNMADD_Q => r4(0b100_1111),
+            QOpcode::FADD_Q => op_fp(0b000_0011),
+            QOpcode::FSUB_Q => op_fp(0b000_0111),
+            QOpcode::FMUL_Q => op_fp(0b000_1011),
+            QOpcode::FDIV_Q => op_fp(0b000_1111),
+            QOpcode::FSQRT_Q => op_fp_unary(0b010_1111, 0b00000),
+            QOpcode::FSGNJ_Q => op_fp_funct3(0b000, 0b001_0011),
+            QOpcode::FSGNJN_Q => op_fp_funct3(0b001, 0b001_0011),
+            QOpcode::FSGNJX_Q => op_fp_funct3(0b010, 0b001_0011),
+            QOpcode::FMIN_Q => op_fp_funct3(0b000, 0b001_0111),
+            QOpcode::FMAX_Q => op_fp_funct3(0b001, 0b001_0111),
+            QOpcode::FCVT_S_Q => op_fp_unary(0b010_0000, 0b00011),
+            QOpcode::FCVT_Q_S => op_fp_unary(0b010_0011, 0b00000),
+            QOpcode::FCVT_D_Q => op_fp_unary(0b010_0001, 0b00011),
+            QOpcode::FCVT_Q_D => op_fp_unary(0b010_0011, 0b00001),
+            QOpcode::FEQ_Q => op_fp_funct3(0b010, 0b101_0011),
+            QOpcode::FLT_Q => op_fp_funct3(0b001, 0b101_0011),
+            QOpcode::FLE_Q => op_fp_funct3(0b000, 0b101_0011),
+            QOpcode::FCLASS_Q => op_fp_unary_funct3(0b001, 0b111_0011, 0b00000),
+            QOpcode::FCVT_W_Q => op_fp_unary(0b110_0011, 0b00000),
+            QOpcode::FCVT_WU_Q => op_fp_unary(0b110_0011, 0b00001),
+            QOpcode::FCVT_Q_W => op_fp_unary(0b110_1011, 0b00000),
+            QOpcode::FCVT_Q_WU => op_fp_unary(0b110_1011, 0b00001),
+            QOpcode::FCVT_L_Q => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_0011, 0b00010)
+            }
+            QOpcode::FCVT_LU_Q => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_0011, 0b00011)
+            }
+            QOpcode::FCVT_Q_L => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_1011, 0b00010)
+            }
+            QOpcode::FCVT_Q_LU => {
+                only_rv64(isa)?;
+                op_fp_unary(0b110_1011, 0b00011)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_q {
+    #[test]
+    #[allow(overflowing_literals)]
+    fn q_round_trip_test() {
+        use crate::encode::inst_32::{test_round_trip_32_in_rv32, test_round_trip_32_in_rv64};
+
+        test_round_trip_32_in_rv64(0x0105_4507); // flq fa0, 16(a0)
+        test_round_trip_32_in_rv64(0x06b5_7553); // fadd.q fa0, fa0, fa1
+        test_round_trip_32_in_rv64(0x4035_f553); // fcvt.s.q fa0, fa1
+        test_round_trip_32_in_rv64(0x4615_8553); // fcvt.q.d fa0, fa1
+        test_round_trip_32_in_rv64(0xd635_7553); // fcvt.q.lu fa0, a0
+        test_round_trip_32_in_rv32(0xc605_1553); // fcvt.w.q a0, fa0, rtz
+    }
+}
@@ -0,0 +1,485 @@
+pub mod bit_32 {
+    use super::super::inst_32::r_type;
+    use super::super::{imm, reg, signed, unsigned, EncodingError};
+    use crate::instruction::{v_extension::VOpcode, Instruction};
+
+    #[allow(clippy::too_many_lines)]
+    pub fn encode(inst: &Instruction, opc: &VOpcode) -> Result<u32, EncodingError> {
+        let rd = || reg(inst.rd);
+        let rs1 = || reg(inst.rs1);
+        let rs2 = || reg(inst.rs2);
+        let vs1 = || reg(inst.rs1);
+        let vs2 = || reg(inst.rs2);
+        let vs3 = || reg(inst.rs3);
+        let vm = || inst.vm.map(u32::from).ok_or(EncodingError::MissingOperand);
+        let simm5 = || signed(imm(inst.imm)?, 5, 1);
+        let uimm5 = || unsigned(imm(inst.imm)?, 5, 1);
+
+        let op_v = |funct3: u32, funct6: u32, vm: u32, vs2: u32, vs1: u32| {
+            Ok(funct6 << 26
+                | vm << 25
+                | vs2 << 20
+                | vs1 << 15
+                | funct3 << 12
+                | rd()? << 7
+                | 0b101_0111)
+        };
+        // the field at [24:20] is lumop/sumop, rs2 (stride) or vs2 (index).
+        let load = |nf: u32, mop: u32, field: u32, width: u32| {
+            Ok(nf << 29
+                | mop << 26
+                | vm()? << 25
+                | field << 20
+                | rs1()? << 15
+                | width << 12
+                | rd()? << 7
+                | 0b000_0111)
+        };
+        let store = |nf: u32, mop: u32, field: u32, width: u32| {
+            Ok(nf << 29
+                | mop << 26
+                | vm()? << 25
+                | field << 20
+                | rs1()? << 15
+                | width << 12
+                | vs3()? << 7
+                | 0b010_0111)
+        };
+
+        if inst.vm == Some(false) && opc.is_unmasked_only() {
+            return Err(EncodingError::ReservedEncoding);
+        }
+
+        match opc {
+            VOpcode::VSETVLI => {
+                let zimm = unsigned(imm(inst.imm)?, 11, 1)?;
+                Ok(zimm << 20 | rs1()? << 15 | 0b111 << 12 | rd()? << 7 | 0b101_0111)
+            }
+            VOpcode::VSETIVLI => {
+                let zimm = unsigned(imm(inst.imm)?, 10, 1)?;
+                Ok(0b11 << 30 | zimm << 20 | rs1()? << 15 | 0b111 << 12 | rd()? << 7 | 0b101_0111)
+            }
+            VOpcode::VSETVL => Ok(r_type(0b101_0111, rd()?, 0b111, rs1()?, rs2()?, 0b100_0000)),
+            VOpcode::VLE8_V => load(0b000, 0b00, 0b00000, 0b000),
+            VOpcode::VLE16_V => load(0b000, 0b00, 0b00000, 0b101),
+            VOpcode::VLE32_V => load(0b000, 0b00, 0b00000, 0b110),
+            VOpcode::VLE64_V => load(0b000, 0b00, 0b00000, 0b111),
+            VOpcode::VLE8FF_V => load(0b000, 0b00, 0b10000, 0b000),
+            VOpcode::VLE16FF_V => load(0b000, 0b00, 0b10000, 0b101),
+            VOpcode::VLE32FF_V => load(0b000, 0b00, 0b10000, 0b110),
+            VOpcode::VLE64FF_V => load(0b000, 0b00, 0b10000, 0b111),
+            VOpcode::VLUXEI8_V => load(0b000, 0b01, vs2()?, 0b000),
+            VOpcode::VLUXEI16_V => load(0b000, 0b01, vs2()?, 0b101),
+            VOpcode::VLUXEI32_V => load(0b000, 0b01, vs2()?, 0b110),
+            VOpcode::VLUXEI64_V => load(0b000, 0b01, vs2()?, 0b111),
+            VOpcode::VLSE8_V => load(0b000, 0b10, rs2()?, 0b000),
+            VOpcode::VLSE16_V => load(0b000, 0b10, rs2()?, 0b101),
+            VOpcode::VLSE32_V => load(0b000, 0b10, rs2()?, 0b110),
+            VOpcode::VLSE64_V => load(0b000, 0b10, rs2()?, 0b111),
+            VOpcode::VLOXEI8_V => load(0b000, 0b11, vs2()?, 0b000),
+            VOpcode::VLOXEI16_V => load(0b000, 0b11, vs2()?, 0b101),
+            VOpcode::VLOXEI32_V => load(0b000, 0b11, vs2()?, 0b110),
+            VOpcode::VLOXEI64_V => load(0b000, 0b11, vs2()?, 0b111),
+            VOpcode::VLM_V => load(0b000, 0b00, 0b01011, 0b000),
+            VOpcode::VL1RE8_V => load(0b000, 0b00, 0b01000, 0b000),
+            VOpcode::VL1RE16_V => load(0b000, 0b00, 0b01000, 0b101),
+            VOpcode::VL1RE32_V => load(0b000, 0b00, 0b01000, 0b110),
+            VOpcode::VL1RE64_V => load(0b000, 0b00, 0b01000, 0b111),
+            VOpcode::VL2RE8_V => load(0b001, 0b00, 0b01000, 0b000),
+            VOpcode::VL2RE16_V => load(0b001, 0b00, 0b01000, 0b101),
+            VOpcode::VL2RE32_V => load(0b001, 0b00, 0b01000, 0b110),
+            VOpcode::VL2RE64_V => load(0b001, 0b00, 0b01000, 0b111),
+            VOpcode::VL4RE8_V => load(0b011, 0b00, 0b01000, 0b000),
+            VOpcode::VL4RE16_V => load(0b011, 0b00, 0b01000, 0b101),
+            VOpcode::VL4RE32_V => load(0b011, 0b00, 0b01000, 0b110),
+            VOpcode::VL4RE64_V => load(0b011, 0b00, 0b01000, 0b111),
+            VOpcode::VL8RE8_V => load(0b111, 0b00, 0b01000, 0b000),
+            VOpcode::VL8RE16_V => load(0b111, 0b00, 0b01000, 0b101),
+            VOpcode::VL8RE32_V => load(0b111, 0b00, 0b01000, 0b110),
+            VOpcode::VL8RE64_V => load(0b111, 0b00, 0b01000, 0b111),
+            VOpcode::VSE8_V => store(0b000, 0b00, 0b00000, 0b000),
+            VOpcode::VSE16_V => store(0b000, 0b00, 0b00000, 0b101),
+            VOpcode::VSE32_V => store(0b000, 0b00, 0b00000, 0b110),
+            VOpcode::VSE64_V => store(0b000, 0b00, 0b00000, 0b111),
+            VOpcode::VSUXEI8_V => store(0b000, 0b01, vs2()?, 0b000),
+            VOpcode::VSUXEI16_V => store(0b000, 0b01, vs2()?, 0b101),
+            VOpcode::VSUXEI32_V => store(0b000, 0b01, vs2()?, 0b110),
+            VOpcode::VSUXEI64_V => store(0b000, 0b01, vs2()?, 0b111),
+            VOpcode::VSSE8_V => store(0b000, 0b10, rs2()?, 0b000),
+            VOpcode::VSSE16_V => store(0b000, 0b10, rs2()?, 0b101),
+            VOpcode::VSSE32_V => store(0b000, 0b10, rs2()?, 0b110),
+            VOpcode::VSSE64_V => store(0b000, 0b10, rs2()?, 0b111),
+            VOpcode::VSOXEI8_V => store(0b000, 0b11, vs2()?, 0b000),
+            VOpcode::VSOXEI16_V => store(0b000, 0b11, vs2()?, 0b101),
+            VOpcode::VSOXEI32_V => store(0b000, 0b11, vs2()?, 0b110),
+            VOpcode::VSOXEI64_V => store(0b000, 0b11, vs2()?, 0b111),
+            VOpcode::VSM_V => store(0b000, 0b00, 0b01011, 0b000),
+            VOpcode::VS1R_V => store(0b000, 0b00, 0b01000, 0b000),
+            VOpcode::VS2R_V => store(0b001, 0b00, 0b01000, 0b000),
+            VOpcode::VS4R_V => store(0b011, 0b00, 0b01000, 0b000),
+            VOpcode::VS8R_V => store(0b111, 0b00, 0b01000, 0b000),
+            VOpcode::VADD_VV => op_v(0b000, 0b00_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VADD_VX => op_v(0b100, 0b00_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VADD_VI => op_v(0b011, 0b00_0000, vm()?, vs2()?, simm5()?),
+            VOpcode::VSUB_VV => op_v(0b000, 0b00_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VSUB_VX => op_v(0b100, 0b00_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VRSUB_VX => op_v(0b100, 0b00_0011, vm()?, vs2()?, vs1()?),
+            VOpcode::VRSUB_VI => op_v(0b011, 0b00_0011, vm()?, vs2()?, simm5()?),
+            VOpcode::VMINU_VV => op_v(0b000, 0b00_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VMINU_VX => op_v(0b100, 0b00_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VMIN_VV => op_v(0b000, 0b00_0101, vm()?, vs2()?, vs1()?),
+            VOpcode::VMIN_VX => op_v(0b100, 0b00_0101, vm()?, vs2()?, vs1()?),
+            VOpcode::VMAXU_VV => op_v(0b000, 0b00_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VMAXU_VX => op_v(0b100, 0b00_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VMAX_VV => op_v(0b000, 0b00_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VMAX_VX => op_v(0b100, 0b00_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VAND_VV => op_v(0b000, 0b00_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VAND_VX => op_v(0b100, 0b00_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VAND_VI => op_v(0b011, 0b00_1001, vm()?, vs2()?, simm5()?),
+            VOpcode::VOR_VV => op_v(0b000, 0b00_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VOR_VX => op_v(0b100, 0b00_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VOR_VI => op_v(0b011, 0b00_1010, vm()?, vs2()?, simm5()?),
+            VOpcode::VXOR_VV => op_v(0b000, 0b00_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VXOR_VX => op_v(0b100, 0b00_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VXOR_VI => op_v(0b011, 0b00_1011, vm()?, vs2()?, simm5()?),
+            VOpcode::VRGATHER_VV => op_v(0b000, 0b00_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VRGATHER_VX => op_v(0b100, 0b00_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VRGATHER_VI => op_v(0b011, 0b00_1100, vm()?, vs2()?, uimm5()?),
+            VOpcode::VRGATHEREI16_VV => op_v(0b000, 0b00_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VSLIDEUP_VX => op_v(0b100, 0b00_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VSLIDEUP_VI => op_v(0b011, 0b00_1110, vm()?, vs2()?, uimm5()?),
+            VOpcode::VSLIDEDOWN_VX => op_v(0b100, 0b00_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VSLIDEDOWN_VI => op_v(0b011, 0b00_1111, vm()?, vs2()?, uimm5()?),
+            VOpcode::VADC_VVM => op_v(0b000, 0b01_0000, 0b0, vs2()?, vs1()?),
+            VOpcode::VADC_VXM => op_v(0b100, 0b01_0000, 0b0, vs2()?, vs1()?),
+            VOpcode::VADC_VIM => op_v(0b011, 0b01_0000, 0b0, vs2()?, simm5()?),
+            VOpcode::VMADC_VVM => op_v(0b000, 0b01_0001, 0b0, vs2()?, vs1()?),
+            VOpcode::VMADC_VXM => op_v(0b100, 0b01_0001, 0b0, vs2()?, vs1()?),
+            VOpcode::VMADC_VIM => op_v(0b011, 0b01_0001, 0b0, vs2()?, simm5()?),
+            VOpcode::VMADC_VV => op_v(0b000, 0b01_0001, 0b1, vs2()?, vs1()?),
+            VOpcode::VMADC_VX => op_v(0b100, 0b01_0001, 0b1, vs2()?, vs1()?),
+            VOpcode::VMADC_VI => op_v(0b011, 0b01_0001, 0b1, vs2()?, simm5()?),
+            VOpcode::VSBC_VVM => op_v(0b000, 0b01_0010, 0b0, vs2()?, vs1()?),
+            VOpcode::VSBC_VXM => op_v(0b100, 0b01_0010, 0b0, vs2()?, vs1()?),
+            VOpcode::VMSBC_VVM => op_v(0b000, 0b01_0011, 0b0, vs2()?, vs1()?),
+            VOpcode::VMSBC_VXM => op_v(0b100, 0b01_0011, 0b0, vs2()?, vs1()?),
+            VOpcode::VMSBC_VV => op_v(0b000, 0b01_0011, 0b1, vs2()?, vs1()?),
+            VOpcode::VMSBC_VX => op_v(0b100, 0b01_0011, 0b1, vs2()?, vs1()?),
+            VOpcode::VMERGE_VVM => op_v(0b000, 0b01_0111, 0b0, vs2()?, vs1()?),
+            VOpcode::VMERGE_VXM => op_v(0b100, 0b01_0111, 0b0, vs2()?, vs1()?),
+            VOpcode::VMERGE_VIM => op_v(0b011, 0b01_0111, 0b0, vs2()?, simm5()?),
+            VOpcode::VMV_V_V => op_v(0b000, 0b01_0111, 0b1, 0b00000, vs1()?),
+            VOpcode::VMV_V_X => op_v(0b100, 0b01_0111, 0b1, 0b00000, vs1()?),
+            VOpcode::VMV_V_I => op_v(0b011, 0b01_0111, 0b1, 0b00000, simm5()?),
+            VOpcode::VMSEQ_VV => op_v(0b000, 0b01_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSEQ_VX => op_v(0b100, 0b01_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSEQ_VI => op_v(0b011, 0b01_1000, vm()?, vs2()?, simm5()?),
+            VOpcode::VMSNE_VV => op_v(0b000, 0b01_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSNE_VX => op_v(0b100, 0b01_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSNE_VI => op_v(0b011, 0b01_1001, vm()?, vs2()?, simm5()?),
+            VOpcode::VMSLTU_VV => op_v(0b000, 0b01_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSLTU_VX => op_v(0b100, 0b01_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSLT_VV => op_v(0b000, 0b01_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSLT_VX => op_v(0b100, 0b01_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSLEU_VV => op_v(0b000, 0b01_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSLEU_VX => op_v(0b100, 0b01_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSLEU_VI => op_v(0b011, 0b01_1100, vm()?, vs2()?, simm5()?),
+            VOpcode::VMSLE_VV => op_v(0b000, 0b01_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSLE_VX => op_v(0b100, 0b01_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSLE_VI => op_v(0b011, 0b01_1101, vm()?, vs2()?, simm5()?),
+            VOpcode::VMSGTU_VX => op_v(0b100, 0b01_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSGTU_VI => op_v(0b011, 0b01_1110, vm()?, vs2()?, simm5()?),
+            VOpcode::VMSGT_VX => op_v(0b100, 0b01_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VMSGT_VI => op_v(0b011, 0b01_1111, vm()?, vs2()?, simm5()?),
+            VOpcode::VSADDU_VV => op_v(0b000, 0b10_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VSADDU_VX => op_v(0b100, 0b10_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VSADDU_VI => op_v(0b011, 0b10_0000, vm()?, vs2()?, simm5()?),
+            VOpcode::VSADD_VV => op_v(0b000, 0b10_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VSADD_VX => op_v(0b100, 0b10_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VSADD_VI => op_v(0b011, 0b10_0001, vm()?, vs2()?, simm5()?),
+            VOpcode::VSSUBU_VV => op_v(0b000, 0b10_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VSSUBU_VX => op_v(0b100, 0b10_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VSSUB_VV => op_v(0b000, 0b10_0011, vm()?, vs2()?, vs1()?),
+            VOpcode::VSSUB_VX => op_v(0b100, 0b10_0011, vm()?, vs2()?, vs1()?),
+            VOpcode::VSLL_VV => op_v(0b000, 0b10_0101, vm()?, vs2()?, vs1()?),
+            VOpcode::VSLL_VX => op_v(0b100, 0b10_0101, vm()?, vs2()?, vs1()?),
+            VOpcode::VSLL_VI => op_v(0b011, 0b10_0101, vm()?, vs2()?, uimm5()?),
+            VOpcode::VSMUL_VV => op_v(0b000, 0b10_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VSMUL_VX => op_v(0b100, 0b10_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VMV1R_V => op_v(0b011, 0b10_0111, 0b1, vs2()?, 0b00000),
+            VOpcode::VMV2R_V => op_v(0b011, 0b10_0111, 0b1, vs2()?, 0b00001),
+            VOpcode::VMV4R_V => op_v(0b011, 0b10_0111, 0b1, vs2()?, 0b00011),
+            VOpcode::VMV8R_V => op_v(0b011, 0b10_0111, 0b1, vs2()?, 0b00111),
+            VOpcode::VSRL_VV => op_v(0b000, 0b10_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VSRL_VX => op_v(0b100, 0b10_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VSRL_VI => op_v(0b011, 0b10_1000, vm()?, vs2()?, uimm5()?),
+            VOpcode::VSRA_VV => op_v(0b000, 0b10_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VSRA_VX => op_v(0b100, 0b10_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VSRA_VI => op_v(0b011, 0b10_1001, vm()?, vs2()?, uimm5()?),
+            VOpcode::VSSRL_VV => op_v(0b000, 0b10_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VSSRL_VX => op_v(0b100, 0b10_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VSSRL_VI => op_v(0b011, 0b10_1010, vm()?, vs2()?, uimm5()?),
+            VOpcode::VSSRA_VV => op_v(0b000, 0b10_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VSSRA_VX => op_v(0b100, 0b10_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VSSRA_VI => op_v(0b011, 0b10_1011, vm()?, vs2()?, uimm5()?),
+            VOpcode::VNSRL_WV => op_v(0b000, 0b10_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VNSRL_WX => op_v(0b100, 0b10_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VNSRL_WI => op_v(0b011, 0b10_1100, vm()?, vs2()?, uimm5()?),
+            VOpcode::VNSRA_WV => op_v(0b000, 0b10_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VNSRA_WX => op_v(0b100, 0b10_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VNSRA_WI => op_v(0b011, 0b10_1101, vm()?, vs2()?, uimm5()?),
+            VOpcode::VNCLIPU_WV => op_v(0b000, 0b10_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VNCLIPU_WX => op_v(0b100, 0b10_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VNCLIPU_WI => op_v(0b011, 0b10_1110, vm()?, vs2()?, uimm5()?),
+            VOpcode::VNCLIP_WV => op_v(0b000, 0b10_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VNCLIP_WX => op_v(0b100, 0b10_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VNCLIP_WI => op_v(0b011, 0b10_1111, vm()?, vs2()?, uimm5()?),
+            VOpcode::VWREDSUMU_VS => op_v(0b000, 0b11_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VWREDSUM_VS => op_v(0b000, 0b11_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VREDSUM_VS => op_v(0b010, 0b00_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VREDAND_VS => op_v(0b010, 0b00_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VREDOR_VS => op_v(0b010, 0b00_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VREDXOR_VS => op_v(0b010, 0b00_0011, vm()?, vs2()?, vs1()?),
+            VOpcode::VREDMINU_VS => op_v(0b010, 0b00_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VREDMIN_VS => op_v(0b010, 0b00_0101, vm()?, vs2()?, vs1()?),
+            VOpcode::VREDMAXU_VS => op_v(0b010, 0b00_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VREDMAX_VS => op_v(0b010, 0b00_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VAADDU_VV => op_v(0b010, 0b00_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VAADDU_VX => op_v(0b110, 0b00_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VAADD_VV => op_v(0b010, 0b00_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VAADD_VX => op_v(0b110, 0b00_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VASUBU_VV => op_v(0b010, 0b00_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VASUBU_VX => op_v(0b110, 0b00_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VASUB_VV => op_v(0b010, 0b00_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VASUB_VX => op_v(0b110, 0b00_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VSLIDE1UP_VX => op_v(0b110, 0b00_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VSLIDE1DOWN_VX => op_v(0b110, 0b00_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VMV_X_S => op_v(0b010, 0b01_0000, vm()?, vs2()?, 0b00000),
+            VOpcode::VCPOP_M => op_v(0b010, 0b01_0000, vm()?, vs2()?, 0b10000),
+            VOpcode::VFIRST_M => op_v(0b010, 0b01_0000, vm()?, vs2()?, 0b10001),
+            VOpcode::VMV_S_X => op_v(0b110, 0b01_0000, vm()?, 0b00000, vs1()?),
+            VOpcode::VZEXT_VF8 => op_v(0b010, 0b01_0010, vm()?, vs2()?, 0b00010),
+            VOpcode::VSEXT_VF8 => op_v(0b010, 0b01_0010, vm()?, vs2()?, 0b00011),
+            VOpcode::VZEXT_VF4 => op_v(0b010, 0b01_0010, vm()?, vs2()?, 0b00100),
+            VOpcode::VSEXT_VF4 => op_v(0b010, 0b01_0010, vm()?, vs2()?, 0b00101),
+            VOpcode::VZEXT_VF2 => op_v(0b010, 0b01_0010, vm()?, vs2()?, 0b00110),
+            VOpcode::VSEXT_VF2 => op_v(0b010, 0b01_0010, vm()?, vs2()?, 0b00111),
+            VOpcode::VMSBF_M => op_v(0b010, 0b01_0100, vm()?, vs2()?, 0b00001),
+            VOpcode::VMSOF_M => op_v(0b010, 0b01_0100, vm()?, vs2()?, 0b00010),
+            VOpcode::VMSIF_M => op_v(0b010, 0b01_0100, vm()?, vs2()?, 0b00011),
+            VOpcode::VIOTA_M => op_v(0b010, 0b01_0100, vm()?, vs2()?, 0b10000),
+            VOpcode::VID_V => op_v(0b010, 0b01_0100, vm()?, 0b00000, 0b10001),
+            VOpcode::VCOMPRESS_VM => op_v(0b010, 0b01_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VMANDN_MM => op_v(0b010, 0b01_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VMAND_MM => op_v(0b010, 0b01_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VMOR_MM => op_v(0b010, 0b01_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VMXOR_MM => op_v(0b010, 0b01_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VMORN_MM => op_v(0b010, 0b01_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VMNAND_MM => op_v(0b010, 0b01_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VMNOR_MM => op_v(0b010, 0b01_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VMXNOR_MM => op_v(0b010, 0b01_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VDIVU_VV => op_v(0b010, 0b10_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VDIVU_VX => op_v(0b110, 0b10_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VDIV_VV => op_v(0b010, 0b10_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VDIV_VX => op_v(0b110, 0b10_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VREMU_VV => op_v(0b010, 0b10_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VREMU_VX => op_v(0b110, 0b10_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VREM_VV => op_v(0b010, 0b10_0011, vm()?, vs2()?, vs1()?),
+            VOpcode::VREM_VX => op_v(0b110, 0b10_0011, vm()?, vs2()?, vs1()?),
+            VOpcode::VMULHU_VV => op_v(0b010, 0b10_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VMULHU_VX => op_v(0b110, 0b10_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VMUL_VV => op_v(0b010, 0b10_0101, vm()?, vs2()?, vs1()?),
+            VOpcode::VMUL_VX => op_v(0b110, 0b10_0101, vm()?, vs2()?, vs1()?),
+            VOpcode::VMULHSU_VV => op_v(0b010, 0b10_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VMULHSU_VX => op_v(0b110, 0b10_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VMULH_VV => op_v(0b010, 0b10_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VMULH_VX => op_v(0b110, 0b10_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VMADD_VV => op_v(0b010, 0b10_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VMADD_VX => op_v(0b110, 0b10_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VNMSUB_VV => op_v(0b010, 0b10_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VNMSUB_VX => op_v(0b110, 0b10_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VMACC_VV => op_v(0b010, 0b10_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VMACC_VX => op_v(0b110, 0b10_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VNMSAC_VV => op_v(0b010, 0b10_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VNMSAC_VX => op_v(0b110, 0b10_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VWADDU_VV => op_v(0b010, 0b11_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VWADDU_VX => op_v(0b110, 0b11_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VWADD_VV => op_v(0b010, 0b11_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VWADD_VX => op_v(0b110, 0b11_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VWSUBU_VV => op_v(0b010, 0b11_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VWSUBU_VX => op_v(0b110, 0b11_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VWSUB_VV => op_v(0b010, 0b11_0011, vm()?, vs2()?, vs1()?),
+            VOpcode::VWSUB_VX => op_v(0b110, 0b11_0011, vm()?, vs2()?, vs1()?),
+            VOpcode::VWADDU_WV => op_v(0b010, 0b11_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VWADDU_WX => op_v(0b110, 0b11_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VWADD_WV => op_v(0b010, 0b11_0101, vm()?, vs2()?, vs1()?),
+            VOpcode::VWADD_WX => op_v(0b110, 0b11_0101, vm()?, vs2()?, vs1()?),
+            VOpcode::VWSUBU_WV => op_v(0b010, 0b11_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VWSUBU_WX => op_v(0b110, 0b11_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VWSUB_WV => op_v(0b010, 0b11_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VWSUB_WX => op_v(0b110, 0b11_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMULU_VV => op_v(0b010, 0b11_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMULU_VX => op_v(0b110, 0b11_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMULSU_VV => op_v(0b010, 0b11_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMULSU_VX => op_v(0b110, 0b11_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMUL_VV => op_v(0b010, 0b11_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMUL_VX => op_v(0b110, 0b11_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMACCU_VV => op_v(0b010, 0b11_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMACCU_VX => op_v(0b110, 0b11_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMACC_VV => op_v(0b010, 0b11_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMACC_VX => op_v(0b110, 0b11_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMACCUS_VX => op_v(0b110, 0b11_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMACCSU_VV => op_v(0b010, 0b11_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VWMACCSU_VX => op_v(0b110, 0b11_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VFADD_VV => op_v(0b001, 0b00_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFADD_VF => op_v(0b101, 0b00_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFSUB_VV => op_v(0b001, 0b00_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VFSUB_VF => op_v(0b101, 0b00_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMIN_VV => op_v(0b001, 0b00_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMIN_VF => op_v(0b101, 0b00_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMAX_VV => op_v(0b001, 0b00_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMAX_VF => op_v(0b101, 0b00_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VFSGNJ_VV => op_v(0b001, 0b00_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFSGNJ_VF => op_v(0b101, 0b00_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFSGNJN_VV => op_v(0b001, 0b00_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VFSGNJN_VF => op_v(0b101, 0b00_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VFSGNJX_VV => op_v(0b001, 0b00_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VFSGNJX_VF => op_v(0b101, 0b00_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VFREDUSUM_VS => op_v(0b001, 0b00_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VFREDOSUM_VS => op_v(0b001, 0b00_0011, vm()?, vs2()?, vs1()?),
+            VOpcode::VFREDMIN_VS => op_v(0b001, 0b00_0101, vm()?, vs2()?, vs1()?),
+            VOpcode::VFREDMAX_VS => op_v(0b001, 0b00_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VFSLIDE1UP_VF => op_v(0b101, 0b00_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VFSLIDE1DOWN_VF => op_v(0b101, 0b00_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMV_F_S => op_v(0b001, 0b01_0000, vm()?, vs2()?, 0b00000),
+            VOpcode::VFMV_S_F => op_v(0b101, 0b01_0000, vm()?, 0b00000, vs1()?),
+            VOpcode::VFCVT_XU_F_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b00000),
+            VOpcode::VFCVT_X_F_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b00001),
+            VOpcode::VFCVT_F_XU_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b00010),
+            VOpcode::VFCVT_F_X_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b00011),
+            VOpcode::VFCVT_RTZ_XU_F_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b00110),
+            VOpcode::VFCVT_RTZ_X_F_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b00111),
+            VOpcode::VFWCVT_XU_F_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b01000),
+            VOpcode::VFWCVT_X_F_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b01001),
+            VOpcode::VFWCVT_F_XU_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b01010),
+            VOpcode::VFWCVT_F_X_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b01011),
+            VOpcode::VFWCVT_F_F_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b01100),
+            VOpcode::VFWCVT_RTZ_XU_F_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b01110),
+            VOpcode::VFWCVT_RTZ_X_F_V => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b01111),
+            VOpcode::VFNCVT_XU_F_W => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b10000),
+            VOpcode::VFNCVT_X_F_W => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b10001),
+            VOpcode::VFNCVT_F_XU_W => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b10010),
+            VOpcode::VFNCVT_F_X_W => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b10011),
+            VOpcode::VFNCVT_F_F_W => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b10100),
+            VOpcode::VFNCVT_ROD_F_F_W => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b10101),
+            VOpcode::VFNCVT_RTZ_XU_F_W => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b10110),
+            VOpcode::VFNCVT_RTZ_X_F_W => op_v(0b001, 0b01_0010, vm()?, vs2()?, 0b10111),
+            VOpcode::VFSQRT_V => op_v(0b001, 0b01_0011, vm()?, vs2()?, 0b00000),
+            VOpcode::VFRSQRT7_V => op_v(0b001, 0b01_0011, vm()?, vs2()?, 0b00100),
+            VOpcode::VFREC7_V => op_v(0b001, 0b01_0011, vm()?, vs2()?, 0b00101),
+            VOpcode::VFCLASS_V => op_v(0b001, 0b01_0011, vm()?, vs2()?, 0b10000),
+            VOpcode::VFMERGE_VFM => op_v(0b101, 0b01_0111, 0b0, vs2()?, vs1()?),
+            VOpcode::VFMV_V_F => op_v(0b101, 0b01_0111, 0b1, 0b00000, vs1()?),
+            VOpcode::VMFEQ_VV => op_v(0b001, 0b01_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VMFEQ_VF => op_v(0b101, 0b01_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VMFLE_VV => op_v(0b001, 0b01_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VMFLE_VF => op_v(0b101, 0b01_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VMFLT_VV => op_v(0b001, 0b01_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VMFLT_VF => op_v(0b101, 0b01_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VMFNE_VV => op_v(0b001, 0b01_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VMFNE_VF => op_v(0b101, 0b01_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VMFGT_VF => op_v(0b101, 0b01_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VMFGE_VF => op_v(0b101, 0b01_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VFDIV_VV => op_v(0b001, 0b10_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFDIV_VF => op_v(0b101, 0b10_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFRDIV_VF => op_v(0b101, 0b10_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMUL_VV => op_v(0b001, 0b10_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMUL_VF => op_v(0b101, 0b10_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VFRSUB_VF => op_v(0b101, 0b10_0111, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMADD_VV => op_v(0b001, 0b10_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMADD_VF => op_v(0b101, 0b10_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFNMADD_VV => op_v(0b001, 0b10_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VFNMADD_VF => op_v(0b101, 0b10_1001, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMSUB_VV => op_v(0b001, 0b10_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMSUB_VF => op_v(0b101, 0b10_1010, vm()?, vs2()?, vs1()?),
+            VOpcode::VFNMSUB_VV => op_v(0b001, 0b10_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VFNMSUB_VF => op_v(0b101, 0b10_1011, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMACC_VV => op_v(0b001, 0b10_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMACC_VF => op_v(0b101, 0b10_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VFNMACC_VV => op_v(0b001, 0b10_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VFNMACC_VF => op_v(0b101, 0b10_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMSAC_VV => op_v(0b001, 0b10_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VFMSAC_VF => op_v(0b101, 0b10_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VFNMSAC_VV => op_v(0b001, 0b10_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VFNMSAC_VF => op_v(0b101, 0b10_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWADD_VV => op_v(0b001, 0b11_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWADD_VF => op_v(0b101, 0b11_0000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWSUB_VV => op_v(0b001, 0b11_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWSUB_VF => op_v(0b101, 0b11_0010, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWREDUSUM_VS => op_v(0b001, 0b11_0001, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWREDOSUM_VS => op_v(0b001, 0b11_0011, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWADD_WV => op_v(0b001, 0b11_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWADD_WF => op_v(0b101, 0b11_0100, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWSUB_WV => op_v(0b001, 0b11_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWSUB_WF => op_v(0b101, 0b11_0110, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWMUL_VV => op_v(0b001, 0b11_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWMUL_VF => op_v(0b101, 0b11_1000, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWMACC_VV => op_v(0b001, 0b11_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWMACC_VF => op_v(0b101, 0b11_1100, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWNMACC_VV => op_v(0b001, 0b11_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWNMACC_VF => op_v(0b101, 0b11_1101, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWMSAC_VV => op_v(0b001, 0b11_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWMSAC_VF => op_v(0b101, 0b11_1110, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWNMSAC_VV => op_v(0b001, 0b11_1111, vm()?, vs2()?, vs1()?),
+            VOpcode::VFWNMSAC_VF => op_v(0b101, 0b11_1111, vm()?, vs2()?, vs1()?),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_v {
+    #[test]
+    #[allow(overflowing_literals)]
+    fn v_round_trip_test() {
+        use crate::encode::inst_32::{test_round_trip_32_in_rv32, test_round_trip_32_in_rv64};
+
+        test_round_trip_32_in_rv64(0x0d05_f557); // vsetvli a0, a1, e32, m1, ta, ma
+        test_round_trip_32_in_rv64(0xc078_7557); // vsetivli a0, 16, e8, mf2, tu, mu
+        test_round_trip_32_in_rv64(0x80c5_f557); // vsetvl a0, a1, a2
+        test_round_trip_32_in_rv64(0x0005_0407); // vle8.v v8, (a0), v0.t
+        test_round_trip_32_in_rv64(0x0305_7407); // vle64ff.v v8, (a0)
+        test_round_trip_32_in_rv64(0x0705_6407); // vluxei32.v v8, (a0), v16
+        test_round_trip_32_in_rv64(0x08b5_7427); // vsse64.v v8, (a0), a1, v0.t
+        test_round_trip_32_in_rv64(0x0f05_5427); // vsoxei16.v v8, (a0), v16
+        test_round_trip_32_in_rv64(0x02b5_0407); // vlm.v v8, (a0)
+        test_round_trip_32_in_rv64(0x6285_0427); // vs4r.v v8, (a0)
+        test_round_trip_32_in_rv64(0x030e_b457); // vadd.vi v8, v16, -3
+        test_round_trip_32_in_rv64(0x5d0c_0457); // vmerge.vvm v8, v16, v24, v0
+        test_round_trip_32_in_rv64(0x5e02_b457); // vmv.v.i v8, 5
+        test_round_trip_32_in_rv64(0xb302_3457); // vnsrl.wi v8, v16, 4
+        test_round_trip_32_in_rv64(0x4108_2557); // vcpop.m a0, v16, v0.t
+        test_round_trip_32_in_rv64(0x4b02_2457); // vzext.vf4 v8, v16
+        test_round_trip_32_in_rv64(0x670c_2457); // vmand.mm v8, v16, v24
+        test_round_trip_32_in_rv64(0xfb85_6457); // vwmaccus.vx v8, a0, v24
+        test_round_trip_32_in_rv64(0x5e05_5457); // vfmv.v.f v8, fa0
+        test_round_trip_32_in_rv64(0x7705_5057); // vmfgt.vf v0, v16, fa0
+        test_round_trip_32_in_rv32(0x030c_0457); // vadd.vv v8, v16, v24
+        test_round_trip_32_in_rv32(0x2285_6407); // vl2re32.v v8, (a0)
+        test_round_trip_32_in_rv64(0x9e60_3e57); // vmv1r.v v28, v6
+    }
+
+    #[test]
+    fn v_encode_error_test() {
+        use crate::{Decode, Encode, EncodingError, Isa};
+
+        // vmv1r.v and vmand.mm cannot be masked.
+        for bits in [0x9e60_3e57_u32, 0x670c_2457] {
+            let mut inst = bits.decode(Isa::Rv64).unwrap();
+            inst.vm = Some(false);
+            assert_eq!(
+                u32::encode(&inst, Isa::Rv64),
+                Err(EncodingError::ReservedEncoding)
+            );
+        }
+    }
+}
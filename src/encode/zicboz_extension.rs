@@ -0,0 +1,22 @@
+pub mod bit_32 {
+    use super::super::inst_32::i_type;
+    use super::super::{reg, EncodingError};
+    use crate::instruction::{zicboz_extension::ZicbozOpcode, Instruction};
+
+    pub fn encode(inst: &Instruction, opc: &ZicbozOpcode) -> Result<u32, EncodingError> {
+        match opc {
+            ZicbozOpcode::CBO_ZERO => Ok(i_type(0b000_1111, 0, 0b010, reg(inst.rs1)?, 0b100)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_zicboz {
+    #[test]
+    fn zicboz_round_trip_test() {
+        use crate::encode::inst_32::test_round_trip_32_in_rv64;
+
+        test_round_trip_32_in_rv64(0b0000_0000_0100_1010_1010_0000_0000_1111); // cbo.zero (s5)
+        test_round_trip_32_in_rv64(0b0000_0000_0100_1000_0010_0000_0000_1111); // cbo.zero (a6)
+    }
+}
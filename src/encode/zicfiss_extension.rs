@@ -0,0 +1,85 @@
+pub mod bit_32 {
+    use super::super::inst_32::{i_type, r_type};
+    use super::super::{only_rv64, reg, unsigned, EncodingError};
+    use crate::instruction::{zicfiss_extension::ZicfissOpcode, Instruction};
+    use crate::Isa;
+
+    pub fn encode(inst: &Instruction, opc: &ZicfissOpcode, isa: Isa) -> Result<u32, EncodingError> {
+        // sspush and sspopchk only accept x1 (ra) or x5 (t0).
+        let ra_or_t0 = |field| match reg(field)? {
+            rs @ (1 | 5) => Ok(rs),
+            _ => Err(EncodingError::InvalidRegister),
+        };
+        let aq_and_rl = || unsigned(inst.imm.unwrap_or(0), 2, 1);
+        let ssamoswap = |funct3| {
+            Ok(r_type(
+                0b010_1111,
+                reg(inst.rd)?,
+                funct3,
+                reg(inst.rs1)?,
+                reg(inst.rs2)?,
+                0b01001 << 2 | aq_and_rl()?,
+            ))
+        };
+
+        match opc {
+            ZicfissOpcode::SSPUSH => Ok(r_type(
+                0b111_0011,
+                0,
+                0b100,
+                0,
+                ra_or_t0(inst.rs2)?,
+                0b110_0111,
+            )),
+            ZicfissOpcode::SSPOPCHK => Ok(i_type(
+                0b111_0011,
+                0,
+                0b100,
+                ra_or_t0(inst.rs1)?,
+                0b1100_1101_1100,
+            )),
+            ZicfissOpcode::SSRDP => match reg(inst.rd)? {
+                0 => Err(EncodingError::InvalidRegister),
+                rd => Ok(i_type(0b111_0011, rd, 0b100, 0, 0b1100_1101_1100)),
+            },
+            ZicfissOpcode::SSAMOSWAP_W => ssamoswap(0b010),
+            ZicfissOpcode::SSAMOSWAP_D => {
+                only_rv64(isa)?;
+                ssamoswap(0b011)
+            }
+            ZicfissOpcode::C_SSPUSH | ZicfissOpcode::C_SSPOPCHK => Err(EncodingError::Not32BitInst),
+        }
+    }
+}
+
+pub mod bit_16 {
+    use super::super::EncodingError;
+    use crate::instruction::zicfiss_extension::ZicfissOpcode;
+
+    pub fn encode(opc: &ZicfissOpcode) -> Result<u16, EncodingError> {
+        match opc {
+            ZicfissOpcode::C_SSPUSH => Ok(0b0110_0000_1000_0001),
+            ZicfissOpcode::C_SSPOPCHK => Ok(0b0110_0010_1000_0001),
+            _ => Err(EncodingError::Not16BitInst),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_zicfiss {
+    #[test]
+    fn zicfiss_round_trip_test() {
+        use crate::encode::inst_16::test_round_trip_16_in_rv64;
+        use crate::encode::inst_32::test_round_trip_32_in_rv64;
+
+        test_round_trip_32_in_rv64(0b1100_1110_0101_0000_0100_0000_0111_0011); // sspush t0
+        test_round_trip_32_in_rv64(0b1100_1110_0001_0000_0100_0000_0111_0011); // sspush ra
+        test_round_trip_32_in_rv64(0b1100_1101_1100_0000_1100_0000_0111_0011); // sspopchk ra
+        test_round_trip_32_in_rv64(0b1100_1101_1100_0010_1100_0000_0111_0011); // sspopchk t0
+        test_round_trip_32_in_rv64(0xcdc0_4573); // ssrdp a0
+        test_round_trip_32_in_rv64(0b0100_1000_1100_0010_1010_0001_1010_1111); // ssamoswap.w
+        test_round_trip_32_in_rv64(0b0100_1000_1100_0111_0011_0001_1010_1111); // ssamoswap.d
+        test_round_trip_16_in_rv64(0x6081); // c.sspush ra
+        test_round_trip_16_in_rv64(0x6281); // c.sspopchk t0
+    }
+}
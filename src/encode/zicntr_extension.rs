@@ -0,0 +1,30 @@
+pub mod bit_32 {
+    use super::super::inst_32::i_type;
+    use super::super::{reg, EncodingError};
+    use crate::instruction::{zicntr_extension::ZicntrOpcode, Instruction};
+
+    pub fn encode(inst: &Instruction, opc: &ZicntrOpcode) -> Result<u32, EncodingError> {
+        // csrrs rd, csr, zero
+        let read_counter = |csr| Ok(i_type(0b111_0011, reg(inst.rd)?, 0b010, 0, csr));
+
+        match opc {
+            ZicntrOpcode::RDCYCLE => read_counter(0xc00),
+            ZicntrOpcode::RDTIME => read_counter(0xc01),
+            ZicntrOpcode::RDINSTRET => read_counter(0xc02),
+            ZicntrOpcode::RDCYCLE_H => read_counter(0xc80),
+            ZicntrOpcode::RDTIME_H => read_counter(0xc81),
+            ZicntrOpcode::RDINSTRET_H => read_counter(0xc82),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_zicntr {
+    #[test]
+    fn zicntr_round_trip_test() {
+        use crate::encode::inst_32::test_round_trip_32_in_rv64;
+
+        test_round_trip_32_in_rv64(0b1100_0000_0001_0000_0010_0111_1111_0011); // rdtime a5
+        test_round_trip_32_in_rv64(0xc800_2573); // rdcycleh a0
+    }
+}
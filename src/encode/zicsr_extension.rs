@@ -0,0 +1,49 @@
+pub mod bit_32 {
+    use super::super::inst_32::i_type;
+    use super::super::{imm, reg, unsigned, EncodingError};
+    use crate::instruction::{zicsr_extension::ZicsrOpcode, Instruction};
+
+    pub fn encode(inst: &Instruction, opc: &ZicsrOpcode) -> Result<u32, EncodingError> {
+        // csr number is stored in rs2.
+        let csr = || match inst.rs2 {
+            Some(csr @ 0..=0xfff) => Ok(u32::try_from(csr).unwrap()),
+            Some(_) => Err(EncodingError::ImmediateOutOfRange),
+            None => Err(EncodingError::MissingOperand),
+        };
+        let csr_reg = |funct3| {
+            Ok(i_type(
+                0b111_0011,
+                reg(inst.rd)?,
+                funct3,
+                reg(inst.rs1)?,
+                csr()?,
+            ))
+        };
+        let csr_uimm = |funct3| {
+            let uimm = unsigned(imm(inst.imm)?, 5, 1)?;
+            Ok(i_type(0b111_0011, reg(inst.rd)?, funct3, uimm, csr()?))
+        };
+
+        match opc {
+            ZicsrOpcode::CSRRW => csr_reg(0b001),
+            ZicsrOpcode::CSRRS => csr_reg(0b010),
+            ZicsrOpcode::CSRRC => csr_reg(0b011),
+            ZicsrOpcode::CSRRWI => csr_uimm(0b101),
+            ZicsrOpcode::CSRRSI => csr_uimm(0b110),
+            ZicsrOpcode::CSRRCI => csr_uimm(0b111),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_zicsr {
+    #[test]
+    fn zicsr_round_trip_test() {
+        use crate::encode::inst_32::test_round_trip_32_in_rv64;
+
+        test_round_trip_32_in_rv64(0b0001_0000_0000_1000_0011_0000_0111_0011); // csrrc zero, sstatus, a6
+        test_round_trip_32_in_rv64(0b0001_0000_0000_1000_0010_0000_0111_0011); // csrrs zero, sstatus, a6
+        test_round_trip_32_in_rv64(0b0001_0000_0000_0001_0110_0000_0111_0011); // csrrsi zero, sstatus, 2
+        test_round_trip_32_in_rv64(0x3405_9573); // csrrw a0, mscratch, a1
+    }
+}
@@ -0,0 +1,30 @@
+pub mod bit_32 {
+    use super::super::inst_32::i_type;
+    use super::super::{imm, reg, unsigned, EncodingError};
+    use crate::instruction::{zifencei_extension::ZifenceiOpcode, Instruction};
+
+    pub fn encode(inst: &Instruction, opc: &ZifenceiOpcode) -> Result<u32, EncodingError> {
+        // imm is reserved for future use.
+        let imm12 = || unsigned(imm(inst.imm)?, 12, 1);
+
+        match opc {
+            ZifenceiOpcode::FENCE_I => Ok(i_type(
+                0b000_1111,
+                reg(inst.rd)?,
+                0b001,
+                reg(inst.rs1)?,
+                imm12()?,
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_zifencei {
+    #[test]
+    fn zifencei_round_trip_test() {
+        use crate::encode::inst_32::test_round_trip_32_in_rv64;
+
+        test_round_trip_32_in_rv64(0x0000_100f); // fence.i
+    }
+}
@@ -1,27 +1,57 @@
 //! Define instructions data structure.
 
 pub mod a_extension;
+pub(crate) mod alias;
+pub mod b_extension;
 pub mod base_i;
 pub mod c_extension;
+mod compress;
+mod control_flow;
+pub mod d_extension;
+mod def_use;
+pub mod f_extension;
 pub mod m_extension;
+mod memory;
+pub(crate) mod objdump;
+mod operand;
+mod options;
 pub mod priv_extension;
+pub mod q_extension;
+mod register;
+pub mod v_extension;
 pub mod zicboz_extension;
 pub mod zicfiss_extension;
 pub mod zicntr_extension;
 pub mod zicsr_extension;
 pub mod zifencei_extension;
 
-use core::fmt::{self, Display, Formatter};
+use core::fmt::{self, Display, Formatter, Write};
+
+use crate::Extensions;
+
+pub use alias::Aliased;
+pub use control_flow::ControlFlow;
+pub use def_use::RegisterSet;
+pub use memory::{AccessKind, AccessWidth, MemoryAccess};
+pub use objdump::Objdump;
+pub use operand::{Operand, Operands};
+pub use options::{DisplayWith, FormatOptions, Radix};
+pub use register::{FReg, Register, VReg, XReg};
 
 use a_extension::AOpcode;
-use base_i::BaseIOpcode;
+use b_extension::BOpcode;
+use base_i::{BaseIOpcode, Fence};
 use c_extension::COpcode;
+use d_extension::DOpcode;
+use f_extension::{FOpcode, RoundingMode};
 use m_extension::MOpcode;
 use priv_extension::PrivOpcode;
+use q_extension::QOpcode;
+use v_extension::{VOpcode, Vtype};
 use zicboz_extension::ZicbozOpcode;
 use zicfiss_extension::ZicfissOpcode;
 use zicntr_extension::ZicntrOpcode;
-use zicsr_extension::ZicsrOpcode;
+use zicsr_extension::{Csr, ZicsrOpcode};
 use zifencei_extension::ZifenceiOpcode;
 
 /// Instruction
@@ -35,231 +65,298 @@ pub struct Instruction {
     pub rs1: Option<usize>,
     /// Register Source 2
     pub rs2: Option<usize>,
+    /// Register Source 3
+    pub rs3: Option<usize>,
     /// Immediate
     pub imm: Option<i32>,
+    /// Vector mask bit (`true` means unmasked)
+    pub vm: Option<bool>,
+    /// Rounding mode of floating-point instructions
+    pub rm: Option<RoundingMode>,
     /// Instruction format
     pub inst_format: InstFormat,
     /// Is compressed instruction?
     pub is_compressed: bool,
 }
 
+/// Format an instruction in assembly syntax.
+///
+/// The alternate flag (`{:#}`) prints raw register names instead of ABI names.
+/// Use `Instruction::display_with` for other styles.
+/// ```
+/// use raki::{Decode, Isa};
+///
+/// let inst = 0x00b5_0533_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(format!("{inst}"), "add a0, a0, a1");
+/// assert_eq!(format!("{inst:#}"), "add x10, x10, x11");
+/// ```
 impl Display for Instruction {
-    #[allow(clippy::too_many_lines)]
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let opts = FormatOptions::new().with_numeric_names(f.alternate());
+        self.fmt_with(f, &opts)
+    }
+}
+
+/// Mnemonic printed by `Instruction::fmt_with`.
+struct Mnemonic<'a> {
+    opc: &'a OpcodeKind,
+    lowercase: bool,
+}
+
+impl Display for Mnemonic<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.lowercase {
+            write!(objdump::Compact(f), "{}", self.opc)
+        } else {
+            write!(f, "{}", self.opc)
+        }
+    }
+}
+
+impl Instruction {
+    /// Print the instruction in assembly syntax with `opts`.
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn fmt_with(&self, f: &mut Formatter, opts: &FormatOptions) -> fmt::Result {
+        let x = |reg| opts.xreg(reg);
+        let fr = |reg| opts.freg(reg);
+        let v = |reg| opts.vreg(reg);
+        let sep = opts.separator();
+        let (rd, rs1, rs2, rs3) = (
+            || self.rd.unwrap(),
+            || self.rs1.unwrap(),
+            || self.rs2.unwrap(),
+            || self.rs3.unwrap(),
+        );
+        let imm = || opts.imm(i64::from(self.imm.unwrap()), Radix::Decimal);
+        let opc = Mnemonic {
+            opc: &self.opc,
+            // pseudo-instructions are lowercase, so `C.addi` follows them.
+            lowercase: opts.aliases(),
+        };
+
         match self.inst_format {
             InstFormat::RFormat | InstFormat::MFormat => {
-                write!(
-                    f,
-                    "{} {}, {}, {}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    reg2str(self.rs1.unwrap()),
-                    reg2str(self.rs2.unwrap())
-                )
+                write!(f, "{opc} {}{sep}{}{sep}{}", x(rd()), x(rs1()), x(rs2()))
             }
-            InstFormat::AFormat => match self.opc {
-                OpcodeKind::A(AOpcode::LR_W | AOpcode::LR_D) => write!(
-                    f,
-                    "{} {}, {}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    reg2str(self.rs1.unwrap()),
-                ),
-                _ => write!(
-                    f,
-                    "{} {}, {}, {}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    reg2str(self.rs1.unwrap()),
-                    reg2str(self.rs2.unwrap())
-                ),
+            InstFormat::AFormat | InstFormat::ALrFormat => match self.opc {
+                OpcodeKind::A(AOpcode::LR_W | AOpcode::LR_D) => {
+                    write!(f, "{opc} {}{sep}{}", x(rd()), x(rs1()))
+                }
+                _ => write!(f, "{opc} {}{sep}{}{sep}{}", x(rd()), x(rs1()), x(rs2())),
             },
-            InstFormat::RShamtFormat => {
-                write!(
-                    f,
-                    "{} {}, {}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    reg2str(self.rs1.unwrap()),
-                )
+            InstFormat::RUnaryFormat => write!(f, "{opc} {}{sep}{}", x(rd()), x(rs1())),
+            InstFormat::RShamtFormat | InstFormat::ClFormat | InstFormat::IFormat => {
+                write!(f, "{opc} {}{sep}{}{sep}{}", x(rd()), x(rs1()), imm())
             }
-            InstFormat::ClFormat | InstFormat::ALrFormat | InstFormat::IFormat => write!(
-                f,
-                "{} {}, {}, {}",
-                self.opc,
-                reg2str(self.rd.unwrap()),
-                reg2str(self.rs1.unwrap()),
-                self.imm.unwrap()
-            ),
-            InstFormat::CsFormat | InstFormat::SFormat | InstFormat::BFormat => write!(
-                f,
-                "{} {}, {}({})",
-                self.opc,
-                reg2str(self.rs1.unwrap()),
-                self.imm.unwrap(),
-                reg2str(self.rs2.unwrap()),
-            ),
-            InstFormat::CiwFormat => {
-                write!(
-                    f,
-                    "{} {}, sp, {:x}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    self.imm.unwrap()
-                )
+            InstFormat::CsFormat | InstFormat::SFormat => {
+                write!(f, "{opc} {}{sep}{}({})", x(rs2()), imm(), x(rs1()))
             }
-            InstFormat::CssFormat => {
-                write!(
-                    f,
-                    "{} {}, {}(sp)",
-                    self.opc,
-                    reg2str(self.rs2.unwrap()),
-                    self.imm.unwrap()
-                )
+            InstFormat::BFormat => {
+                write!(f, "{opc} {}{sep}{}{sep}{}", x(rs1()), x(rs2()), imm())
             }
-            InstFormat::UFormat | InstFormat::JFormat => {
-                write!(
-                    f,
-                    "{} {}, {:#x}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    self.imm.unwrap()
-                )
+            InstFormat::CiwFormat => write!(f, "{opc} {}{sep}{}{sep}{}", x(rd()), x(2), imm()),
+            InstFormat::CssFormat => write!(f, "{opc} {}{sep}{}({})", x(rs2()), imm(), x(2)),
+            InstFormat::UFormat => {
+                // upper immediates are printed as the bit pattern.
+                let bits = opts.imm(i64::from(self.imm.unwrap()) & 0xffff_ffff, Radix::Hex);
+                write!(f, "{opc} {}{sep}{bits}", x(rd()))
             }
-            InstFormat::CjFormat => {
-                write!(f, "{} {}", self.opc, self.imm.unwrap())
+            InstFormat::JFormat => {
+                let offset = opts.imm(i64::from(self.imm.unwrap()), Radix::Hex);
+                write!(f, "{opc} {}{sep}{offset}", x(rd()))
             }
+            InstFormat::CjFormat => write!(f, "{opc} {}", imm()),
             InstFormat::CiFormat => {
-                write!(
-                    f,
-                    "{} {}, {}, {}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    reg2str(self.rd.unwrap()),
-                    self.imm.unwrap()
-                )
+                write!(f, "{opc} {}{sep}{}{sep}{}", x(rd()), x(rd()), imm())
             }
             InstFormat::CrFormat => match self.opc {
-                OpcodeKind::C(COpcode::JR) => {
-                    write!(f, "{} zero, 0({})", self.opc, reg2str(self.rs1.unwrap()),)
+                OpcodeKind::C(COpcode::JR) => write!(f, "{opc} {}{sep}0({})", x(0), x(rs1())),
+                OpcodeKind::C(COpcode::JALR) => write!(f, "{opc} {}{sep}0({})", x(1), x(rs1())),
+                OpcodeKind::C(COpcode::MV) => write!(f, "{opc} {}{sep}{}", x(rd()), x(rs2())),
+                OpcodeKind::C(COpcode::ADD) => {
+                    write!(f, "{opc} {}{sep}{}{sep}{}", x(rd()), x(rd()), x(rs2()))
                 }
-                OpcodeKind::C(COpcode::JALR) => {
-                    write!(f, "{} ra, 0({})", self.opc, reg2str(self.rs1.unwrap()),)
-                }
-                OpcodeKind::C(COpcode::MV) => write!(
-                    f,
-                    "{} {}, {}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    reg2str(self.rs2.unwrap())
-                ),
-                OpcodeKind::C(COpcode::ADD) => write!(
-                    f,
-                    "{} {}, {}, {}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    reg2str(self.rd.unwrap()),
-                    reg2str(self.rs2.unwrap())
-                ),
                 _ => unreachable!(),
             },
             InstFormat::CaFormat => {
-                write!(
-                    f,
-                    "{} {}, {}, {}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    reg2str(self.rd.unwrap()),
-                    reg2str(self.rs2.unwrap())
-                )
-            }
-            InstFormat::CbFormat => {
-                write!(
-                    f,
-                    "{} {}, {}",
-                    self.opc,
-                    self.rs1.unwrap(),
-                    self.imm.unwrap(),
-                )
+                write!(f, "{opc} {}{sep}{}{sep}{}", x(rd()), x(rd()), x(rs2()))
             }
+            InstFormat::CbFormat => match self.opc {
+                OpcodeKind::C(COpcode::BEQZ | COpcode::BNEZ) => {
+                    write!(f, "{opc} {}{sep}{}", x(rs1()), imm())
+                }
+                _ => write!(f, "{opc} {}{sep}{}", x(rd()), imm()),
+            },
             InstFormat::CsrFormat => {
-                write!(
-                    f,
-                    "{} {}, {:#x}, {}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    self.rs2.unwrap(),
-                    reg2str(self.rs1.unwrap()),
-                )
+                let csr = Csr::from_field(rs2());
+                write!(f, "{opc} {}{sep}{csr}{sep}{}", x(rd()), x(rs1()))
             }
             InstFormat::CsrUiFormat => {
+                let csr = Csr::from_field(rs2());
+                write!(f, "{opc} {}{sep}{csr}{sep}{}", x(rd()), imm())
+            }
+            InstFormat::OnlyRd => match self.opc {
+                // counters are read by the csr instruction (`rdcycle` etc. are aliases).
+                OpcodeKind::Zicntr(_) => {
+                    let csr = self.csr().unwrap();
+                    write!(f, "csrrs {}{sep}{csr}{sep}{}", x(rd()), x(0))
+                }
+                _ => write!(f, "{opc} {}", x(rd())),
+            },
+            InstFormat::OnlyRs1 => write!(f, "{opc} {}", x(rs1())),
+            InstFormat::OnlyRs2 => write!(f, "{opc} {}", x(rs2())),
+            InstFormat::FlFormat | InstFormat::CflFormat => {
+                write!(f, "{opc} {}{sep}{}({})", fr(rd()), imm(), x(rs1()))
+            }
+            InstFormat::FsFormat | InstFormat::CfsFormat => {
+                write!(f, "{opc} {}{sep}{}({})", fr(rs2()), imm(), x(rs1()))
+            }
+            InstFormat::CflspFormat => write!(f, "{opc} {}{sep}{}({})", fr(rd()), imm(), x(2)),
+            InstFormat::CfsspFormat => write!(f, "{opc} {}{sep}{}({})", fr(rs2()), imm(), x(2)),
+            InstFormat::R4Format => {
                 write!(
                     f,
-                    "{} {}, {}, {}",
-                    self.opc,
-                    reg2str(self.rd.unwrap()),
-                    self.rs2.unwrap(),
-                    self.imm.unwrap(),
-                )
+                    "{opc} {}{sep}{}{sep}{}{sep}{}",
+                    fr(rd()),
+                    fr(rs1()),
+                    fr(rs2()),
+                    fr(rs3())
+                )?;
+                self.fmt_rounding_mode(f, sep)
             }
-            InstFormat::OnlyRd => {
-                write!(f, "{} {}", self.opc, reg2str(self.rd.unwrap()),)
+            InstFormat::FrFormat => {
+                write!(f, "{opc} {}{sep}{}{sep}{}", fr(rd()), fr(rs1()), fr(rs2()))?;
+                self.fmt_rounding_mode(f, sep)
             }
-            InstFormat::OnlyRs1 => {
-                write!(f, "{} {}", self.opc, reg2str(self.rs1.unwrap()),)
+            InstFormat::FrUnaryFormat => {
+                write!(f, "{opc} {}{sep}{}", fr(rd()), fr(rs1()))?;
+                self.fmt_rounding_mode(f, sep)
             }
-            InstFormat::OnlyRs2 => {
-                write!(f, "{} {}", self.opc, reg2str(self.rs2.unwrap()),)
+            InstFormat::FrCmpFormat => {
+                write!(f, "{opc} {}{sep}{}{sep}{}", x(rd()), fr(rs1()), fr(rs2()))
             }
-            InstFormat::NoOperand => match self.opc {
-                OpcodeKind::BaseI(BaseIOpcode::ECALL | BaseIOpcode::EBREAK)
-                | OpcodeKind::Zifencei(ZifenceiOpcode::FENCE)
-                | OpcodeKind::C(COpcode::NOP | COpcode::EBREAK)
-                | OpcodeKind::Priv(
-                    PrivOpcode::MRET | PrivOpcode::SRET | PrivOpcode::WFI | PrivOpcode::SFENCE_VMA,
-                ) => write!(f, "{}", self.opc),
-                _ => unreachable!(),
-            },
+            InstFormat::FrToXFormat => {
+                write!(f, "{opc} {}{sep}{}", x(rd()), fr(rs1()))?;
+                self.fmt_rounding_mode(f, sep)
+            }
+            InstFormat::XToFrFormat => {
+                write!(f, "{opc} {}{sep}{}", fr(rd()), x(rs1()))?;
+                self.fmt_rounding_mode(f, sep)
+            }
+            InstFormat::VsetvliFormat => {
+                write!(f, "{opc} {}{sep}{}{sep}", x(rd()), x(rs1()))?;
+                self.fmt_vtype(f, sep)
+            }
+            InstFormat::VsetivliFormat => {
+                let uimm = opts.imm(i64::try_from(rs1()).unwrap(), Radix::Decimal);
+                write!(f, "{opc} {}{sep}{uimm}{sep}", x(rd()))?;
+                self.fmt_vtype(f, sep)
+            }
+            InstFormat::VlFormat => write!(f, "{opc} {}{sep}({})", v(rd()), x(rs1())),
+            InstFormat::VlsFormat => {
+                write!(f, "{opc} {}{sep}({}){sep}{}", v(rd()), x(rs1()), x(rs2()))
+            }
+            InstFormat::VlxFormat => {
+                write!(f, "{opc} {}{sep}({}){sep}{}", v(rd()), x(rs1()), v(rs2()))
+            }
+            InstFormat::VsFormat => write!(f, "{opc} {}{sep}({})", v(rs3()), x(rs1())),
+            InstFormat::VssFormat => {
+                write!(f, "{opc} {}{sep}({}){sep}{}", v(rs3()), x(rs1()), x(rs2()))
+            }
+            InstFormat::VsxFormat => {
+                write!(f, "{opc} {}{sep}({}){sep}{}", v(rs3()), x(rs1()), v(rs2()))
+            }
+            InstFormat::VvFormat
+            | InstFormat::VxFormat
+            | InstFormat::ViFormat
+            | InstFormat::VfFormat
+            | InstFormat::VUnaryFormat => {
+                write!(f, "{opc} {}", v(rd()))?;
+                if let Some(vs2) = self.rs2 {
+                    write!(f, "{sep}{}", v(vs2))?;
+                }
+                match self.inst_format {
+                    InstFormat::VvFormat => write!(f, "{sep}{}", v(rs1())),
+                    InstFormat::VxFormat => write!(f, "{sep}{}", x(rs1())),
+                    InstFormat::ViFormat => write!(f, "{sep}{}", imm()),
+                    InstFormat::VfFormat => write!(f, "{sep}{}", fr(rs1())),
+                    _ => Ok(()),
+                }
+            }
+            InstFormat::VmaVvFormat => {
+                write!(f, "{opc} {}{sep}{}{sep}{}", v(rd()), v(rs1()), v(rs2()))
+            }
+            InstFormat::VmaVxFormat => {
+                write!(f, "{opc} {}{sep}{}{sep}{}", v(rd()), x(rs1()), v(rs2()))
+            }
+            InstFormat::VmaVfFormat => {
+                write!(f, "{opc} {}{sep}{}{sep}{}", v(rd()), fr(rs1()), v(rs2()))
+            }
+            InstFormat::VToXFormat => write!(f, "{opc} {}{sep}{}", x(rd()), v(rs2())),
+            InstFormat::VToFFormat => write!(f, "{opc} {}{sep}{}", fr(rd()), v(rs2())),
+            InstFormat::HfenceFormat => write!(f, "{opc} {}{sep}{}", x(rs1()), x(rs2())),
+            InstFormat::HlvFormat => write!(f, "{opc} {}{sep}({})", x(rd()), x(rs1())),
+            InstFormat::HsvFormat => write!(f, "{opc} {}{sep}({})", x(rs2()), x(rs1())),
+            InstFormat::FenceFormat => {
+                let fence = Fence::from_imm(self.imm.unwrap());
+                write!(f, "{opc} {}{sep}{}", fence.pred, fence.succ)
+            }
+            InstFormat::NoOperand => write!(f, "{opc}"),
+        }?;
+
+        self.fmt_vector_mask(f, sep)
+    }
+
+    /// Print the rounding mode if it is not the dynamic rounding mode.
+    fn fmt_rounding_mode(&self, f: &mut Formatter, sep: &str) -> fmt::Result {
+        match self.rm {
+            Some(RoundingMode::DYN) | None => Ok(()),
+            Some(rm) => write!(f, "{sep}{rm}"),
         }
     }
-}
 
-/// Convert register number to string.
-fn reg2str(rd_value: usize) -> &'static str {
-    match rd_value {
-        0 => "zero",
-        1 => "ra",
-        2 => "sp",
-        3 => "gp",
-        4 => "tp",
-        5 => "t0",
-        6 => "t1",
-        7 => "t2",
-        8 => "s0", // fp
-        9 => "s1",
-        10 => "a0",
-        11 => "a1",
-        12 => "a2",
-        13 => "a3",
-        14 => "a4",
-        15 => "a5",
-        16 => "a6",
-        17 => "a7",
-        18 => "s2",
-        19 => "s3",
-        20 => "s4",
-        21 => "s5",
-        22 => "s6",
-        23 => "s7",
-        24 => "s8",
-        25 => "s9",
-        26 => "s10",
-        27 => "s11",
-        28 => "t3",
-        29 => "t4",
-        30 => "t5",
-        31 => "t6",
-        _ => panic!("unknown register"),
+    /// Print the vtype stored in imm, or the raw value if it is reserved.
+    fn fmt_vtype(&self, f: &mut Formatter, sep: &str) -> fmt::Result {
+        let zimm = self.imm.unwrap();
+        match Vtype::from_imm(zimm) {
+            Some(vtype) => vtype.fmt_separated(f, sep),
+            None => write!(f, "{zimm}"),
+        }
+    }
+
+    /// Print the mask operand if the instruction is masked.
+    fn fmt_vector_mask(&self, f: &mut Formatter, sep: &str) -> fmt::Result {
+        match self.vector_mask() {
+            Some(mask) => write!(f, "{sep}{mask}"),
+            None => Ok(()),
+        }
+    }
+
+    /// Mask operand of the instruction (`v0.t`, or `v0` for the carry/merge instructions).
+    pub(crate) fn vector_mask(&self) -> Option<&'static str> {
+        match (self.vm, &self.opc) {
+            (
+                Some(false),
+                OpcodeKind::V(
+                    VOpcode::VADC_VVM
+                    | VOpcode::VADC_VXM
+                    | VOpcode::VADC_VIM
+                    | VOpcode::VMADC_VVM
+                    | VOpcode::VMADC_VXM
+                    | VOpcode::VMADC_VIM
+                    | VOpcode::VSBC_VVM
+                    | VOpcode::VSBC_VXM
+                    | VOpcode::VMSBC_VVM
+                    | VOpcode::VMSBC_VXM
+                    | VOpcode::VMERGE_VVM
+                    | VOpcode::VMERGE_VXM
+                    | VOpcode::VMERGE_VIM
+                    | VOpcode::VFMERGE_VFM,
+                ),
+            ) => Some("v0"),
+            (Some(false), _) => Some("v0.t"),
+            _ => None,
+        }
     }
 }
 
@@ -279,6 +376,12 @@ pub enum InstFormat {
     /// ```
     RShamtFormat,
 
+    /// Regular format with a single source
+    /// ```ignore
+    /// clz rd, rs1
+    /// ```
+    RUnaryFormat,
+
     /// Immediate format
     /// ```ignore
     /// lw rd, imm(rs1)
@@ -368,6 +471,32 @@ pub enum InstFormat {
     /// ```
     CjFormat,
 
+    /// Compressed Floating-point Load format
+    /// ```ignore
+    /// c.fld rd, imm(rs1)
+    /// ```
+    CflFormat,
+
+    /// Compressed Floating-point Store format
+    /// ```ignore
+    /// c.fsd rs2, imm(rs1)
+    /// ```
+    CfsFormat,
+
+    /// Compressed Floating-point Stack-relative Load format
+    /// ```ignore
+    /// c.fldsp rd, imm
+    /// -> fld rd, imm[8:3](x2)
+    /// ```
+    CflspFormat,
+
+    /// Compressed Floating-point Stack-relative Store format
+    /// ```ignore
+    /// c.fsdsp rs2, imm
+    /// -> fsd rs2, imm[8:3](x2)
+    /// ```
+    CfsspFormat,
+
     /// Compressed Csr format
     /// ```ignore
     /// csrrw rd, csr, rs1
@@ -399,9 +528,193 @@ pub enum InstFormat {
     /// ```
     ALrFormat,
 
+    /// Floating-point load format
+    /// ```ignore
+    /// flw rd, imm(rs1)
+    /// ```
+    FlFormat,
+
+    /// Floating-point store format
+    /// ```ignore
+    /// fsw rs2, imm(rs1)
+    /// ```
+    FsFormat,
+
+    /// Fused multiply-add format
+    /// ```ignore
+    /// fmadd.s rd, rs1, rs2, rs3
+    /// ```
+    R4Format,
+
+    /// Floating-point register format
+    /// ```ignore
+    /// fadd.s rd, rs1, rs2
+    /// ```
+    FrFormat,
+
+    /// Floating-point register format with a single source
+    /// ```ignore
+    /// fsqrt.s rd, rs1
+    /// ```
+    FrUnaryFormat,
+
+    /// Floating-point compare format (rd is an integer register)
+    /// ```ignore
+    /// feq.s rd, rs1, rs2
+    /// ```
+    FrCmpFormat,
+
+    /// Floating-point to integer register format
+    /// ```ignore
+    /// fcvt.w.s rd, rs1
+    /// ```
+    FrToXFormat,
+
+    /// Integer to floating-point register format
+    /// ```ignore
+    /// fcvt.s.w rd, rs1
+    /// ```
+    XToFrFormat,
+
+    /// Vector configuration-setting format
+    /// ```ignore
+    /// vsetvli rd, rs1, vtypei
+    /// ```
+    VsetvliFormat,
+
+    /// Vector configuration-setting format with an immediate AVL
+    /// ```ignore
+    /// vsetivli rd, uimm, vtypei
+    /// ```
+    VsetivliFormat,
+
+    /// Vector unit-stride load format
+    /// ```ignore
+    /// vle32.v vd, (rs1), vm
+    /// ```
+    VlFormat,
+
+    /// Vector strided load format
+    /// ```ignore
+    /// vlse32.v vd, (rs1), rs2, vm
+    /// ```
+    VlsFormat,
+
+    /// Vector indexed load format
+    /// ```ignore
+    /// vluxei32.v vd, (rs1), vs2, vm
+    /// ```
+    VlxFormat,
+
+    /// Vector unit-stride store format
+    /// ```ignore
+    /// vse32.v vs3, (rs1), vm
+    /// ```
+    VsFormat,
+
+    /// Vector strided store format
+    /// ```ignore
+    /// vsse32.v vs3, (rs1), rs2, vm
+    /// ```
+    VssFormat,
+
+    /// Vector indexed store format
+    /// ```ignore
+    /// vsuxei32.v vs3, (rs1), vs2, vm
+    /// ```
+    VsxFormat,
+
+    /// Vector-vector format
+    /// ```ignore
+    /// vadd.vv vd, vs2, vs1, vm
+    /// ```
+    VvFormat,
+
+    /// Vector-scalar format
+    /// ```ignore
+    /// vadd.vx vd, vs2, rs1, vm
+    /// ```
+    VxFormat,
+
+    /// Vector-immediate format
+    /// ```ignore
+    /// vadd.vi vd, vs2, imm, vm
+    /// ```
+    ViFormat,
+
+    /// Vector-floating-point scalar format
+    /// ```ignore
+    /// vfadd.vf vd, vs2, rs1, vm
+    /// ```
+    VfFormat,
+
+    /// Vector multiply-add format (vector-vector)
+    /// ```ignore
+    /// vmacc.vv vd, vs1, vs2, vm
+    /// ```
+    VmaVvFormat,
+
+    /// Vector multiply-add format (vector-scalar)
+    /// ```ignore
+    /// vmacc.vx vd, rs1, vs2, vm
+    /// ```
+    VmaVxFormat,
+
+    /// Vector multiply-add format (vector-floating-point scalar)
+    /// ```ignore
+    /// vfmacc.vf vd, rs1, vs2, vm
+    /// ```
+    VmaVfFormat,
+
+    /// Vector format with a single source
+    /// ```ignore
+    /// vfsqrt.v vd, vs2, vm
+    /// vid.v vd, vm
+    /// ```
+    VUnaryFormat,
+
+    /// Vector to integer register format
+    /// ```ignore
+    /// vmv.x.s rd, vs2
+    /// ```
+    VToXFormat,
+
+    /// Vector to floating-point register format
+    /// ```ignore
+    /// vfmv.f.s rd, vs2
+    /// ```
+    VToFFormat,
+
+    /// Hypervisor fence format
+    /// ```ignore
+    /// hfence.gvma rs1, rs2
+    /// sinval.vma rs1, rs2
+    /// ```
+    HfenceFormat,
+
+    /// Hypervisor virtual-machine load format
+    /// ```ignore
+    /// hlv.w rd, (rs1)
+    /// ```
+    HlvFormat,
+
+    /// Hypervisor virtual-machine store format
+    /// ```ignore
+    /// hsv.w rs2, (rs1)
+    /// ```
+    HsvFormat,
+
+    /// Fence format
+    /// ```ignore
+    /// fence iorw, iorw
+    /// fence rw, w
+    /// ```
+    FenceFormat,
+
     /// No operand
     /// ```ignore
     /// ecall
+    /// fence.tso
     /// wfi
     /// mret
     /// c.ebreak
@@ -444,8 +757,18 @@ pub enum OpcodeKind {
     M(MOpcode),
     /// Atomic Instructions
     A(AOpcode),
+    /// Single-Precision Floating-Point
+    F(FOpcode),
+    /// Double-Precision Floating-Point
+    D(DOpcode),
+    /// Quad-Precision Floating-Point
+    Q(QOpcode),
     /// Compressed Instructions
     C(COpcode),
+    /// Bit-Manipulation
+    B(BOpcode),
+    /// Vector Operations
+    V(VOpcode),
     /// Instruction-Fetch Fence,
     Zifencei(ZifenceiOpcode),
     /// Cache-Block Zero Instructions
@@ -466,7 +789,12 @@ impl Display for OpcodeKind {
             Self::BaseI(opc) => write!(f, "{opc}"),
             Self::M(opc) => write!(f, "{opc}"),
             Self::A(opc) => write!(f, "{opc}"),
+            Self::F(opc) => write!(f, "{opc}"),
+            Self::D(opc) => write!(f, "{opc}"),
+            Self::Q(opc) => write!(f, "{opc}"),
             Self::C(opc) => write!(f, "{opc}"),
+            Self::B(opc) => write!(f, "{opc}"),
+            Self::V(opc) => write!(f, "{opc}"),
             Self::Zifencei(opc) => write!(f, "{opc}"),
             Self::Zicboz(opc) => write!(f, "{opc}"),
             Self::Zicsr(opc) => write!(f, "{opc}"),
@@ -478,13 +806,77 @@ impl Display for OpcodeKind {
 }
 
 impl OpcodeKind {
+    /// Get the extension that the instruction belongs to.
+    ///
+    /// Bit-manipulation instructions belong to their sub-extension (e.g. `sh1add` to Zba).
+    #[must_use]
+    pub fn get_extension(&self) -> Extensions {
+        match &self {
+            Self::BaseI(_) => Extensions::BaseI,
+            Self::M(_) => Extensions::M,
+            Self::A(_) => Extensions::A,
+            Self::F(_) => Extensions::F,
+            Self::D(_) => Extensions::D,
+            Self::Q(_) => Extensions::Q,
+            Self::C(_) => Extensions::C,
+            Self::B(opc) => opc.get_sub_extension(),
+            Self::V(_) => Extensions::V,
+            Self::Zifencei(_) => Extensions::Zifencei,
+            Self::Zicboz(_) => Extensions::Zicboz,
+            Self::Zicsr(_) => Extensions::Zicsr,
+            Self::Zicfiss(_) => Extensions::Zicfiss,
+            Self::Zicntr(_) => Extensions::Zicntr,
+            Self::Priv(
+                PrivOpcode::HFENCE_VVMA
+                | PrivOpcode::HFENCE_GVMA
+                | PrivOpcode::HLV_B
+                | PrivOpcode::HLV_BU
+                | PrivOpcode::HLV_H
+                | PrivOpcode::HLV_HU
+                | PrivOpcode::HLVX_HU
+                | PrivOpcode::HLV_W
+                | PrivOpcode::HLVX_WU
+                | PrivOpcode::HLV_WU
+                | PrivOpcode::HLV_D
+                | PrivOpcode::HSV_B
+                | PrivOpcode::HSV_H
+                | PrivOpcode::HSV_W
+                | PrivOpcode::HSV_D,
+            ) => Extensions::H,
+            Self::Priv(_) => Extensions::Priv,
+        }
+    }
+
+    /// Get the extension that the instruction additionally depends on, if any.
+    ///
+    /// e.g. `c.fld` belongs to C but also requires D, and `vfadd.vv` belongs to V but also
+    /// requires F.
+    #[must_use]
+    pub fn get_required_extension(&self) -> Option<Extensions> {
+        match &self {
+            Self::C(COpcode::FLD | COpcode::FSD | COpcode::FLDSP | COpcode::FSDSP) => {
+                Some(Extensions::D)
+            }
+            Self::C(COpcode::FLW | COpcode::FSW | COpcode::FLWSP | COpcode::FSWSP) => {
+                Some(Extensions::F)
+            }
+            Self::V(opc) if opc.is_floating_point() => Some(Extensions::F),
+            _ => None,
+        }
+    }
+
     #[must_use]
     pub fn get_format(&self) -> InstFormat {
         match &self {
             Self::BaseI(opc) => opc.get_format(),
             Self::M(opc) => opc.get_format(),
             Self::A(opc) => opc.get_format(),
+            Self::F(opc) => opc.get_format(),
+            Self::D(opc) => opc.get_format(),
+            Self::Q(opc) => opc.get_format(),
             Self::C(opc) => opc.get_format(),
+            Self::B(opc) => opc.get_format(),
+            Self::V(opc) => opc.get_format(),
             Self::Zifencei(opc) => opc.get_format(),
             Self::Zicboz(opc) => opc.get_format(),
             Self::Zicsr(opc) => opc.get_format(),
@@ -0,0 +1,403 @@
+//! Pseudo-instruction aliases.
+//! See: [RISC-V Assembly Programmer's Manual](https://github.com/riscv-non-isa/riscv-asm-manual/blob/main/src/asm-manual.adoc)
+
+use core::fmt::{self, Display, Formatter};
+
+use super::{
+    b_extension::BOpcode,
+    base_i::BaseIOpcode,
+    c_extension::COpcode,
+    d_extension::DOpcode,
+    f_extension::FOpcode,
+    q_extension::QOpcode,
+    v_extension::VOpcode,
+    zicsr_extension::{Csr, ZicsrOpcode},
+    FormatOptions, Instruction, OpcodeKind, Radix,
+};
+
+/// Instruction formatted with or without pseudo-instruction aliases.
+///
+/// # Example
+/// ```
+/// use raki::{Decode, Isa};
+///
+/// // jalr zero, 0(ra)
+/// let inst = 0x0000_8067_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(inst.with_aliases(true).to_string(), "ret");
+/// assert_eq!(inst.with_aliases(false).to_string(), inst.to_string());
+///
+/// // csrrs a0, mstatus, zero
+/// let inst = 0x3000_2573_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(format!("{:#}", inst.with_aliases(true)), "csrr x10, mstatus");
+/// ```
+pub struct Aliased<'a> {
+    inst: &'a Instruction,
+    aliases: bool,
+}
+
+impl Instruction {
+    /// Format the instruction as a pseudo-instruction (e.g. `nop`, `ret`, `csrr`) if `aliases` is true.
+    ///
+    /// The alternate flag (`{:#}`) prints raw register names.
+    /// Use `FormatOptions::with_aliases` for other styles.
+    #[must_use]
+    pub fn with_aliases(&self, aliases: bool) -> Aliased<'_> {
+        Aliased {
+            inst: self,
+            aliases,
+        }
+    }
+}
+
+impl Display for Aliased<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let opts = FormatOptions::new()
+            .with_numeric_names(f.alternate())
+            .with_aliases(self.aliases);
+        write!(f, "{}", self.inst.display_with(&opts))
+    }
+}
+
+/// Print the branch/jump target, or the pc-relative offset if the target is unknown.
+fn fmt_offset(
+    f: &mut Formatter,
+    opts: &FormatOptions,
+    imm: i32,
+    target: Option<u64>,
+) -> fmt::Result {
+    match target {
+        Some(addr) => write!(f, "{addr:#x}"),
+        None => write!(f, "{}", opts.imm(i64::from(imm), Radix::Decimal)),
+    }
+}
+
+/// Print the instruction as a pseudo-instruction.
+/// `target` replaces the pc-relative offset of branches and jumps.
+///
+/// It returns `None` if the instruction has no alias.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn fmt_alias(
+    inst: &Instruction,
+    f: &mut Formatter,
+    opts: &FormatOptions,
+    target: Option<u64>,
+) -> Option<fmt::Result> {
+    let x = |reg| opts.xreg(reg);
+    let fr = |reg| opts.freg(reg);
+    let sep = opts.separator();
+    let (rd, rs1, rs2, imm) = (inst.rd, inst.rs1, inst.rs2, inst.imm);
+
+    let result = match (&inst.opc, rd, rs1, rs2, imm) {
+        // base integer instructions
+        (OpcodeKind::BaseI(BaseIOpcode::ADDI), Some(0), Some(0), _, Some(0))
+        | (OpcodeKind::C(COpcode::NOP), ..) => write!(f, "nop"),
+        (OpcodeKind::BaseI(BaseIOpcode::ADDI), Some(rd), Some(0), _, Some(imm))
+        | (OpcodeKind::C(COpcode::LI), Some(rd), _, _, Some(imm)) => {
+            let imm = opts.imm(i64::from(imm), Radix::Decimal);
+            write!(f, "li {}{sep}{imm}", x(rd))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::ADDI), Some(rd), Some(rs), _, Some(0))
+        | (OpcodeKind::C(COpcode::MV), Some(rd), _, Some(rs), _) => {
+            write!(f, "mv {}{sep}{}", x(rd), x(rs))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::XORI), Some(rd), Some(rs), _, Some(-1)) => {
+            write!(f, "not {}{sep}{}", x(rd), x(rs))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::ANDI), Some(rd), Some(rs), _, Some(255)) => {
+            write!(f, "zext.b {}{sep}{}", x(rd), x(rs))
+        }
+        (OpcodeKind::B(BOpcode::ADD_UW), Some(rd), Some(rs), Some(0), _) => {
+            write!(f, "zext.w {}{sep}{}", x(rd), x(rs))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::SUB), Some(rd), Some(0), Some(rs), _) => {
+            write!(f, "neg {}{sep}{}", x(rd), x(rs))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::SUBW), Some(rd), Some(0), Some(rs), _) => {
+            write!(f, "negw {}{sep}{}", x(rd), x(rs))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::ADDIW), Some(rd), Some(rs), _, Some(0)) => {
+            write!(f, "sext.w {}{sep}{}", x(rd), x(rs))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::SLTIU), Some(rd), Some(rs), _, Some(1)) => {
+            write!(f, "seqz {}{sep}{}", x(rd), x(rs))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::SLTU), Some(rd), Some(0), Some(rs), _) => {
+            write!(f, "snez {}{sep}{}", x(rd), x(rs))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::SLT), Some(rd), Some(rs), Some(0), _) => {
+            write!(f, "sltz {}{sep}{}", x(rd), x(rs))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::SLT), Some(rd), Some(0), Some(rs), _) => {
+            write!(f, "sgtz {}{sep}{}", x(rd), x(rs))
+        }
+
+        // branches and jumps
+        (OpcodeKind::BaseI(BaseIOpcode::BEQ), _, Some(rs), Some(0), Some(imm))
+        | (OpcodeKind::C(COpcode::BEQZ), _, Some(rs), _, Some(imm)) => {
+            write!(f, "beqz {}{sep}", x(rs)).and_then(|()| fmt_offset(f, opts, imm, target))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::BNE), _, Some(rs), Some(0), Some(imm))
+        | (OpcodeKind::C(COpcode::BNEZ), _, Some(rs), _, Some(imm)) => {
+            write!(f, "bnez {}{sep}", x(rs)).and_then(|()| fmt_offset(f, opts, imm, target))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::BGE), _, Some(0), Some(rs), Some(imm)) => {
+            write!(f, "blez {}{sep}", x(rs)).and_then(|()| fmt_offset(f, opts, imm, target))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::BGE), _, Some(rs), Some(0), Some(imm)) => {
+            write!(f, "bgez {}{sep}", x(rs)).and_then(|()| fmt_offset(f, opts, imm, target))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::BLT), _, Some(rs), Some(0), Some(imm)) => {
+            write!(f, "bltz {}{sep}", x(rs)).and_then(|()| fmt_offset(f, opts, imm, target))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::BLT), _, Some(0), Some(rs), Some(imm)) => {
+            write!(f, "bgtz {}{sep}", x(rs)).and_then(|()| fmt_offset(f, opts, imm, target))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::JAL), Some(0), _, _, Some(imm))
+        | (OpcodeKind::C(COpcode::J), _, _, _, Some(imm)) => {
+            write!(f, "j ").and_then(|()| fmt_offset(f, opts, imm, target))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::JAL), Some(1), _, _, Some(imm))
+        | (OpcodeKind::C(COpcode::JAL), _, _, _, Some(imm)) => {
+            write!(f, "jal ").and_then(|()| fmt_offset(f, opts, imm, target))
+        }
+        (OpcodeKind::BaseI(BaseIOpcode::JALR), Some(0), Some(1), _, Some(0))
+        | (OpcodeKind::C(COpcode::JR), _, Some(1), _, _) => write!(f, "ret"),
+        (OpcodeKind::BaseI(BaseIOpcode::JALR), Some(0), Some(rs), _, Some(0))
+        | (OpcodeKind::C(COpcode::JR), _, Some(rs), _, _) => write!(f, "jr {}", x(rs)),
+        (OpcodeKind::BaseI(BaseIOpcode::JALR), Some(1), Some(rs), _, Some(0))
+        | (OpcodeKind::C(COpcode::JALR), _, Some(rs), _, _) => write!(f, "jalr {}", x(rs)),
+        (OpcodeKind::BaseI(BaseIOpcode::FENCE), Some(0), Some(0), _, Some(0b1111_1111)) => {
+            write!(f, "fence")
+        }
+
+        // counters
+        (OpcodeKind::Zicntr(opc), Some(rd), ..) => write!(f, "{opc} {}", x(rd)),
+
+        // control and status registers (the csr address is stored in rs2)
+        // (`csrr*i` has no rs1)
+        (OpcodeKind::Zicsr(opc), Some(rd), rs1, Some(csr), _) => {
+            return fmt_csr_alias(f, opts, opc, rd, rs1.unwrap_or(0), csr, imm);
+        }
+
+        // floating-point sign injection
+        (
+            OpcodeKind::F(FOpcode::FSGNJ_S)
+            | OpcodeKind::D(DOpcode::FSGNJ_D)
+            | OpcodeKind::Q(QOpcode::FSGNJ_Q),
+            Some(rd),
+            Some(rs),
+            Some(rs_),
+            _,
+        ) if rs == rs_ => write!(f, "fmv.{} {}{sep}{}", fp_suffix(&inst.opc), fr(rd), fr(rs)),
+        (
+            OpcodeKind::F(FOpcode::FSGNJN_S)
+            | OpcodeKind::D(DOpcode::FSGNJN_D)
+            | OpcodeKind::Q(QOpcode::FSGNJN_Q),
+            Some(rd),
+            Some(rs),
+            Some(rs_),
+            _,
+        ) if rs == rs_ => write!(f, "fneg.{} {}{sep}{}", fp_suffix(&inst.opc), fr(rd), fr(rs)),
+        (
+            OpcodeKind::F(FOpcode::FSGNJX_S)
+            | OpcodeKind::D(DOpcode::FSGNJX_D)
+            | OpcodeKind::Q(QOpcode::FSGNJX_Q),
+            Some(rd),
+            Some(rs),
+            Some(rs_),
+            _,
+        ) if rs == rs_ => write!(f, "fabs.{} {}{sep}{}", fp_suffix(&inst.opc), fr(rd), fr(rs)),
+
+        // vector instructions (vs2 is stored in rs2 and vs1 in rs1)
+        (OpcodeKind::V(opc), Some(vd), rs1, Some(vs2), imm) => {
+            return fmt_vector_alias(inst, f, opts, opc, vd, rs1, vs2, imm);
+        }
+
+        _ => return None,
+    };
+
+    Some(result)
+}
+
+/// Print the csr instruction as a pseudo-instruction.
+fn fmt_csr_alias(
+    f: &mut Formatter,
+    opts: &FormatOptions,
+    opc: &ZicsrOpcode,
+    rd: usize,
+    rs: usize,
+    csr: usize,
+    imm: Option<i32>,
+) -> Option<fmt::Result> {
+    let x = |reg| opts.xreg(reg);
+    let sep = opts.separator();
+    let uimm = imm.map(|imm| opts.imm(i64::from(imm), Radix::Decimal));
+    // floating-point control and status registers
+    let csr = Csr::from_field(csr);
+    let fcsr = match csr {
+        Csr::FFLAGS => Some("flags"),
+        Csr::FRM => Some("rm"),
+        Csr::FCSR => Some("csr"),
+        _ => None,
+    };
+
+    let result = match (opc, rd, rs, fcsr) {
+        (ZicsrOpcode::CSRRW, 0, 0, None) if csr == Csr::CYCLE => write!(f, "unimp"),
+        (ZicsrOpcode::CSRRS, rd, 0, Some(name)) => write!(f, "fr{name} {}", x(rd)),
+        (ZicsrOpcode::CSRRW, 0, rs, Some(name)) => write!(f, "fs{name} {}", x(rs)),
+        (ZicsrOpcode::CSRRW, rd, rs, Some(name)) => write!(f, "fs{name} {}{sep}{}", x(rd), x(rs)),
+        (ZicsrOpcode::CSRRWI, 0, _, Some("flags" | "rm")) => {
+            write!(f, "fs{}i {}", fcsr?, uimm?)
+        }
+        (ZicsrOpcode::CSRRWI, rd, _, Some("flags" | "rm")) => {
+            write!(f, "fs{}i {}{sep}{}", fcsr?, x(rd), uimm?)
+        }
+        (ZicsrOpcode::CSRRS, rd, 0, None) => write!(f, "csrr {}{sep}{csr}", x(rd)),
+        (ZicsrOpcode::CSRRW, 0, rs, None) => write!(f, "csrw {csr}{sep}{}", x(rs)),
+        (ZicsrOpcode::CSRRS, 0, rs, None) => write!(f, "csrs {csr}{sep}{}", x(rs)),
+        (ZicsrOpcode::CSRRC, 0, rs, None) => write!(f, "csrc {csr}{sep}{}", x(rs)),
+        (ZicsrOpcode::CSRRWI, 0, _, None) => write!(f, "csrwi {csr}{sep}{}", uimm?),
+        (ZicsrOpcode::CSRRSI, 0, _, None) => write!(f, "csrsi {csr}{sep}{}", uimm?),
+        (ZicsrOpcode::CSRRCI, 0, _, None) => write!(f, "csrci {csr}{sep}{}", uimm?),
+        _ => return None,
+    };
+
+    Some(result)
+}
+
+/// Print the vector instruction as a pseudo-instruction.
+#[allow(clippy::too_many_arguments)]
+fn fmt_vector_alias(
+    inst: &Instruction,
+    f: &mut Formatter,
+    opts: &FormatOptions,
+    opc: &VOpcode,
+    vd: usize,
+    rs1: Option<usize>,
+    vs2: usize,
+    imm: Option<i32>,
+) -> Option<fmt::Result> {
+    let (vd_num, vd, vs) = (vd, opts.vreg(vd), opts.vreg(vs2));
+    let sep = opts.separator();
+    let name = match (opc, rs1, imm) {
+        (VOpcode::VXOR_VI, _, Some(-1)) => "vnot.v",
+        (VOpcode::VRSUB_VX, Some(0), _) => "vneg.v",
+        (VOpcode::VWADD_VX, Some(0), _) => "vwcvt.x.x.v",
+        (VOpcode::VWADDU_VX, Some(0), _) => "vwcvtu.x.x.v",
+        (VOpcode::VNSRL_WX, Some(0), _) => "vncvt.x.x.w",
+        (VOpcode::VFSGNJN_VV, Some(vs1), _) if vs1 == vs2 => "vfneg.v",
+        (VOpcode::VFSGNJX_VV, Some(vs1), _) if vs1 == vs2 => "vfabs.v",
+        (VOpcode::VMAND_MM, Some(vs1), _) if vs1 == vs2 => "vmmv.m",
+        (VOpcode::VMNAND_MM, Some(vs1), _) if vs1 == vs2 => "vmnot.m",
+        (VOpcode::VMXOR_MM, Some(vs1), _) if vs1 == vs2 && vs1 == vd_num => {
+            return Some(write!(f, "vmclr.m {vd}"));
+        }
+        (VOpcode::VMXNOR_MM, Some(vs1), _) if vs1 == vs2 && vs1 == vd_num => {
+            return Some(write!(f, "vmset.m {vd}"));
+        }
+        _ => return None,
+    };
+
+    Some(write!(f, "{name} {vd}{sep}{vs}").and_then(|()| inst.fmt_vector_mask(f, sep)))
+}
+
+/// Precision suffix of floating-point instructions.
+fn fp_suffix(opc: &OpcodeKind) -> &'static str {
+    match opc {
+        OpcodeKind::D(_) => "d",
+        OpcodeKind::Q(_) => "q",
+        _ => "s",
+    }
+}
+
+#[cfg(test)]
+mod test_alias {
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn alias_test() {
+        use crate::{Decode, Isa};
+
+        let alias = |inst: u32| {
+            let inst = match u16::try_from(inst) {
+                Ok(inst) if inst & 0b11 != 0b11 => inst.decode(Isa::Rv64).unwrap(),
+                _ => inst.decode(Isa::Rv64).unwrap(),
+            };
+            inst.with_aliases(true).to_string()
+        };
+
+        assert_eq!(alias(0x0000_0013), "nop");
+        assert_eq!(alias(0x0001), "nop");
+        assert_eq!(alias(0x02a0_0513), "li a0, 42");
+        assert_eq!(alias(0x4505), "li a0, 1");
+        assert_eq!(alias(0x0005_8513), "mv a0, a1");
+        assert_eq!(alias(0x852e), "mv a0, a1");
+        assert_eq!(alias(0xfff5_c513), "not a0, a1");
+        assert_eq!(alias(0x0ff5_f513), "zext.b a0, a1");
+        assert_eq!(alias(0x0805_853b), "zext.w a0, a1");
+        assert_eq!(alias(0x40b0_0533), "neg a0, a1");
+        assert_eq!(alias(0x40b0_053b), "negw a0, a1");
+        assert_eq!(alias(0x0005_851b), "sext.w a0, a1");
+        assert_eq!(alias(0x0015_b513), "seqz a0, a1");
+        assert_eq!(alias(0x00b0_3533), "snez a0, a1");
+        assert_eq!(alias(0x0005_a533), "sltz a0, a1");
+        assert_eq!(alias(0x00b0_2533), "sgtz a0, a1");
+        assert_eq!(alias(0x0ff0_000f), "fence");
+        assert_eq!(alias(0x0310_000f), "fence rw, w");
+
+        assert_eq!(alias(0x0005_0463), "beqz a0, 8");
+        assert_eq!(alias(0xc111), "beqz a0, 4");
+        assert_eq!(alias(0xfe05_1ee3), "bnez a0, -4");
+        assert_eq!(alias(0x00a0_5463), "blez a0, 8");
+        assert_eq!(alias(0x0005_5463), "bgez a0, 8");
+        assert_eq!(alias(0x0005_4463), "bltz a0, 8");
+        assert_eq!(alias(0x00a0_4463), "bgtz a0, 8");
+        assert_eq!(alias(0x0080_006f), "j 8");
+        assert_eq!(alias(0xbff5), "j -4");
+        assert_eq!(alias(0x0080_00ef), "jal 8");
+        assert_eq!(alias(0x0000_8067), "ret");
+        assert_eq!(alias(0x8082), "ret");
+        assert_eq!(alias(0x0005_0067), "jr a0");
+        assert_eq!(alias(0x0005_00e7), "jalr a0");
+        assert_eq!(alias(0x9502), "jalr a0");
+
+        assert_eq!(alias(0x3000_2573), "csrr a0, mstatus");
+        assert_eq!(alias(0x3005_9073), "csrw mstatus, a1");
+        assert_eq!(alias(0x7c05_9073), "csrw 0x7c0, a1");
+        assert_eq!(alias(0x3005_a073), "csrs mstatus, a1");
+        assert_eq!(alias(0x3005_b073), "csrc mstatus, a1");
+        assert_eq!(alias(0x3004_5073), "csrwi mstatus, 8");
+        assert_eq!(alias(0x3004_6073), "csrsi mstatus, 8");
+        assert_eq!(alias(0x3004_7073), "csrci mstatus, 8");
+        assert_eq!(alias(0x0030_2573), "frcsr a0");
+        assert_eq!(alias(0x0020_2573), "frrm a0");
+        assert_eq!(alias(0x0010_2573), "frflags a0");
+        assert_eq!(alias(0x0035_9073), "fscsr a1");
+        assert_eq!(alias(0x0025_9573), "fsrm a0, a1");
+        assert_eq!(alias(0x0011_5073), "fsflagsi 2");
+        assert_eq!(alias(0x0021_5573), "fsrmi a0, 2");
+        assert_eq!(alias(0xc000_1073), "unimp");
+        assert_eq!(alias(0xc000_2573), "rdcycle a0");
+        assert_eq!(alias(0xc820_25f3), "rdinstreth a1");
+        let rdcycle = 0xc000_2573_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(rdcycle.to_string(), "csrrs a0, cycle, zero");
+        // not an alias
+        assert_eq!(alias(0x3005_a573), "csrrs a0, mstatus, a1");
+        assert_eq!(alias(0x0505), "c.addi a0, a0, 1");
+
+        assert_eq!(alias(0x20b5_8553), "fmv.s fa0, fa1");
+        assert_eq!(alias(0x22b5_9553), "fneg.d fa0, fa1");
+        assert_eq!(alias(0x26b5_a553), "fabs.q fa0, fa1");
+
+        assert_eq!(alias(0x2e8f_b457), "vnot.v v8, v8");
+        assert_eq!(alias(0x0c80_4457), "vneg.v v8, v8, v0.t");
+        assert_eq!(alias(0xc680_6457), "vwcvt.x.x.v v8, v8");
+        assert_eq!(alias(0xc280_6457), "vwcvtu.x.x.v v8, v8");
+        assert_eq!(alias(0xb280_4457), "vncvt.x.x.w v8, v8");
+        assert_eq!(alias(0x2684_1457), "vfneg.v v8, v8");
+        assert_eq!(alias(0x2a84_1457), "vfabs.v v8, v8");
+        assert_eq!(alias(0x6684_2457), "vmmv.m v8, v8");
+        assert_eq!(alias(0x7684_2457), "vmnot.m v8, v8");
+        assert_eq!(alias(0x6e84_2457), "vmclr.m v8");
+        assert_eq!(alias(0x7e84_2457), "vmset.m v8");
+    }
+}
@@ -0,0 +1,212 @@
+//! B extension Instruction.
+
+use super::{InstFormat, Opcode};
+use crate::Extensions;
+use core::fmt::{self, Display, Formatter};
+
+/// Insturctions in B Extension (Zba, Zbb, Zbc and Zbs).
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq)]
+pub enum BOpcode {
+    // Zba
+    SH1ADD,
+    SH2ADD,
+    SH3ADD,
+
+    // Zbb
+    ANDN,
+    ORN,
+    XNOR,
+    CLZ,
+    CTZ,
+    CPOP,
+    MAX,
+    MAXU,
+    MIN,
+    MINU,
+    SEXT_B,
+    SEXT_H,
+    ZEXT_H,
+    ROL,
+    ROR,
+    RORI,
+    ORC_B,
+    REV8,
+
+    // Zbc
+    CLMUL,
+    CLMULH,
+    CLMULR,
+
+    // Zbs
+    BCLR,
+    BCLRI,
+    BEXT,
+    BEXTI,
+    BINV,
+    BINVI,
+    BSET,
+    BSETI,
+
+    //-- rv64 only --
+    ADD_UW,
+    SH1ADD_UW,
+    SH2ADD_UW,
+    SH3ADD_UW,
+    SLLI_UW,
+    CLZW,
+    CTZW,
+    CPOPW,
+    ROLW,
+    RORW,
+    RORIW,
+}
+
+impl Display for BOpcode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            BOpcode::SH1ADD => write!(f, "sh1add"),
+            BOpcode::SH2ADD => write!(f, "sh2add"),
+            BOpcode::SH3ADD => write!(f, "sh3add"),
+            BOpcode::ANDN => write!(f, "andn"),
+            BOpcode::ORN => write!(f, "orn"),
+            BOpcode::XNOR => write!(f, "xnor"),
+            BOpcode::CLZ => write!(f, "clz"),
+            BOpcode::CTZ => write!(f, "ctz"),
+            BOpcode::CPOP => write!(f, "cpop"),
+            BOpcode::MAX => write!(f, "max"),
+            BOpcode::MAXU => write!(f, "maxu"),
+            BOpcode::MIN => write!(f, "min"),
+            BOpcode::MINU => write!(f, "minu"),
+            BOpcode::SEXT_B => write!(f, "sext.b"),
+            BOpcode::SEXT_H => write!(f, "sext.h"),
+            BOpcode::ZEXT_H => write!(f, "zext.h"),
+            BOpcode::ROL => write!(f, "rol"),
+            BOpcode::ROR => write!(f, "ror"),
+            BOpcode::RORI => write!(f, "rori"),
+            BOpcode::ORC_B => write!(f, "orc.b"),
+            BOpcode::REV8 => write!(f, "rev8"),
+            BOpcode::CLMUL => write!(f, "clmul"),
+            BOpcode::CLMULH => write!(f, "clmulh"),
+            BOpcode::CLMULR => write!(f, "clmulr"),
+            BOpcode::BCLR => write!(f, "bclr"),
+            BOpcode::BCLRI => write!(f, "bclri"),
+            BOpcode::BEXT => write!(f, "bext"),
+            BOpcode::BEXTI => write!(f, "bexti"),
+            BOpcode::BINV => write!(f, "binv"),
+            BOpcode::BINVI => write!(f, "binvi"),
+            BOpcode::BSET => write!(f, "bset"),
+            BOpcode::BSETI => write!(f, "bseti"),
+            BOpcode::ADD_UW => write!(f, "add.uw"),
+            BOpcode::SH1ADD_UW => write!(f, "sh1add.uw"),
+            BOpcode::SH2ADD_UW => write!(f, "sh2add.uw"),
+            BOpcode::SH3ADD_UW => write!(f, "sh3add.uw"),
+            BOpcode::SLLI_UW => write!(f, "slli.uw"),
+            BOpcode::CLZW => write!(f, "clzw"),
+            BOpcode::CTZW => write!(f, "ctzw"),
+            BOpcode::CPOPW => write!(f, "cpopw"),
+            BOpcode::ROLW => write!(f, "rolw"),
+            BOpcode::RORW => write!(f, "rorw"),
+            BOpcode::RORIW => write!(f, "roriw"),
+        }
+    }
+}
+
+impl BOpcode {
+    /// Get the sub-extension (Zba, Zbb, Zbc or Zbs) that the instruction belongs to.
+    pub(crate) fn get_sub_extension(&self) -> Extensions {
+        match self {
+            BOpcode::SH1ADD
+            | BOpcode::SH2ADD
+            | BOpcode::SH3ADD
+            | BOpcode::ADD_UW
+            | BOpcode::SH1ADD_UW
+            | BOpcode::SH2ADD_UW
+            | BOpcode::SH3ADD_UW
+            | BOpcode::SLLI_UW => Extensions::Zba,
+            BOpcode::ANDN
+            | BOpcode::ORN
+            | BOpcode::XNOR
+            | BOpcode::CLZ
+            | BOpcode::CTZ
+            | BOpcode::CPOP
+            | BOpcode::MAX
+            | BOpcode::MAXU
+            | BOpcode::MIN
+            | BOpcode::MINU
+            | BOpcode::SEXT_B
+            | BOpcode::SEXT_H
+            | BOpcode::ZEXT_H
+            | BOpcode::ROL
+            | BOpcode::ROR
+            | BOpcode::RORI
+            | BOpcode::ORC_B
+            | BOpcode::REV8
+            | BOpcode::CLZW
+            | BOpcode::CTZW
+            | BOpcode::CPOPW
+            | BOpcode::ROLW
+            | BOpcode::RORW
+            | BOpcode::RORIW => Extensions::Zbb,
+            BOpcode::CLMUL | BOpcode::CLMULH | BOpcode::CLMULR => Extensions::Zbc,
+            BOpcode::BCLR
+            | BOpcode::BCLRI
+            | BOpcode::BEXT
+            | BOpcode::BEXTI
+            | BOpcode::BINV
+            | BOpcode::BINVI
+            | BOpcode::BSET
+            | BOpcode::BSETI => Extensions::Zbs,
+        }
+    }
+}
+
+impl Opcode for BOpcode {
+    fn get_format(&self) -> InstFormat {
+        match self {
+            BOpcode::SH1ADD
+            | BOpcode::SH2ADD
+            | BOpcode::SH3ADD
+            | BOpcode::ANDN
+            | BOpcode::ORN
+            | BOpcode::XNOR
+            | BOpcode::MAX
+            | BOpcode::MAXU
+            | BOpcode::MIN
+            | BOpcode::MINU
+            | BOpcode::ROL
+            | BOpcode::ROR
+            | BOpcode::CLMUL
+            | BOpcode::CLMULH
+            | BOpcode::CLMULR
+            | BOpcode::BCLR
+            | BOpcode::BEXT
+            | BOpcode::BINV
+            | BOpcode::BSET
+            | BOpcode::ADD_UW
+            | BOpcode::SH1ADD_UW
+            | BOpcode::SH2ADD_UW
+            | BOpcode::SH3ADD_UW
+            | BOpcode::ROLW
+            | BOpcode::RORW => InstFormat::RFormat,
+            BOpcode::CLZ
+            | BOpcode::CTZ
+            | BOpcode::CPOP
+            | BOpcode::SEXT_B
+            | BOpcode::SEXT_H
+            | BOpcode::ZEXT_H
+            | BOpcode::ORC_B
+            | BOpcode::REV8
+            | BOpcode::CLZW
+            | BOpcode::CTZW
+            | BOpcode::CPOPW => InstFormat::RUnaryFormat,
+            BOpcode::RORI
+            | BOpcode::BCLRI
+            | BOpcode::BEXTI
+            | BOpcode::BINVI
+            | BOpcode::BSETI
+            | BOpcode::SLLI_UW
+            | BOpcode::RORIW => InstFormat::RShamtFormat,
+        }
+    }
+}
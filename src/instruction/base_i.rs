@@ -1,6 +1,6 @@
 //! Base I Instructions.
 
-use super::{InstFormat, Opcode};
+use super::{InstFormat, Instruction, Opcode, OpcodeKind};
 use core::fmt::{self, Display, Formatter};
 
 /// Insturctions in Base-I.
@@ -44,6 +44,9 @@ pub enum BaseIOpcode {
     SRA,
     OR,
     AND,
+    FENCE,
+    FENCE_TSO,
+    PAUSE,
     ECALL,
     EBREAK,
 
@@ -102,6 +105,9 @@ impl Display for BaseIOpcode {
             BaseIOpcode::SRA => write!(f, "sra"),
             BaseIOpcode::OR => write!(f, "or"),
             BaseIOpcode::AND => write!(f, "and"),
+            BaseIOpcode::FENCE => write!(f, "fence"),
+            BaseIOpcode::FENCE_TSO => write!(f, "fence.tso"),
+            BaseIOpcode::PAUSE => write!(f, "pause"),
             BaseIOpcode::ECALL => write!(f, "ecall"),
             BaseIOpcode::EBREAK => write!(f, "ebreak"),
             BaseIOpcode::LWU => write!(f, "lwu"),
@@ -170,7 +176,140 @@ impl Opcode for BaseIOpcode {
             }
             BaseIOpcode::JAL => InstFormat::JFormat,
             BaseIOpcode::LUI | BaseIOpcode::AUIPC => InstFormat::UFormat,
-            BaseIOpcode::ECALL | BaseIOpcode::EBREAK => InstFormat::NoOperand,
+            BaseIOpcode::FENCE => InstFormat::FenceFormat,
+            BaseIOpcode::FENCE_TSO
+            | BaseIOpcode::PAUSE
+            | BaseIOpcode::ECALL
+            | BaseIOpcode::EBREAK => InstFormat::NoOperand,
         }
     }
 }
+
+/// Fence mode (`fm`) of FENCE.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum FenceMode {
+    /// Normal fence (`fm = 0000`)
+    Normal,
+    /// Total store ordering (`fm = 1000`, `fence.tso` when `pred = succ = rw`)
+    Tso,
+    /// Reserved for future use
+    Reserved(u8),
+}
+
+/// Ordering fields (`fm`, `pred`, `succ`) encoded in the immediate of FENCE.
+///
+/// # Example
+/// ```
+/// use raki::{Decode, FenceMode, Isa};
+///
+/// // fence rw, w
+/// let inst = 0x0310_000f_u32.decode(Isa::Rv64).unwrap();
+/// let fence = inst.fence().unwrap();
+/// assert_eq!(fence.fm, FenceMode::Normal);
+/// assert!(fence.pred.read() && fence.pred.write());
+/// assert_eq!(fence.succ.to_string(), "w");
+/// assert_eq!(inst.to_string(), "fence rw, w");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Fence {
+    /// Fence mode
+    pub fm: FenceMode,
+    /// Predecessor set
+    pub pred: FenceSet,
+    /// Successor set
+    pub succ: FenceSet,
+}
+
+impl Fence {
+    /// Convert the raw 12bit `fm_pred_succ` field into `Fence`.
+    #[must_use]
+    pub fn from_imm(fm_pred_succ: i32) -> Self {
+        let field = |shift: i32| u8::try_from(fm_pred_succ >> shift & 0b1111).unwrap_or(0);
+
+        Fence {
+            fm: match field(8) {
+                0b0000 => FenceMode::Normal,
+                0b1000 => FenceMode::Tso,
+                fm => FenceMode::Reserved(fm),
+            },
+            pred: FenceSet::new(field(4)),
+            succ: FenceSet::new(field(0)),
+        }
+    }
+}
+
+impl Instruction {
+    /// Ordering fields of `fence`, `fence.tso` and `pause`, or `None` for the other instructions.
+    #[must_use]
+    pub fn fence(&self) -> Option<Fence> {
+        match self.opc {
+            OpcodeKind::BaseI(BaseIOpcode::FENCE | BaseIOpcode::FENCE_TSO | BaseIOpcode::PAUSE) => {
+                self.imm.map(Fence::from_imm)
+            }
+            _ => None,
+        }
+    }
+}
+
+/// Predecessor/successor set of FENCE (`i`: device input, `o`: device output, `r`: memory reads, `w`: memory writes).
+///
+/// `Display` prints the set like `iorw`, or `0` if it is empty.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct FenceSet(u8);
+
+impl FenceSet {
+    /// Create a set from the 4bit `pred`/`succ` field.
+    #[must_use]
+    pub const fn new(bits: u8) -> Self {
+        FenceSet(bits & 0b1111)
+    }
+
+    /// Raw 4bit field.
+    #[must_use]
+    pub const fn bits(self) -> u8 {
+        self.0
+    }
+
+    /// Device input.
+    #[must_use]
+    pub const fn input(self) -> bool {
+        self.0 & 0b1000 != 0
+    }
+
+    /// Device output.
+    #[must_use]
+    pub const fn output(self) -> bool {
+        self.0 & 0b0100 != 0
+    }
+
+    /// Memory reads.
+    #[must_use]
+    pub const fn read(self) -> bool {
+        self.0 & 0b0010 != 0
+    }
+
+    /// Memory writes.
+    #[must_use]
+    pub const fn write(self) -> bool {
+        self.0 & 0b0001 != 0
+    }
+}
+
+impl Display for FenceSet {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.0 == 0 {
+            return write!(f, "0");
+        }
+        for (flag, name) in [
+            (self.input(), "i"),
+            (self.output(), "o"),
+            (self.read(), "r"),
+            (self.write(), "w"),
+        ] {
+            if flag {
+                write!(f, "{name}")?;
+            }
+        }
+        Ok(())
+    }
+}
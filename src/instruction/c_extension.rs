@@ -34,6 +34,16 @@ pub enum COpcode {
     JALR,
     ADD,
     SWSP,
+    FLD,
+    FSD,
+    FLDSP,
+    FSDSP,
+
+    //-- rv32 only --
+    FLW,
+    FSW,
+    FLWSP,
+    FSWSP,
 
     //-- rv64 only --
     LD,
@@ -75,6 +85,14 @@ impl Display for COpcode {
             COpcode::JALR => write!(f, "C.jalr"),
             COpcode::ADD => write!(f, "C.add"),
             COpcode::SWSP => write!(f, "C.swsp"),
+            COpcode::FLD => write!(f, "C.fld"),
+            COpcode::FSD => write!(f, "C.fsd"),
+            COpcode::FLDSP => write!(f, "C.fldsp"),
+            COpcode::FSDSP => write!(f, "C.fsdsp"),
+            COpcode::FLW => write!(f, "C.flw"),
+            COpcode::FSW => write!(f, "C.fsw"),
+            COpcode::FLWSP => write!(f, "C.flwsp"),
+            COpcode::FSWSP => write!(f, "C.fswsp"),
             COpcode::LD => write!(f, "C.ld"),
             COpcode::SD => write!(f, "C.sd"),
             COpcode::ADDIW => write!(f, "C.addiw"),
@@ -94,6 +112,8 @@ impl Opcode for COpcode {
             COpcode::LW | COpcode::LD => InstFormat::ClFormat,
             COpcode::ADDI4SPN => InstFormat::CiwFormat,
             COpcode::SW | COpcode::SD => InstFormat::CsFormat,
+            COpcode::FLD | COpcode::FLW => InstFormat::CflFormat,
+            COpcode::FSD | COpcode::FSW => InstFormat::CfsFormat,
 
             // Quadrant 1
             COpcode::JAL | COpcode::J => InstFormat::CjFormat,
@@ -116,6 +136,8 @@ impl Opcode for COpcode {
             COpcode::SDSP | COpcode::SWSP => InstFormat::CssFormat,
             COpcode::JR | COpcode::JALR | COpcode::MV | COpcode::ADD => InstFormat::CrFormat,
             COpcode::EBREAK => InstFormat::NoOperand,
+            COpcode::FLDSP | COpcode::FLWSP => InstFormat::CflspFormat,
+            COpcode::FSDSP | COpcode::FSWSP => InstFormat::CfsspFormat,
         }
     }
 }
@@ -0,0 +1,317 @@
+//! Conversion between compressed and 32-bit instructions.
+
+use super::{
+    base_i::BaseIOpcode, c_extension::COpcode, d_extension::DOpcode, f_extension::FOpcode,
+    zicfiss_extension::ZicfissOpcode, Instruction, OpcodeKind,
+};
+use crate::{Decode, Encode, Isa};
+
+/// Instruction with the format derived from `opc`.
+fn build(
+    opc: OpcodeKind,
+    rd: Option<usize>,
+    rs1: Option<usize>,
+    rs2: Option<usize>,
+    imm: Option<i32>,
+    is_compressed: bool,
+) -> Instruction {
+    Instruction {
+        inst_format: opc.get_format(),
+        opc,
+        rd,
+        rs1,
+        rs2,
+        rs3: None,
+        imm,
+        vm: None,
+        rm: None,
+        is_compressed,
+    }
+}
+
+impl Instruction {
+    /// Expand a compressed instruction into its 32-bit equivalent
+    /// (e.g. `c.lwsp a0, 12(sp)` into `lw a0, 12(sp)`).
+    ///
+    /// `is_compressed` of the expanded instruction stays `true` to keep the length of the original.
+    /// It returns `None` if the instruction is not compressed.
+    ///
+    /// # Example
+    /// ```
+    /// use raki::{BaseIOpcode, Decode, Isa, OpcodeKind};
+    ///
+    /// // c.lwsp a0, 12(sp)
+    /// let inst = 0x4532_u16.decode(Isa::Rv64).unwrap();
+    /// let expanded = inst.expand().unwrap();
+    /// assert_eq!(expanded.opc, OpcodeKind::BaseI(BaseIOpcode::LW));
+    /// assert_eq!((expanded.rd, expanded.rs1, expanded.imm), (Some(10), Some(2), Some(12)));
+    /// assert!(expanded.is_compressed);
+    /// ```
+    #[must_use]
+    pub fn expand(&self) -> Option<Instruction> {
+        let (rd, rs1, rs2, imm) = (self.rd, self.rs1, self.rs2, self.imm);
+        let (zero, ra, sp) = (Some(0), Some(1), Some(2));
+        let base = OpcodeKind::BaseI;
+
+        let (opc, rd, rs1, rs2, imm) = match &self.opc {
+            OpcodeKind::C(opc) => match opc {
+                // Quadrant 0
+                COpcode::ADDI4SPN => (base(BaseIOpcode::ADDI), rd, sp, None, imm),
+                COpcode::LW => (base(BaseIOpcode::LW), rd, rs1, None, imm),
+                COpcode::LD => (base(BaseIOpcode::LD), rd, rs1, None, imm),
+                COpcode::FLW => (OpcodeKind::F(FOpcode::FLW), rd, rs1, None, imm),
+                COpcode::FLD => (OpcodeKind::D(DOpcode::FLD), rd, rs1, None, imm),
+                COpcode::SW => (base(BaseIOpcode::SW), None, rs1, rs2, imm),
+                COpcode::SD => (base(BaseIOpcode::SD), None, rs1, rs2, imm),
+                COpcode::FSW => (OpcodeKind::F(FOpcode::FSW), None, rs1, rs2, imm),
+                COpcode::FSD => (OpcodeKind::D(DOpcode::FSD), None, rs1, rs2, imm),
+
+                // Quadrant 1
+                COpcode::NOP => (base(BaseIOpcode::ADDI), zero, zero, None, imm),
+                COpcode::ADDI => (base(BaseIOpcode::ADDI), rd, rd, None, imm),
+                COpcode::JAL => (base(BaseIOpcode::JAL), ra, None, None, imm),
+                COpcode::ADDIW => (base(BaseIOpcode::ADDIW), rd, rd, None, imm),
+                COpcode::LI => (base(BaseIOpcode::ADDI), rd, zero, None, imm),
+                COpcode::ADDI16SP => (base(BaseIOpcode::ADDI), sp, sp, None, imm),
+                // c.lui holds the shifted immediate, lui holds the upper 20 bits.
+                COpcode::LUI => (
+                    base(BaseIOpcode::LUI),
+                    rd,
+                    None,
+                    None,
+                    imm.map(|imm| imm >> 12 & 0xf_ffff),
+                ),
+                COpcode::SRLI => (base(BaseIOpcode::SRLI), rd, rd, None, imm),
+                COpcode::SRAI => (base(BaseIOpcode::SRAI), rd, rd, None, imm),
+                COpcode::ANDI => (base(BaseIOpcode::ANDI), rd, rd, None, imm),
+                COpcode::SUB => (base(BaseIOpcode::SUB), rd, rd, rs2, None),
+                COpcode::XOR => (base(BaseIOpcode::XOR), rd, rd, rs2, None),
+                COpcode::OR => (base(BaseIOpcode::OR), rd, rd, rs2, None),
+                COpcode::AND => (base(BaseIOpcode::AND), rd, rd, rs2, None),
+                COpcode::SUBW => (base(BaseIOpcode::SUBW), rd, rd, rs2, None),
+                COpcode::ADDW => (base(BaseIOpcode::ADDW), rd, rd, rs2, None),
+                COpcode::J => (base(BaseIOpcode::JAL), zero, None, None, imm),
+                COpcode::BEQZ => (base(BaseIOpcode::BEQ), None, rs1, zero, imm),
+                COpcode::BNEZ => (base(BaseIOpcode::BNE), None, rs1, zero, imm),
+
+                // Quadrant 2
+                COpcode::SLLI => (base(BaseIOpcode::SLLI), rd, rd, None, imm),
+                COpcode::LWSP => (base(BaseIOpcode::LW), rd, sp, None, imm),
+                COpcode::LDSP => (base(BaseIOpcode::LD), rd, sp, None, imm),
+                COpcode::FLWSP => (OpcodeKind::F(FOpcode::FLW), rd, sp, None, imm),
+                COpcode::FLDSP => (OpcodeKind::D(DOpcode::FLD), rd, sp, None, imm),
+                COpcode::JR => (base(BaseIOpcode::JALR), zero, rs1, None, Some(0)),
+                COpcode::MV => (base(BaseIOpcode::ADD), rd, zero, rs2, None),
+                COpcode::EBREAK => (base(BaseIOpcode::EBREAK), None, None, None, None),
+                COpcode::JALR => (base(BaseIOpcode::JALR), ra, rs1, None, Some(0)),
+                COpcode::ADD => (base(BaseIOpcode::ADD), rd, rd, rs2, None),
+                COpcode::SWSP => (base(BaseIOpcode::SW), None, sp, rs2, imm),
+                COpcode::SDSP => (base(BaseIOpcode::SD), None, sp, rs2, imm),
+                COpcode::FSWSP => (OpcodeKind::F(FOpcode::FSW), None, sp, rs2, imm),
+                COpcode::FSDSP => (OpcodeKind::D(DOpcode::FSD), None, sp, rs2, imm),
+            },
+            OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPUSH) => (
+                OpcodeKind::Zicfiss(ZicfissOpcode::SSPUSH),
+                zero,
+                zero,
+                ra,
+                None,
+            ),
+            OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPOPCHK) => (
+                OpcodeKind::Zicfiss(ZicfissOpcode::SSPOPCHK),
+                zero,
+                Some(5),
+                None,
+                None,
+            ),
+            _ => return None,
+        };
+
+        Some(build(opc, rd, rs1, rs2, imm, true))
+    }
+
+    /// Compress a 32-bit instruction into its RVC equivalent
+    /// (e.g. `addi sp, sp, -16` into `c.addi16sp sp, -16`).
+    ///
+    /// It returns `None` if the instruction has no compressed form in `isa`
+    /// (e.g. the registers are not in `x8`-`x15` or the immediate is out of range).
+    ///
+    /// # Example
+    /// ```
+    /// use raki::{COpcode, Decode, Isa, OpcodeKind};
+    ///
+    /// // add a0, a0, a1
+    /// let inst = 0x00b5_0533_u32.decode(Isa::Rv64).unwrap();
+    /// let compressed = inst.compress(Isa::Rv64).unwrap();
+    /// assert_eq!(compressed.opc, OpcodeKind::C(COpcode::ADD));
+    ///
+    /// // add a0, a1, a2
+    /// let inst = 0x00c5_8533_u32.decode(Isa::Rv64).unwrap();
+    /// assert!(inst.compress(Isa::Rv64).is_none());
+    /// ```
+    #[must_use]
+    pub fn compress(&self, isa: Isa) -> Option<Instruction> {
+        let (rd, rs1, rs2, imm) = (self.rd, self.rs1, self.rs2, self.imm);
+        let (zero, ra, sp) = (Some(0), Some(1), Some(2));
+
+        let opc = match &self.opc {
+            OpcodeKind::BaseI(opc) => OpcodeKind::C(match opc {
+                BaseIOpcode::ADDI => match (rd, rs1) {
+                    (_, Some(0)) if rd == zero => COpcode::NOP,
+                    (_, Some(0)) => COpcode::LI,
+                    (Some(2), Some(2)) if imm.is_some_and(|imm| imm != 0 && imm % 16 == 0) => {
+                        COpcode::ADDI16SP
+                    }
+                    (_, Some(2)) if rd != sp => COpcode::ADDI4SPN,
+                    _ => COpcode::ADDI,
+                },
+                BaseIOpcode::ADDIW => COpcode::ADDIW,
+                // c.lui with nzimm = 0 is reserved.
+                BaseIOpcode::LUI if imm == Some(0) => return None,
+                BaseIOpcode::LUI => COpcode::LUI,
+                BaseIOpcode::SRLI => COpcode::SRLI,
+                BaseIOpcode::SRAI => COpcode::SRAI,
+                BaseIOpcode::SLLI => COpcode::SLLI,
+                BaseIOpcode::ANDI => COpcode::ANDI,
+                BaseIOpcode::SUB => COpcode::SUB,
+                BaseIOpcode::XOR => COpcode::XOR,
+                BaseIOpcode::OR => COpcode::OR,
+                BaseIOpcode::AND => COpcode::AND,
+                BaseIOpcode::SUBW => COpcode::SUBW,
+                BaseIOpcode::ADDW => COpcode::ADDW,
+                BaseIOpcode::ADD if rs1 == zero => COpcode::MV,
+                BaseIOpcode::ADD => COpcode::ADD,
+                BaseIOpcode::JAL if rd == ra => COpcode::JAL,
+                BaseIOpcode::JAL => COpcode::J,
+                BaseIOpcode::JALR if rd == ra => COpcode::JALR,
+                BaseIOpcode::JALR => COpcode::JR,
+                BaseIOpcode::BEQ => COpcode::BEQZ,
+                BaseIOpcode::BNE => COpcode::BNEZ,
+                BaseIOpcode::LW if rs1 == sp => COpcode::LWSP,
+                BaseIOpcode::LW => COpcode::LW,
+                BaseIOpcode::LD if rs1 == sp => COpcode::LDSP,
+                BaseIOpcode::LD => COpcode::LD,
+                BaseIOpcode::SW if rs1 == sp => COpcode::SWSP,
+                BaseIOpcode::SW => COpcode::SW,
+                BaseIOpcode::SD if rs1 == sp => COpcode::SDSP,
+                BaseIOpcode::SD => COpcode::SD,
+                BaseIOpcode::EBREAK => COpcode::EBREAK,
+                _ => return None,
+            }),
+            OpcodeKind::F(FOpcode::FLW) if rs1 == sp => OpcodeKind::C(COpcode::FLWSP),
+            OpcodeKind::F(FOpcode::FLW) => OpcodeKind::C(COpcode::FLW),
+            OpcodeKind::F(FOpcode::FSW) if rs1 == sp => OpcodeKind::C(COpcode::FSWSP),
+            OpcodeKind::F(FOpcode::FSW) => OpcodeKind::C(COpcode::FSW),
+            OpcodeKind::D(DOpcode::FLD) if rs1 == sp => OpcodeKind::C(COpcode::FLDSP),
+            OpcodeKind::D(DOpcode::FLD) => OpcodeKind::C(COpcode::FLD),
+            OpcodeKind::D(DOpcode::FSD) if rs1 == sp => OpcodeKind::C(COpcode::FSDSP),
+            OpcodeKind::D(DOpcode::FSD) => OpcodeKind::C(COpcode::FSD),
+            OpcodeKind::Zicfiss(ZicfissOpcode::SSPUSH) => {
+                OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPUSH)
+            }
+            OpcodeKind::Zicfiss(ZicfissOpcode::SSPOPCHK) => {
+                OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPOPCHK)
+            }
+            _ => return None,
+        };
+        let imm = match opc {
+            // lui holds the upper 20 bits, c.lui holds the sign-extended shifted immediate.
+            OpcodeKind::C(COpcode::LUI) => imm.map(|imm| imm << 12),
+            _ => imm,
+        };
+
+        // Encode the candidate, and accept it only if it expands back to this instruction.
+        let candidate = build(opc, rd, rs1, rs2, imm, true);
+        let compressed = u16::encode(&candidate, isa).ok()?.decode(isa).ok()?;
+        let mut expanded = compressed.expand()?;
+        expanded.is_compressed = self.is_compressed;
+        (expanded == *self).then_some(compressed)
+    }
+}
+
+#[cfg(test)]
+mod test_compress {
+    #[test]
+    fn expand_test() {
+        use crate::{Decode, Encode, Isa};
+
+        // every compressed instruction expands to the decoding of its 32-bit encoding.
+        for isa in [Isa::Rv32, Isa::Rv64] {
+            for inst_16 in 0..=u16::MAX {
+                let Ok(inst) = inst_16.decode(isa) else {
+                    continue;
+                };
+                let expanded = inst.expand().unwrap();
+                let inst_32 = u32::encode(&expanded, isa)
+                    .unwrap_or_else(|e| panic!("{e:?}: {inst_16:#06x} -> {expanded:?}"));
+                let mut decoded = inst_32.decode(isa).unwrap();
+                decoded.is_compressed = true;
+                assert_eq!(decoded, expanded, "{inst_16:#06x}");
+
+                if let Some(compressed) = expanded.compress(isa) {
+                    assert_eq!(compressed.expand(), Some(expanded), "{inst_16:#06x}");
+                }
+            }
+        }
+
+        // addi a0, a0, 1
+        assert_eq!(0x0015_0513_u32.decode(Isa::Rv64).unwrap().expand(), None);
+    }
+
+    #[test]
+    fn compress_test() {
+        use crate::{COpcode, Decode, Isa, OpcodeKind, ZicfissOpcode};
+
+        let compress = |inst: u32, isa: Isa| {
+            inst.decode(isa)
+                .unwrap()
+                .compress(isa)
+                .map(|compressed| compressed.opc)
+        };
+        let rv64 = |inst: u32| compress(inst, Isa::Rv64);
+
+        // addi zero, zero, 0 / addi a0, zero, 1 / addi sp, sp, -16 / addi a0, sp, 16 / addi a0, a0, 1
+        assert_eq!(rv64(0x0000_0013), Some(OpcodeKind::C(COpcode::NOP)));
+        assert_eq!(rv64(0x0010_0513), Some(OpcodeKind::C(COpcode::LI)));
+        assert_eq!(rv64(0xff01_0113), Some(OpcodeKind::C(COpcode::ADDI16SP)));
+        assert_eq!(rv64(0x0101_0513), Some(OpcodeKind::C(COpcode::ADDI4SPN)));
+        assert_eq!(rv64(0x0015_0513), Some(OpcodeKind::C(COpcode::ADDI)));
+        // addi a0, a0, 32 (out of range) / addi a0, a1, 1
+        assert_eq!(rv64(0x0205_0513), None);
+        assert_eq!(rv64(0x0015_8513), None);
+        // lui a0, 0xfffff / lui a0, 0x80000 / lui a0, 0
+        assert_eq!(rv64(0xffff_f537), Some(OpcodeKind::C(COpcode::LUI)));
+        assert_eq!(rv64(0x8000_0537), None);
+        assert_eq!(rv64(0x0000_0537), None);
+        // lw a0, 12(sp) / lw a0, 4(a1) / lw a0, 4(a6) / ld a0, 8(a1) on rv32 and rv64
+        assert_eq!(rv64(0x00c1_2503), Some(OpcodeKind::C(COpcode::LWSP)));
+        assert_eq!(rv64(0x0045_a503), Some(OpcodeKind::C(COpcode::LW)));
+        assert_eq!(rv64(0x0048_2503), None);
+        assert_eq!(rv64(0x0085_b503), Some(OpcodeKind::C(COpcode::LD)));
+        assert_eq!(
+            compress(0x0045_a507, Isa::Rv32),
+            Some(OpcodeKind::C(COpcode::FLW))
+        );
+        assert_eq!(rv64(0x0045_a507), None);
+        // jal ra, 8 on rv32 and rv64 / jalr zero, 0(ra) / jalr zero, 4(ra)
+        assert_eq!(
+            compress(0x0080_00ef, Isa::Rv32),
+            Some(OpcodeKind::C(COpcode::JAL))
+        );
+        assert_eq!(rv64(0x0080_00ef), None);
+        assert_eq!(rv64(0x0000_8067), Some(OpcodeKind::C(COpcode::JR)));
+        assert_eq!(rv64(0x0040_8067), None);
+        // add a0, zero, a1 / beq a0, zero, 8 / beq a0, a1, 8
+        assert_eq!(rv64(0x00b0_0533), Some(OpcodeKind::C(COpcode::MV)));
+        assert_eq!(rv64(0x0005_0463), Some(OpcodeKind::C(COpcode::BEQZ)));
+        assert_eq!(rv64(0x00b5_0463), None);
+        // sspush ra / sspush t0
+        assert_eq!(
+            rv64(0xce10_4073),
+            Some(OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPUSH))
+        );
+        assert_eq!(rv64(0xce50_4073), None);
+        // mul a0, a0, a1
+        assert_eq!(rv64(0x02b5_0533), None);
+    }
+}
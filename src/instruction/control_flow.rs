@@ -0,0 +1,196 @@
+//! Control-flow classification of instructions.
+
+use super::{
+    base_i::BaseIOpcode, c_extension::COpcode, priv_extension::PrivOpcode,
+    zifencei_extension::ZifenceiOpcode, Instruction, OpcodeKind,
+};
+
+/// Control flow of an instruction.
+///
+/// Offsets are relative to the address of the instruction.
+///
+/// # Example
+/// ```
+/// use raki::{ControlFlow, Decode, Isa};
+///
+/// // jal ra, 16
+/// let inst = 0x0100_00ef_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(inst.control_flow(), Some(ControlFlow::Call { offset: Some(16) }));
+///
+/// // jalr zero, 0(ra)
+/// let inst = 0x0000_8067_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(inst.control_flow(), Some(ControlFlow::Return));
+///
+/// // addi a0, a0, 1
+/// let inst = 0x0015_0513_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(inst.control_flow(), None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ControlFlow {
+    /// Conditional branch (e.g. `beq`, `c.bnez`)
+    Branch {
+        /// Offset of the branch target
+        offset: i32,
+    },
+    /// Direct jump that does not push a return address (e.g. `jal zero`, `c.j`)
+    Jump {
+        /// Offset of the jump target
+        offset: i32,
+    },
+    /// Indirect jump that does not push nor pop a return address (e.g. `jalr zero, 0(t1)`)
+    IndirectJump,
+    /// Call that pushes a return address (e.g. `jal ra`, `jalr ra, 0(a0)`, `c.jalr`)
+    Call {
+        /// Offset of the callee, if it is a direct call
+        offset: Option<i32>,
+    },
+    /// Return that pops a return address (e.g. `jalr zero, 0(ra)`, `c.jr t0`)
+    Return,
+    /// Coroutine swap that pops a return address and then pushes one
+    /// (e.g. `jalr ra, 0(t0)`, `c.jalr t0`)
+    CoroutineSwap,
+    /// Trap into the execution environment (`ecall`, `ebreak`)
+    Trap,
+    /// Return from a trap handler (e.g. `mret`, `sret`)
+    TrapReturn,
+    /// Memory, instruction-fetch or address-translation fence (e.g. `fence`, `sfence.vma`)
+    Fence,
+}
+
+impl ControlFlow {
+    /// Statically known target offset.
+    #[must_use]
+    pub fn offset(self) -> Option<i32> {
+        match self {
+            ControlFlow::Branch { offset } | ControlFlow::Jump { offset } => Some(offset),
+            ControlFlow::Call { offset } => offset,
+            _ => None,
+        }
+    }
+}
+
+/// `x1` (`ra`) or `x5` (`t0`), the link registers of the return-address stack hints.
+fn is_link(reg: Option<usize>) -> bool {
+    matches!(reg, Some(1 | 5))
+}
+
+/// Classify `jalr rd, rs1` by the return-address stack hints of the spec.
+fn jalr(rd: Option<usize>, rs1: Option<usize>) -> ControlFlow {
+    match (is_link(rd), is_link(rs1)) {
+        (true, true) if rd != rs1 => ControlFlow::CoroutineSwap,
+        (true, _) => ControlFlow::Call { offset: None },
+        (false, true) => ControlFlow::Return,
+        (false, false) => ControlFlow::IndirectJump,
+    }
+}
+
+impl Instruction {
+    /// Control flow of the instruction, or `None` if it falls through to the next instruction.
+    #[must_use]
+    pub fn control_flow(&self) -> Option<ControlFlow> {
+        match self.opc {
+            OpcodeKind::BaseI(
+                BaseIOpcode::BEQ
+                | BaseIOpcode::BNE
+                | BaseIOpcode::BLT
+                | BaseIOpcode::BGE
+                | BaseIOpcode::BLTU
+                | BaseIOpcode::BGEU,
+            )
+            | OpcodeKind::C(COpcode::BEQZ | COpcode::BNEZ) => {
+                Some(ControlFlow::Branch { offset: self.imm? })
+            }
+            OpcodeKind::BaseI(BaseIOpcode::JAL) if is_link(self.rd) => Some(ControlFlow::Call {
+                offset: Some(self.imm?),
+            }),
+            OpcodeKind::BaseI(BaseIOpcode::JAL) | OpcodeKind::C(COpcode::J) => {
+                Some(ControlFlow::Jump { offset: self.imm? })
+            }
+            OpcodeKind::C(COpcode::JAL) => Some(ControlFlow::Call {
+                offset: Some(self.imm?),
+            }),
+            OpcodeKind::BaseI(BaseIOpcode::JALR) => Some(jalr(self.rd, self.rs1)),
+            OpcodeKind::C(COpcode::JR) => Some(jalr(Some(0), self.rs1)),
+            OpcodeKind::C(COpcode::JALR) => Some(jalr(Some(1), self.rs1)),
+            OpcodeKind::BaseI(BaseIOpcode::ECALL | BaseIOpcode::EBREAK)
+            | OpcodeKind::C(COpcode::EBREAK) => Some(ControlFlow::Trap),
+            OpcodeKind::Priv(
+                PrivOpcode::MRET | PrivOpcode::SRET | PrivOpcode::MNRET | PrivOpcode::DRET,
+            ) => Some(ControlFlow::TrapReturn),
+            OpcodeKind::BaseI(BaseIOpcode::FENCE | BaseIOpcode::FENCE_TSO)
+            | OpcodeKind::Zifencei(ZifenceiOpcode::FENCE_I)
+            | OpcodeKind::Priv(
+                PrivOpcode::SFENCE_VMA
+                | PrivOpcode::SINVAL_VMA
+                | PrivOpcode::SFENCE_W_INVAL
+                | PrivOpcode::SFENCE_INVAL_IR
+                | PrivOpcode::HFENCE_VVMA
+                | PrivOpcode::HFENCE_GVMA,
+            ) => Some(ControlFlow::Fence),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_control_flow {
+    #[test]
+    fn control_flow_test() {
+        use super::ControlFlow;
+        use crate::{Decode, Isa};
+
+        let control_flow = |inst: u32, isa: Isa| match u16::try_from(inst) {
+            Ok(inst) if inst & 0b11 != 0b11 => inst.decode(isa).unwrap().control_flow(),
+            _ => inst.decode(isa).unwrap().control_flow(),
+        };
+        let rv64 = |inst: u32| control_flow(inst, Isa::Rv64);
+
+        // bne a0, a1, -8 / c.beqz a0, 8
+        assert_eq!(rv64(0xfeb5_1ce3), Some(ControlFlow::Branch { offset: -8 }));
+        assert_eq!(rv64(0xc501), Some(ControlFlow::Branch { offset: 8 }));
+        // jal zero, -4 / jal t0, 8 / jal t1, 8 / c.j 4 / c.jal 4 (rv32)
+        assert_eq!(rv64(0xffdf_f06f), Some(ControlFlow::Jump { offset: -4 }));
+        assert_eq!(
+            rv64(0x0080_02ef),
+            Some(ControlFlow::Call { offset: Some(8) })
+        );
+        assert_eq!(rv64(0x0080_036f), Some(ControlFlow::Jump { offset: 8 }));
+        assert_eq!(rv64(0xa011), Some(ControlFlow::Jump { offset: 4 }));
+        assert_eq!(
+            control_flow(0x2011, Isa::Rv32),
+            Some(ControlFlow::Call { offset: Some(4) })
+        );
+        // jalr zero, 0(ra) / jalr zero, 0(t0) / jalr zero, 0(a0)
+        assert_eq!(rv64(0x0000_8067), Some(ControlFlow::Return));
+        assert_eq!(rv64(0x0002_8067), Some(ControlFlow::Return));
+        assert_eq!(rv64(0x0005_0067), Some(ControlFlow::IndirectJump));
+        // jalr ra, 0(a0) / jalr ra, 0(ra) / jalr t0, 0(t0)
+        assert_eq!(rv64(0x0005_00e7), Some(ControlFlow::Call { offset: None }));
+        assert_eq!(rv64(0x0000_80e7), Some(ControlFlow::Call { offset: None }));
+        assert_eq!(rv64(0x0002_82e7), Some(ControlFlow::Call { offset: None }));
+        // jalr ra, 0(t0) / jalr t0, 0(ra)
+        assert_eq!(rv64(0x0002_80e7), Some(ControlFlow::CoroutineSwap));
+        assert_eq!(rv64(0x0000_82e7), Some(ControlFlow::CoroutineSwap));
+        // c.jr ra / c.jr a0 / c.jalr a0 / c.jalr t0
+        assert_eq!(rv64(0x8082), Some(ControlFlow::Return));
+        assert_eq!(rv64(0x8502), Some(ControlFlow::IndirectJump));
+        assert_eq!(rv64(0x9502), Some(ControlFlow::Call { offset: None }));
+        assert_eq!(rv64(0x9282), Some(ControlFlow::CoroutineSwap));
+        // ecall / c.ebreak / mret / sfence.vma zero, zero
+        assert_eq!(rv64(0x0000_0073), Some(ControlFlow::Trap));
+        assert_eq!(rv64(0x9002), Some(ControlFlow::Trap));
+        assert_eq!(rv64(0x3020_0073), Some(ControlFlow::TrapReturn));
+        assert_eq!(rv64(0x1200_0073), Some(ControlFlow::Fence));
+        // fence rw, rw / fence.tso / fence.i
+        assert_eq!(rv64(0x0330_000f), Some(ControlFlow::Fence));
+        assert_eq!(rv64(0x8330_000f), Some(ControlFlow::Fence));
+        assert_eq!(rv64(0x0000_100f), Some(ControlFlow::Fence));
+        // addi a0, a0, 1 / wfi / pause
+        assert_eq!(rv64(0x0015_0513), None);
+        assert_eq!(rv64(0x1050_0073), None);
+        assert_eq!(rv64(0x0100_000f), None);
+
+        assert_eq!(ControlFlow::Jump { offset: -4 }.offset(), Some(-4));
+        assert_eq!(ControlFlow::Return.offset(), None);
+    }
+}
@@ -0,0 +1,117 @@
+//! D extension Instruction.
+
+use super::{InstFormat, Opcode};
+use core::fmt::{self, Display, Formatter};
+
+/// Insturctions in D Extension.
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq)]
+pub enum DOpcode {
+    FLD,
+    FSD,
+    FMADD_D,
+    FMSUB_D,
+    FNMSUB_D,
+    FNMADD_D,
+    FADD_D,
+    FSUB_D,
+    FMUL_D,
+    FDIV_D,
+    FSQRT_D,
+    FSGNJ_D,
+    FSGNJN_D,
+    FSGNJX_D,
+    FMIN_D,
+    FMAX_D,
+    FCVT_S_D,
+    FCVT_D_S,
+    FEQ_D,
+    FLT_D,
+    FLE_D,
+    FCLASS_D,
+    FCVT_W_D,
+    FCVT_WU_D,
+    FCVT_D_W,
+    FCVT_D_WU,
+
+    //-- rv64 only --
+    FCVT_L_D,
+    FCVT_LU_D,
+    FMV_X_D,
+    FCVT_D_L,
+    FCVT_D_LU,
+    FMV_D_X,
+}
+
+impl Display for DOpcode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            DOpcode::FLD => write!(f, "fld"),
+            DOpcode::FSD => write!(f, "fsd"),
+            DOpcode::FMADD_D => write!(f, "fmadd.d"),
+            DOpcode::FMSUB_D => write!(f, "fmsub.d"),
+            DOpcode::FNMSUB_D => write!(f, "fnmsub.d"),
+            DOpcode::FNMADD_D => write!(f, "fnmadd.d"),
+            DOpcode::FADD_D => write!(f, "fadd.d"),
+            DOpcode::FSUB_D => write!(f, "fsub.d"),
+            DOpcode::FMUL_D => write!(f, "fmul.d"),
+            DOpcode::FDIV_D => write!(f, "fdiv.d"),
+            DOpcode::FSQRT_D => write!(f, "fsqrt.d"),
+            DOpcode::FSGNJ_D => write!(f, "fsgnj.d"),
+            DOpcode::FSGNJN_D => write!(f, "fsgnjn.d"),
+            DOpcode::FSGNJX_D => write!(f, "fsgnjx.d"),
+            DOpcode::FMIN_D => write!(f, "fmin.d"),
+            DOpcode::FMAX_D => write!(f, "fmax.d"),
+            DOpcode::FCVT_S_D => write!(f, "fcvt.s.d"),
+            DOpcode::FCVT_D_S => write!(f, "fcvt.d.s"),
+            DOpcode::FEQ_D => write!(f, "feq.d"),
+            DOpcode::FLT_D => write!(f, "flt.d"),
+            DOpcode::FLE_D => write!(f, "fle.d"),
+            DOpcode::FCLASS_D => write!(f, "fclass.d"),
+            DOpcode::FCVT_W_D => write!(f, "fcvt.w.d"),
+            DOpcode::FCVT_WU_D => write!(f, "fcvt.wu.d"),
+            DOpcode::FCVT_D_W => write!(f, "fcvt.d.w"),
+            DOpcode::FCVT_D_WU => write!(f, "fcvt.d.wu"),
+            DOpcode::FCVT_L_D => write!(f, "fcvt.l.d"),
+            DOpcode::FCVT_LU_D => write!(f, "fcvt.lu.d"),
+            DOpcode::FMV_X_D => write!(f, "fmv.x.d"),
+            DOpcode::FCVT_D_L => write!(f, "fcvt.d.l"),
+            DOpcode::FCVT_D_LU => write!(f, "fcvt.d.lu"),
+            DOpcode::FMV_D_X => write!(f, "fmv.d.x"),
+        }
+    }
+}
+
+impl Opcode for DOpcode {
+    fn get_format(&self) -> InstFormat {
+        match self {
+            DOpcode::FLD => InstFormat::FlFormat,
+            DOpcode::FSD => InstFormat::FsFormat,
+            DOpcode::FMADD_D | DOpcode::FMSUB_D | DOpcode::FNMSUB_D | DOpcode::FNMADD_D => {
+                InstFormat::R4Format
+            }
+            DOpcode::FADD_D
+            | DOpcode::FSUB_D
+            | DOpcode::FMUL_D
+            | DOpcode::FDIV_D
+            | DOpcode::FSGNJ_D
+            | DOpcode::FSGNJN_D
+            | DOpcode::FSGNJX_D
+            | DOpcode::FMIN_D
+            | DOpcode::FMAX_D => InstFormat::FrFormat,
+            DOpcode::FSQRT_D | DOpcode::FCVT_S_D | DOpcode::FCVT_D_S => InstFormat::FrUnaryFormat,
+            DOpcode::FEQ_D | DOpcode::FLT_D | DOpcode::FLE_D => InstFormat::FrCmpFormat,
+            DOpcode::FCLASS_D
+            | DOpcode::FCVT_W_D
+            | DOpcode::FCVT_WU_D
+            | DOpcode::FCVT_L_D
+            | DOpcode::FCVT_LU_D
+            | DOpcode::FMV_X_D => InstFormat::FrToXFormat,
+            DOpcode::FCVT_D_W
+            | DOpcode::FCVT_D_WU
+            | DOpcode::FCVT_D_L
+            | DOpcode::FCVT_D_LU
+            | DOpcode::FMV_D_X => InstFormat::XToFrFormat,
+        }
+    }
+}
@@ -0,0 +1,283 @@
+//! Registers read and written by instructions.
+
+use super::{
+    c_extension::COpcode, zicfiss_extension::ZicfissOpcode, zicsr_extension::Csr,
+    zicsr_extension::ZicsrOpcode, FReg, InstFormat, Instruction, OpcodeKind, Register, VReg, XReg,
+};
+
+/// Set of registers and CSRs.
+///
+/// `x0` is never included since it is hardwired to zero.
+///
+/// # Example
+/// ```
+/// use raki::{Decode, Isa, Register, XReg};
+///
+/// // c.addi16sp sp, -64
+/// let inst = 0x7139_u16.decode(Isa::Rv64).unwrap();
+/// assert!(inst.reads().contains(Register::X(XReg::SP)));
+/// assert!(inst.writes().contains(Register::X(XReg::SP)));
+///
+/// // c.lwsp a0, 12(sp)
+/// let inst = 0x4532_u16.decode(Isa::Rv64).unwrap();
+/// assert_eq!(
+///     inst.reads().registers().collect::<Vec<_>>(),
+///     vec![Register::X(XReg::SP)]
+/// );
+/// assert_eq!(
+///     inst.writes().registers().collect::<Vec<_>>(),
+///     vec![Register::X(XReg::A0)]
+/// );
+/// ```
+#[derive(Debug, Default, PartialEq, Eq, Clone, Copy)]
+pub struct RegisterSet {
+    x: u32,
+    f: u32,
+    v: u32,
+    csrs: [Option<Csr>; 2],
+}
+
+impl RegisterSet {
+    /// Whether the set contains `reg`.
+    #[must_use]
+    pub fn contains(&self, reg: Register) -> bool {
+        match reg {
+            Register::X(reg) => self.x >> reg.num() & 1 == 1,
+            Register::F(reg) => self.f >> reg.num() & 1 == 1,
+            Register::V(reg) => self.v >> reg.num() & 1 == 1,
+        }
+    }
+
+    /// Whether the set contains `csr`.
+    #[must_use]
+    pub fn contains_csr(&self, csr: Csr) -> bool {
+        self.csrs.contains(&Some(csr))
+    }
+
+    /// Whether the set contains neither registers nor CSRs.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        *self == RegisterSet::default()
+    }
+
+    /// Registers in the order of integer, floating-point and vector registers.
+    pub fn registers(&self) -> impl Iterator<Item = Register> {
+        let bits = |set: u32| (0..32).filter(move |num| set >> num & 1 == 1);
+        bits(self.x)
+            .filter_map(|num| XReg::new(num).map(Register::X))
+            .chain(bits(self.f).filter_map(|num| FReg::new(num).map(Register::F)))
+            .chain(bits(self.v).filter_map(|num| VReg::new(num).map(Register::V)))
+    }
+
+    /// CSRs.
+    pub fn csrs(&self) -> impl Iterator<Item = Csr> {
+        self.csrs.into_iter().flatten()
+    }
+
+    fn insert(&mut self, reg: Option<Register>) {
+        match reg {
+            Some(Register::X(reg)) if reg != XReg::ZERO => self.x |= 1 << reg.num(),
+            Some(Register::F(reg)) => self.f |= 1 << reg.num(),
+            Some(Register::V(reg)) => self.v |= 1 << reg.num(),
+            _ => (),
+        }
+    }
+
+    fn insert_csr(&mut self, csr: Option<Csr>) {
+        if let (Some(csr), Some(slot)) = (csr, self.csrs.iter_mut().find(|slot| slot.is_none())) {
+            *slot = Some(csr);
+        }
+    }
+}
+
+/// How a CSR is accessed (read, write).
+fn csr_access(inst: &Instruction) -> (bool, bool) {
+    let nonzero = |field: Option<usize>| field.is_some_and(|num| num != 0);
+    match inst.opc {
+        OpcodeKind::Zicsr(ZicsrOpcode::CSRRW | ZicsrOpcode::CSRRWI) => (nonzero(inst.rd), true),
+        OpcodeKind::Zicsr(ZicsrOpcode::CSRRS | ZicsrOpcode::CSRRC) => (true, nonzero(inst.rs1)),
+        OpcodeKind::Zicsr(ZicsrOpcode::CSRRSI | ZicsrOpcode::CSRRCI) => {
+            (true, inst.imm.is_some_and(|uimm| uimm != 0))
+        }
+        OpcodeKind::Zicntr(_) => (true, false),
+        _ => (false, false),
+    }
+}
+
+impl Instruction {
+    /// Registers and CSRs read by the instruction, including implicit operands
+    /// (e.g. `sp` of `c.lwsp`, `v0` of masked vector instructions, `ssp` of `sspush`).
+    ///
+    /// Vector register groups (LMUL > 1) are represented by their first register,
+    /// and the floating-point and vector state CSRs (`fcsr`, `vl`, `vtype`) are not included.
+    #[must_use]
+    pub fn reads(&self) -> RegisterSet {
+        let mut set = RegisterSet::default();
+        let x = |reg: XReg| Some(Register::X(reg));
+        set.insert(self.rs1_reg());
+        set.insert(self.rs2_reg());
+        set.insert(self.rs3_reg());
+
+        match (&self.inst_format, &self.opc) {
+            (
+                InstFormat::CiwFormat
+                | InstFormat::CssFormat
+                | InstFormat::CflspFormat
+                | InstFormat::CfsspFormat,
+                _,
+            )
+            | (_, OpcodeKind::C(COpcode::LWSP | COpcode::LDSP)) => set.insert(x(XReg::SP)),
+            // multiply-add instructions accumulate into vd
+            (InstFormat::VmaVvFormat | InstFormat::VmaVxFormat | InstFormat::VmaVfFormat, _) => {
+                set.insert(self.rd_reg());
+            }
+            _ => (),
+        }
+        if self.vector_mask().is_some() {
+            set.insert(VReg::new(0).map(Register::V));
+        }
+
+        if csr_access(self).0 {
+            set.insert_csr(self.csr());
+        }
+        match self.opc {
+            OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPUSH) => set.insert(x(XReg::RA)),
+            OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPOPCHK) => set.insert(x(XReg::T0)),
+            _ => (),
+        }
+        if let OpcodeKind::Zicfiss(
+            ZicfissOpcode::SSPUSH
+            | ZicfissOpcode::SSPOPCHK
+            | ZicfissOpcode::SSRDP
+            | ZicfissOpcode::C_SSPUSH
+            | ZicfissOpcode::C_SSPOPCHK,
+        ) = self.opc
+        {
+            set.insert_csr(Some(Csr::SSP));
+        }
+        set
+    }
+
+    /// Registers and CSRs written by the instruction, including implicit operands
+    /// (e.g. `ra` of `c.jalr`, `ssp` of `sspush`). Writes to `x0` are excluded.
+    ///
+    /// Vector register groups (LMUL > 1) are represented by their first register,
+    /// and the floating-point and vector state CSRs (`fcsr`, `vl`, `vtype`) are not included.
+    #[must_use]
+    pub fn writes(&self) -> RegisterSet {
+        let mut set = RegisterSet::default();
+        set.insert(self.rd_reg());
+
+        if let OpcodeKind::C(COpcode::JAL | COpcode::JALR) = self.opc {
+            set.insert(Some(Register::X(XReg::RA)));
+        }
+        if csr_access(self).1 {
+            set.insert_csr(self.csr());
+        }
+        if let OpcodeKind::Zicfiss(
+            ZicfissOpcode::SSPUSH
+            | ZicfissOpcode::SSPOPCHK
+            | ZicfissOpcode::C_SSPUSH
+            | ZicfissOpcode::C_SSPOPCHK,
+        ) = self.opc
+        {
+            set.insert_csr(Some(Csr::SSP));
+        }
+        set
+    }
+}
+
+#[cfg(test)]
+mod test_def_use {
+    #[test]
+    fn def_use_test() {
+        use crate::{Csr, Decode, FReg, Instruction, Isa, Register, VReg, XReg};
+
+        let decode = |inst: u32| -> Instruction {
+            match u16::try_from(inst) {
+                Ok(inst) if inst & 0b11 != 0b11 => inst.decode(Isa::Rv64).unwrap(),
+                _ => inst.decode(Isa::Rv64).unwrap(),
+            }
+        };
+        let reads = |inst: u32| decode(inst).reads().registers().collect::<Vec<_>>();
+        let writes = |inst: u32| decode(inst).writes().registers().collect::<Vec<_>>();
+        let x = |num| Register::X(XReg::new(num).unwrap());
+        let f = |num| Register::F(FReg::new(num).unwrap());
+        let v = |num| Register::V(VReg::new(num).unwrap());
+
+        // addi a0, zero, 1 / sw a1, 4(a0) / beq a0, a1, 8
+        assert_eq!(
+            (reads(0x0010_0513), writes(0x0010_0513)),
+            (vec![], vec![x(10)])
+        );
+        assert_eq!(
+            (reads(0x00b5_2223), writes(0x00b5_2223)),
+            (vec![x(10), x(11)], vec![])
+        );
+        assert_eq!(reads(0x00b5_0463), vec![x(10), x(11)]);
+        // jal zero, 8 / jalr ra, 0(a0)
+        assert!(decode(0x0080_006f).writes().is_empty());
+        assert_eq!(
+            (reads(0x0005_00e7), writes(0x0005_00e7)),
+            (vec![x(10)], vec![x(1)])
+        );
+        // c.addi a0, 1 / c.sub a0, a1 / c.mv a0, a1 / c.li a0, 1
+        assert_eq!((reads(0x0505), writes(0x0505)), (vec![x(10)], vec![x(10)]));
+        assert_eq!(
+            (reads(0x8d0d), writes(0x8d0d)),
+            (vec![x(10), x(11)], vec![x(10)])
+        );
+        assert_eq!((reads(0x852e), writes(0x852e)), (vec![x(11)], vec![x(10)]));
+        assert_eq!((reads(0x4505), writes(0x4505)), (vec![], vec![x(10)]));
+        // c.addi4spn a0, sp, 16 / c.swsp a0, 12(sp) / c.jalr a0 / c.jr ra
+        assert_eq!((reads(0x0808), writes(0x0808)), (vec![x(2)], vec![x(10)]));
+        assert_eq!((reads(0xc62a), writes(0xc62a)), (vec![x(2), x(10)], vec![]));
+        assert_eq!((reads(0x9502), writes(0x9502)), (vec![x(10)], vec![x(1)]));
+        assert_eq!((reads(0x8082), writes(0x8082)), (vec![x(1)], vec![]));
+        // fmadd.s fa0, fa1, fa2, fa3 / feq.s a0, fa0, fa1
+        assert_eq!(reads(0x68c5_9543), vec![f(11), f(12), f(13)]);
+        assert_eq!(
+            (reads(0xa0b5_2553), writes(0xa0b5_2553)),
+            (vec![f(10), f(11)], vec![x(10)])
+        );
+        // vadd.vx v8, v9, a0, v0.t / vmacc.vv v8, v9, v10 / vse8.v v8, (a0)
+        assert_eq!(reads(0x0095_4457), vec![x(10), v(0), v(9)]);
+        assert_eq!(reads(0xb695_2457), vec![v(8), v(9), v(10)]);
+        assert_eq!(
+            (reads(0x0205_0427), writes(0x0205_0427)),
+            (vec![x(10), v(8)], vec![])
+        );
+        // amoadd.w a0, a2, (a1)
+        assert_eq!(
+            (reads(0x00c5_a52f), writes(0x00c5_a52f)),
+            (vec![x(11), x(12)], vec![x(10)])
+        );
+
+        // csrrs a0, mscratch, a1 / csrrs a0, mscratch, zero / csrrw zero, mscratch, a1
+        let inst = decode(0x3405_a573);
+        assert!(inst.reads().contains_csr(Csr::MSCRATCH));
+        assert!(inst.writes().contains_csr(Csr::MSCRATCH));
+        assert!(!decode(0x3400_2573).writes().contains_csr(Csr::MSCRATCH));
+        let inst = decode(0x3405_9073);
+        assert!(!inst.reads().contains_csr(Csr::MSCRATCH));
+        assert!(inst.writes().contains_csr(Csr::MSCRATCH));
+        assert!(inst.writes().registers().next().is_none());
+        // rdcycle a0
+        assert_eq!(
+            decode(0xc000_2573).reads().csrs().collect::<Vec<_>>(),
+            vec![Csr::CYCLE]
+        );
+
+        // sspush ra / sspopchk t0 / ssrdp a0 / c.sspush ra
+        let inst = decode(0xce10_4073);
+        assert_eq!(inst.reads().registers().collect::<Vec<_>>(), vec![x(1)]);
+        assert!(inst.reads().contains_csr(Csr::SSP) && inst.writes().contains_csr(Csr::SSP));
+        assert!(inst.writes().registers().next().is_none());
+        assert_eq!(reads(0xcdc2_c073), vec![x(5)]);
+        let inst = decode(0xcdc0_4573);
+        assert_eq!(writes(0xcdc0_4573), vec![x(10)]);
+        assert!(!inst.writes().contains_csr(Csr::SSP));
+        assert_eq!((reads(0x6081), writes(0x6081)), (vec![x(1)], vec![]));
+        assert!(decode(0x6081).writes().contains_csr(Csr::SSP));
+    }
+}
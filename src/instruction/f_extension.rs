@@ -0,0 +1,176 @@
+//! F extension Instruction.
+
+use super::{InstFormat, Opcode};
+use core::fmt::{self, Display, Formatter};
+
+/// Insturctions in F Extension.
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq)]
+pub enum FOpcode {
+    FLW,
+    FSW,
+    FMADD_S,
+    FMSUB_S,
+    FNMSUB_S,
+    FNMADD_S,
+    FADD_S,
+    FSUB_S,
+    FMUL_S,
+    FDIV_S,
+    FSQRT_S,
+    FSGNJ_S,
+    FSGNJN_S,
+    FSGNJX_S,
+    FMIN_S,
+    FMAX_S,
+    FCVT_W_S,
+    FCVT_WU_S,
+    FMV_X_W,
+    FEQ_S,
+    FLT_S,
+    FLE_S,
+    FCLASS_S,
+    FCVT_S_W,
+    FCVT_S_WU,
+    FMV_W_X,
+
+    //-- rv64 only --
+    FCVT_L_S,
+    FCVT_LU_S,
+    FCVT_S_L,
+    FCVT_S_LU,
+}
+
+impl Display for FOpcode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            FOpcode::FLW => write!(f, "flw"),
+            FOpcode::FSW => write!(f, "fsw"),
+            FOpcode::FMADD_S => write!(f, "fmadd.s"),
+            FOpcode::FMSUB_S => write!(f, "fmsub.s"),
+            FOpcode::FNMSUB_S => write!(f, "fnmsub.s"),
+            FOpcode::FNMADD_S => write!(f, "fnmadd.s"),
+            FOpcode::FADD_S => write!(f, "fadd.s"),
+            FOpcode::FSUB_S => write!(f, "fsub.s"),
+            FOpcode::FMUL_S => write!(f, "fmul.s"),
+            FOpcode::FDIV_S => write!(f, "fdiv.s"),
+            FOpcode::FSQRT_S => write!(f, "fsqrt.s"),
+            FOpcode::FSGNJ_S => write!(f, "fsgnj.s"),
+            FOpcode::FSGNJN_S => write!(f, "fsgnjn.s"),
+            FOpcode::FSGNJX_S => write!(f, "fsgnjx.s"),
+            FOpcode::FMIN_S => write!(f, "fmin.s"),
+            FOpcode::FMAX_S => write!(f, "fmax.s"),
+            FOpcode::FCVT_W_S => write!(f, "fcvt.w.s"),
+            FOpcode::FCVT_WU_S => write!(f, "fcvt.wu.s"),
+            FOpcode::FMV_X_W => write!(f, "fmv.x.w"),
+            FOpcode::FEQ_S => write!(f, "feq.s"),
+            FOpcode::FLT_S => write!(f, "flt.s"),
+            FOpcode::FLE_S => write!(f, "fle.s"),
+            FOpcode::FCLASS_S => write!(f, "fclass.s"),
+            FOpcode::FCVT_S_W => write!(f, "fcvt.s.w"),
+            FOpcode::FCVT_S_WU => write!(f, "fcvt.s.wu"),
+            FOpcode::FMV_W_X => write!(f, "fmv.w.x"),
+            FOpcode::FCVT_L_S => write!(f, "fcvt.l.s"),
+            FOpcode::FCVT_LU_S => write!(f, "fcvt.lu.s"),
+            FOpcode::FCVT_S_L => write!(f, "fcvt.s.l"),
+            FOpcode::FCVT_S_LU => write!(f, "fcvt.s.lu"),
+        }
+    }
+}
+
+impl Opcode for FOpcode {
+    fn get_format(&self) -> InstFormat {
+        match self {
+            FOpcode::FLW => InstFormat::FlFormat,
+            FOpcode::FSW => InstFormat::FsFormat,
+            FOpcode::FMADD_S | FOpcode::FMSUB_S | FOpcode::FNMSUB_S | FOpcode::FNMADD_S => {
+                InstFormat::R4Format
+            }
+            FOpcode::FADD_S
+            | FOpcode::FSUB_S
+            | FOpcode::FMUL_S
+            | FOpcode::FDIV_S
+            | FOpcode::FSGNJ_S
+            | FOpcode::FSGNJN_S
+            | FOpcode::FSGNJX_S
+            | FOpcode::FMIN_S
+            | FOpcode::FMAX_S => InstFormat::FrFormat,
+            FOpcode::FSQRT_S => InstFormat::FrUnaryFormat,
+            FOpcode::FEQ_S | FOpcode::FLT_S | FOpcode::FLE_S => InstFormat::FrCmpFormat,
+            FOpcode::FCVT_W_S
+            | FOpcode::FCVT_WU_S
+            | FOpcode::FMV_X_W
+            | FOpcode::FCLASS_S
+            | FOpcode::FCVT_L_S
+            | FOpcode::FCVT_LU_S => InstFormat::FrToXFormat,
+            FOpcode::FCVT_S_W
+            | FOpcode::FCVT_S_WU
+            | FOpcode::FMV_W_X
+            | FOpcode::FCVT_S_L
+            | FOpcode::FCVT_S_LU => InstFormat::XToFrFormat,
+        }
+    }
+}
+
+/// Rounding mode of floating-point instructions.
+/// It is stored in `Instruction.rm`.
+///
+/// See: The RISC-V Instruction Set Manual Volume I, Table 20.1
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RoundingMode {
+    /// Round to Nearest, ties to Even
+    RNE,
+    /// Round towards Zero
+    RTZ,
+    /// Round Down (towards -inf)
+    RDN,
+    /// Round Up (towards +inf)
+    RUP,
+    /// Round to Nearest, ties to Max Magnitude
+    RMM,
+    /// Dynamic rounding mode (In instruction's rm field, selects dynamic rounding mode)
+    DYN,
+}
+
+impl RoundingMode {
+    /// Convert the raw `rm` field into `RoundingMode`.
+    /// It returns `None` if the field is reserved.
+    #[must_use]
+    pub fn from_rm(rm: i32) -> Option<Self> {
+        match rm {
+            0b000 => Some(RoundingMode::RNE),
+            0b001 => Some(RoundingMode::RTZ),
+            0b010 => Some(RoundingMode::RDN),
+            0b011 => Some(RoundingMode::RUP),
+            0b100 => Some(RoundingMode::RMM),
+            0b111 => Some(RoundingMode::DYN),
+            _ => None,
+        }
+    }
+
+    /// Convert `RoundingMode` into the raw `rm` field.
+    #[must_use]
+    pub fn to_rm(self) -> u32 {
+        match self {
+            RoundingMode::RNE => 0b000,
+            RoundingMode::RTZ => 0b001,
+            RoundingMode::RDN => 0b010,
+            RoundingMode::RUP => 0b011,
+            RoundingMode::RMM => 0b100,
+            RoundingMode::DYN => 0b111,
+        }
+    }
+}
+
+impl Display for RoundingMode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            RoundingMode::RNE => write!(f, "rne"),
+            RoundingMode::RTZ => write!(f, "rtz"),
+            RoundingMode::RDN => write!(f, "rdn"),
+            RoundingMode::RUP => write!(f, "rup"),
+            RoundingMode::RMM => write!(f, "rmm"),
+            RoundingMode::DYN => write!(f, "dyn"),
+        }
+    }
+}
@@ -0,0 +1,314 @@
+//! Memory accesses of loads, stores and atomic instructions.
+
+use super::{
+    a_extension::AOpcode, base_i::BaseIOpcode, c_extension::COpcode, d_extension::DOpcode,
+    f_extension::FOpcode, q_extension::QOpcode, zicboz_extension::ZicbozOpcode,
+    zicfiss_extension::ZicfissOpcode, Instruction, OpcodeKind, Operand, XReg,
+};
+
+/// Kind of a memory access.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AccessKind {
+    /// Load (e.g. `lw`, `c.ldsp`, `flw`)
+    Load,
+    /// Store (e.g. `sw`, `c.sdsp`, `fsw`)
+    Store,
+    /// Load-reserved (`lr.w`, `lr.d`)
+    LoadReserved,
+    /// Store-conditional (`sc.w`, `sc.d`)
+    StoreConditional,
+    /// Atomic read-modify-write (e.g. `amoadd.w`, `ssamoswap.d`)
+    Amo,
+    /// Zero a whole cache block (`cbo.zero`)
+    CacheBlockZero,
+}
+
+impl AccessKind {
+    /// Whether the access reads memory.
+    #[must_use]
+    pub fn is_read(self) -> bool {
+        matches!(
+            self,
+            AccessKind::Load | AccessKind::LoadReserved | AccessKind::Amo
+        )
+    }
+
+    /// Whether the access writes memory.
+    #[must_use]
+    pub fn is_write(self) -> bool {
+        !matches!(self, AccessKind::Load | AccessKind::LoadReserved)
+    }
+}
+
+/// Width of a memory access.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum AccessWidth {
+    /// 1 byte
+    Byte,
+    /// 2 bytes
+    Half,
+    /// 4 bytes
+    Word,
+    /// 8 bytes
+    Double,
+    /// 16 bytes
+    Quad,
+    /// Cache block (the size is implementation-defined)
+    CacheBlock,
+}
+
+impl AccessWidth {
+    /// Size in bytes, or `None` for `CacheBlock`.
+    #[must_use]
+    pub fn bytes(self) -> Option<u32> {
+        match self {
+            AccessWidth::Byte => Some(1),
+            AccessWidth::Half => Some(2),
+            AccessWidth::Word => Some(4),
+            AccessWidth::Double => Some(8),
+            AccessWidth::Quad => Some(16),
+            AccessWidth::CacheBlock => None,
+        }
+    }
+}
+
+/// Memory access of an instruction.
+///
+/// The effective address is `base + offset`.
+/// For `cbo.zero`, it is rounded down to the cache block size.
+///
+/// # Example
+/// ```
+/// use raki::{AccessKind, AccessWidth, Decode, Isa, XReg};
+///
+/// // lbu a0, -1(a1)
+/// let inst = 0xfff5_c503_u32.decode(Isa::Rv64).unwrap();
+/// let access = inst.memory_access().unwrap();
+/// assert_eq!(access.kind, AccessKind::Load);
+/// assert_eq!((access.base, access.offset), (XReg::A1, -1));
+/// assert_eq!(access.width, AccessWidth::Byte);
+/// assert!(!access.signed);
+///
+/// // amoswap.w.aq a0, a2, (a1)
+/// let inst = 0x0cc5_a52f_u32.decode(Isa::Rv64).unwrap();
+/// let access = inst.memory_access().unwrap();
+/// assert_eq!(access.kind, AccessKind::Amo);
+/// assert!(access.aq && !access.rl);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct MemoryAccess {
+    /// Kind of the access
+    pub kind: AccessKind,
+    /// Base address register
+    pub base: XReg,
+    /// Offset from the base address
+    pub offset: i32,
+    /// Width of the access
+    pub width: AccessWidth,
+    /// Whether the loaded value is sign-extended to XLEN (`false` for stores and floating-point loads)
+    pub signed: bool,
+    /// Acquire ordering
+    pub aq: bool,
+    /// Release ordering
+    pub rl: bool,
+}
+
+impl Instruction {
+    /// Memory access of loads, stores, atomic instructions and `cbo.zero`,
+    /// or `None` for the other instructions.
+    ///
+    /// Vector, hypervisor and shadow-stack push/pop accesses are not described.
+    #[must_use]
+    pub fn memory_access(&self) -> Option<MemoryAccess> {
+        use AccessKind::{Amo, CacheBlockZero, Load, LoadReserved, Store, StoreConditional};
+        use AccessWidth::{Byte, CacheBlock, Double, Half, Quad, Word};
+
+        let (kind, width, signed) = match &self.opc {
+            OpcodeKind::BaseI(opc) => match opc {
+                BaseIOpcode::LB => (Load, Byte, true),
+                BaseIOpcode::LH => (Load, Half, true),
+                BaseIOpcode::LW => (Load, Word, true),
+                BaseIOpcode::LD => (Load, Double, true),
+                BaseIOpcode::LBU => (Load, Byte, false),
+                BaseIOpcode::LHU => (Load, Half, false),
+                BaseIOpcode::LWU => (Load, Word, false),
+                BaseIOpcode::SB => (Store, Byte, false),
+                BaseIOpcode::SH => (Store, Half, false),
+                BaseIOpcode::SW => (Store, Word, false),
+                BaseIOpcode::SD => (Store, Double, false),
+                _ => return None,
+            },
+            OpcodeKind::C(opc) => match opc {
+                COpcode::LW | COpcode::LWSP => (Load, Word, true),
+                COpcode::LD | COpcode::LDSP => (Load, Double, true),
+                COpcode::FLW | COpcode::FLWSP => (Load, Word, false),
+                COpcode::FLD | COpcode::FLDSP => (Load, Double, false),
+                COpcode::SW | COpcode::SWSP | COpcode::FSW | COpcode::FSWSP => (Store, Word, false),
+                COpcode::SD | COpcode::SDSP | COpcode::FSD | COpcode::FSDSP => {
+                    (Store, Double, false)
+                }
+                _ => return None,
+            },
+            OpcodeKind::F(FOpcode::FLW) => (Load, Word, false),
+            OpcodeKind::F(FOpcode::FSW) => (Store, Word, false),
+            OpcodeKind::D(DOpcode::FLD) => (Load, Double, false),
+            OpcodeKind::D(DOpcode::FSD) => (Store, Double, false),
+            OpcodeKind::Q(QOpcode::FLQ) => (Load, Quad, false),
+            OpcodeKind::Q(QOpcode::FSQ) => (Store, Quad, false),
+            OpcodeKind::A(opc) => match opc {
+                AOpcode::LR_W => (LoadReserved, Word, true),
+                AOpcode::LR_D => (LoadReserved, Double, true),
+                AOpcode::SC_W => (StoreConditional, Word, false),
+                AOpcode::SC_D => (StoreConditional, Double, false),
+                AOpcode::AMOSWAP_W
+                | AOpcode::AMOADD_W
+                | AOpcode::AMOXOR_W
+                | AOpcode::AMOAND_W
+                | AOpcode::AMOOR_W
+                | AOpcode::AMOMIN_W
+                | AOpcode::AMOMAX_W
+                | AOpcode::AMOMINU_W
+                | AOpcode::AMOMAXU_W => (Amo, Word, true),
+                AOpcode::AMOSWAP_D
+                | AOpcode::AMOADD_D
+                | AOpcode::AMOXOR_D
+                | AOpcode::AMOAND_D
+                | AOpcode::AMOOR_D
+                | AOpcode::AMOMIN_D
+                | AOpcode::AMOMAX_D
+                | AOpcode::AMOMINU_D
+                | AOpcode::AMOMAXU_D => (Amo, Double, true),
+            },
+            OpcodeKind::Zicfiss(ZicfissOpcode::SSAMOSWAP_W) => (Amo, Word, true),
+            OpcodeKind::Zicfiss(ZicfissOpcode::SSAMOSWAP_D) => (Amo, Double, true),
+            OpcodeKind::Zicboz(ZicbozOpcode::CBO_ZERO) => (CacheBlockZero, CacheBlock, false),
+            _ => return None,
+        };
+
+        let (aq, rl) = self
+            .operands()
+            .find_map(|operand| match operand {
+                Operand::Ordering { aq, rl } => Some((aq, rl)),
+                _ => None,
+            })
+            .unwrap_or((false, false));
+        let offset = match kind {
+            Load | Store => self.imm.unwrap_or(0),
+            _ => 0,
+        };
+        // sp-relative compressed loads/stores have no rs1 field.
+        let base = match self.rs1 {
+            Some(rs1) => XReg::new(rs1)?,
+            None => XReg::SP,
+        };
+
+        Some(MemoryAccess {
+            kind,
+            base,
+            offset,
+            width,
+            signed,
+            aq,
+            rl,
+        })
+    }
+}
+
+#[cfg(test)]
+mod test_memory {
+    #[test]
+    fn memory_access_test() {
+        use super::{AccessKind, AccessWidth, MemoryAccess};
+        use crate::{Decode, Isa, XReg};
+
+        let memory_access = |inst: u32| match u16::try_from(inst) {
+            Ok(inst) if inst & 0b11 != 0b11 => inst.decode(Isa::Rv64).unwrap().memory_access(),
+            _ => inst.decode(Isa::Rv64).unwrap().memory_access(),
+        };
+        let access = |kind, base, offset, width, signed| {
+            Some(MemoryAccess {
+                kind,
+                base,
+                offset,
+                width,
+                signed,
+                aq: false,
+                rl: false,
+            })
+        };
+
+        // ld a0, -8(sp) / sh a1, 6(a0) / fsd fa0, 16(a0)
+        assert_eq!(
+            memory_access(0xff81_3503),
+            access(AccessKind::Load, XReg::SP, -8, AccessWidth::Double, true)
+        );
+        assert_eq!(
+            memory_access(0x00b5_1323),
+            access(AccessKind::Store, XReg::A0, 6, AccessWidth::Half, false)
+        );
+        assert_eq!(
+            memory_access(0x00a5_3827),
+            access(AccessKind::Store, XReg::A0, 16, AccessWidth::Double, false)
+        );
+        // c.lw a0, 4(a1) / c.lwsp a0, 12(sp) / c.sdsp a0, 8(sp) / c.fld fa0, 8(a1)
+        assert_eq!(
+            memory_access(0x41c8),
+            access(AccessKind::Load, XReg::A1, 4, AccessWidth::Word, true)
+        );
+        assert_eq!(
+            memory_access(0x4532),
+            access(AccessKind::Load, XReg::SP, 12, AccessWidth::Word, true)
+        );
+        assert_eq!(
+            memory_access(0xe42a),
+            access(AccessKind::Store, XReg::SP, 8, AccessWidth::Double, false)
+        );
+        assert_eq!(
+            memory_access(0x2588),
+            access(AccessKind::Load, XReg::A1, 8, AccessWidth::Double, false)
+        );
+        // lr.d a1, (a0) / sc.w.rl a0, a2, (a1) / amoadd.w.aqrl a0, a2, (a1)
+        assert_eq!(
+            memory_access(0x1005_35af),
+            access(
+                AccessKind::LoadReserved,
+                XReg::A0,
+                0,
+                AccessWidth::Double,
+                true
+            )
+        );
+        let sc = memory_access(0x1ac5_a52f).unwrap();
+        assert_eq!(
+            (sc.kind, sc.base, sc.aq, sc.rl),
+            (AccessKind::StoreConditional, XReg::A1, false, true)
+        );
+        let amo = memory_access(0x06c5_a52f).unwrap();
+        assert_eq!((amo.kind, amo.aq, amo.rl), (AccessKind::Amo, true, true));
+        // ssamoswap.d.aq a0, a2, (a1) / ssamoswap.w.aqrl a0, a2, (a1)
+        let amo = memory_access(0x4cc5_b52f).unwrap();
+        assert_eq!(
+            (amo.kind, amo.base, amo.width, amo.aq, amo.rl),
+            (AccessKind::Amo, XReg::A1, AccessWidth::Double, true, false)
+        );
+        let amo = memory_access(0x4ec5_a52f).unwrap();
+        assert_eq!(
+            (amo.kind, amo.width, amo.aq, amo.rl),
+            (AccessKind::Amo, AccessWidth::Word, true, true)
+        );
+        // cbo.zero (a0)
+        let cbo = memory_access(0x0045_200f).unwrap();
+        assert_eq!(
+            (cbo.kind, cbo.base, cbo.width.bytes()),
+            (AccessKind::CacheBlockZero, XReg::A0, None)
+        );
+        assert!(cbo.kind.is_write() && !cbo.kind.is_read());
+        // addi a0, a0, 1 / sspush ra
+        assert_eq!(memory_access(0x0015_0513), None);
+        assert_eq!(memory_access(0xce10_4073), None);
+
+        assert!(AccessKind::Amo.is_read() && AccessKind::Amo.is_write());
+        assert!(!AccessKind::StoreConditional.is_read());
+        assert_eq!(AccessWidth::Quad.bytes(), Some(16));
+    }
+}
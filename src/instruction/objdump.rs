@@ -0,0 +1,391 @@
+//! GNU objdump compatible formatting.
+
+use core::fmt::{self, Display, Formatter, Write};
+
+use super::{
+    a_extension::AOpcode,
+    base_i::{BaseIOpcode, Fence},
+    c_extension::COpcode,
+    d_extension::DOpcode,
+    f_extension::RoundingMode,
+    q_extension::QOpcode,
+    v_extension::Vtype,
+    zicboz_extension::ZicbozOpcode,
+    zicfiss_extension::ZicfissOpcode,
+    zicsr_extension::Csr,
+    FormatOptions, InstFormat, Instruction, OpcodeKind,
+};
+
+/// Instruction formatted like `objdump -d -M no-aliases`.
+///
+/// Operands are separated by `,` without spaces and the mnemonic is followed by a tab.
+/// Branch/jump offsets are printed relative to the pc (use `Symbolizer` for absolute targets).
+///
+/// # Example
+/// ```
+/// use raki::{Decode, FormatOptions, Isa};
+///
+/// // c.addi a0, 1
+/// let inst = 0x0505_u16.decode(Isa::Rv64).unwrap();
+/// assert_eq!(inst.objdump().to_string(), "c.addi\ta0,1");
+///
+/// // lw a0, 8(sp)
+/// let inst = 0x0081_2503_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(inst.objdump().to_string(), "lw\ta0,8(sp)");
+/// assert_eq!(format!("{:#}", inst.objdump()), "lw\tx10,8(x2)");
+///
+/// // addi s0, sp, 16
+/// let inst = 0x0101_0413_u32.decode(Isa::Rv64).unwrap();
+/// let opts = FormatOptions::new().with_fp(true);
+/// assert_eq!(inst.objdump().with_options(&opts).to_string(), "addi\tfp,sp,16");
+/// ```
+pub struct Objdump<'a> {
+    inst: &'a Instruction,
+    opts: FormatOptions,
+}
+
+impl Instruction {
+    /// Format the instruction like GNU `objdump -d -M no-aliases`.
+    ///
+    /// The alternate flag (`{:#}`) prints raw register names like `-M numeric`.
+    #[must_use]
+    pub fn objdump(&self) -> Objdump<'_> {
+        Objdump {
+            inst: self,
+            opts: FormatOptions::new(),
+        }
+    }
+}
+
+impl Objdump<'_> {
+    /// Name registers as `opts` does.
+    ///
+    /// Separators, immediates and pseudo-instructions keep the objdump layout.
+    #[must_use]
+    pub fn with_options(self, opts: &FormatOptions) -> Self {
+        Objdump {
+            opts: *opts,
+            ..self
+        }
+    }
+}
+
+impl Display for Objdump<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let opts = if f.alternate() {
+            self.opts.with_numeric_names(true)
+        } else {
+            self.opts
+        };
+        fmt_objdump(self.inst, f, &opts, None)
+    }
+}
+
+/// Writer that prints lowercase characters without spaces (e.g. `C.addi`, `e32, m1`).
+pub(super) struct Compact<'a, 'b>(pub(super) &'a mut Formatter<'b>);
+
+impl Write for Compact<'_, '_> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        s.chars()
+            .filter(|c| *c != ' ')
+            .try_for_each(|c| self.0.write_char(c.to_ascii_lowercase()))
+    }
+}
+
+/// Print the branch/jump target without `0x`, or the pc-relative offset if the target is unknown.
+fn fmt_target(f: &mut Formatter, imm: i32, target: Option<u64>) -> fmt::Result {
+    match target {
+        Some(addr) => write!(f, "{addr:x}"),
+        None => write!(f, "{imm}"),
+    }
+}
+
+/// Print the instruction like `objdump -d -M no-aliases` with the register names of `opts`.
+/// `target` replaces the pc-relative offset of branches and jumps.
+#[allow(clippy::too_many_lines)]
+pub(crate) fn fmt_objdump(
+    inst: &Instruction,
+    f: &mut Formatter,
+    opts: &FormatOptions,
+    target: Option<u64>,
+) -> fmt::Result {
+    let x = |reg| opts.xreg(reg);
+    let fr = |reg| opts.freg(reg);
+    let v = |reg| opts.vreg(reg);
+    let (rd, rs1, rs2, rs3) = (
+        || inst.rd.unwrap(),
+        || inst.rs1.unwrap(),
+        || inst.rs2.unwrap(),
+        || inst.rs3.unwrap(),
+    );
+    let imm = || inst.imm.unwrap();
+
+    fmt_mnemonic(inst, f)?;
+    if matches!(inst.inst_format, InstFormat::NoOperand)
+        && !matches!(
+            inst.opc,
+            OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPUSH | ZicfissOpcode::C_SSPOPCHK)
+        )
+    {
+        return Ok(());
+    }
+    f.write_char('\t')?;
+
+    match inst.inst_format {
+        InstFormat::RFormat | InstFormat::MFormat => {
+            write!(f, "{},{},{}", x(rd()), x(rs1()), x(rs2()))
+        }
+        InstFormat::AFormat | InstFormat::ALrFormat => match inst.opc {
+            OpcodeKind::A(AOpcode::LR_W | AOpcode::LR_D) => write!(f, "{},({})", x(rd()), x(rs1())),
+            _ => write!(f, "{},{},({})", x(rd()), x(rs2()), x(rs1())),
+        },
+        InstFormat::RShamtFormat => write!(f, "{},{},{:#x}", x(rd()), x(rs1()), imm()),
+        InstFormat::RUnaryFormat => write!(f, "{},{}", x(rd()), x(rs1())),
+        InstFormat::IFormat => match inst.opc {
+            OpcodeKind::BaseI(
+                BaseIOpcode::LB
+                | BaseIOpcode::LH
+                | BaseIOpcode::LW
+                | BaseIOpcode::LBU
+                | BaseIOpcode::LHU
+                | BaseIOpcode::LWU
+                | BaseIOpcode::LD
+                | BaseIOpcode::JALR,
+            ) => write!(f, "{},{}({})", x(rd()), imm(), x(rs1())),
+            _ => write!(f, "{},{},{}", x(rd()), x(rs1()), imm()),
+        },
+        InstFormat::ClFormat => write!(f, "{},{}({})", x(rd()), imm(), x(rs1())),
+        InstFormat::SFormat | InstFormat::CsFormat => {
+            write!(f, "{},{}({})", x(rs2()), imm(), x(rs1()))
+        }
+        InstFormat::BFormat => {
+            write!(f, "{},{},", x(rs1()), x(rs2()))?;
+            fmt_target(f, imm(), target)
+        }
+        InstFormat::UFormat => write!(f, "{},{:#x}", x(rd()), imm() & 0xf_ffff),
+        InstFormat::JFormat => {
+            write!(f, "{},", x(rd()))?;
+            fmt_target(f, imm(), target)
+        }
+        InstFormat::CjFormat => fmt_target(f, imm(), target),
+        InstFormat::CiwFormat => write!(f, "{},{},{}", x(rd()), x(2), imm()),
+        InstFormat::CssFormat => write!(f, "{},{}({})", x(rs2()), imm(), x(2)),
+        InstFormat::CiFormat => match inst.opc {
+            OpcodeKind::C(COpcode::LUI) => write!(f, "{},{:#x}", x(rd()), (imm() >> 12) & 0xf_ffff),
+            OpcodeKind::C(COpcode::SLLI) => write!(f, "{},{:#x}", x(rd()), imm()),
+            OpcodeKind::C(COpcode::LWSP | COpcode::LDSP) => {
+                write!(f, "{},{}({})", x(rd()), imm(), x(2))
+            }
+            _ => write!(f, "{},{}", x(rd()), imm()),
+        },
+        InstFormat::CrFormat => match inst.opc {
+            OpcodeKind::C(COpcode::JR | COpcode::JALR) => write!(f, "{}", x(rs1())),
+            _ => write!(f, "{},{}", x(rd()), x(rs2())),
+        },
+        InstFormat::CaFormat => write!(f, "{},{}", x(rd()), x(rs2())),
+        InstFormat::CbFormat => match inst.opc {
+            OpcodeKind::C(COpcode::BEQZ | COpcode::BNEZ) => {
+                write!(f, "{},", x(rs1()))?;
+                fmt_target(f, imm(), target)
+            }
+            OpcodeKind::C(COpcode::SRLI | COpcode::SRAI) => write!(f, "{},{:#x}", x(rd()), imm()),
+            _ => write!(f, "{},{}", x(rd()), imm()),
+        },
+        InstFormat::CsrFormat => write!(f, "{},{},{}", x(rd()), Csr::from_field(rs2()), x(rs1())),
+        InstFormat::CsrUiFormat => {
+            write!(f, "{},{},{}", x(rd()), Csr::from_field(rs2()), imm())
+        }
+        InstFormat::OnlyRd => match &inst.opc {
+            OpcodeKind::Zicntr(_) => {
+                write!(f, "{},{},{}", x(rd()), inst.csr().unwrap(), x(0))
+            }
+            _ => write!(f, "{}", x(rd())),
+        },
+        InstFormat::OnlyRs1 => match inst.opc {
+            OpcodeKind::Zicboz(ZicbozOpcode::CBO_ZERO) => write!(f, "({})", x(rs1())),
+            _ => write!(f, "{}", x(rs1())),
+        },
+        InstFormat::OnlyRs2 => write!(f, "{}", x(rs2())),
+        InstFormat::FlFormat | InstFormat::CflFormat => {
+            write!(f, "{},{}({})", fr(rd()), imm(), x(rs1()))
+        }
+        InstFormat::FsFormat | InstFormat::CfsFormat => {
+            write!(f, "{},{}({})", fr(rs2()), imm(), x(rs1()))
+        }
+        InstFormat::CflspFormat => write!(f, "{},{}({})", fr(rd()), imm(), x(2)),
+        InstFormat::CfsspFormat => write!(f, "{},{}({})", fr(rs2()), imm(), x(2)),
+        InstFormat::R4Format => {
+            write!(f, "{},{},{},{}", fr(rd()), fr(rs1()), fr(rs2()), fr(rs3()))?;
+            fmt_rounding_mode(inst, f)
+        }
+        InstFormat::FrFormat => {
+            write!(f, "{},{},{}", fr(rd()), fr(rs1()), fr(rs2()))?;
+            fmt_rounding_mode(inst, f)
+        }
+        InstFormat::FrUnaryFormat => {
+            write!(f, "{},{}", fr(rd()), fr(rs1()))?;
+            fmt_rounding_mode(inst, f)
+        }
+        InstFormat::FrCmpFormat => write!(f, "{},{},{}", x(rd()), fr(rs1()), fr(rs2())),
+        InstFormat::FrToXFormat => {
+            write!(f, "{},{}", x(rd()), fr(rs1()))?;
+            fmt_rounding_mode(inst, f)
+        }
+        InstFormat::XToFrFormat => {
+            write!(f, "{},{}", fr(rd()), x(rs1()))?;
+            fmt_rounding_mode(inst, f)
+        }
+        InstFormat::VsetvliFormat | InstFormat::VsetivliFormat => {
+            if matches!(inst.inst_format, InstFormat::VsetvliFormat) {
+                write!(f, "{},{},", x(rd()), x(rs1()))?;
+            } else {
+                write!(f, "{},{},", x(rd()), rs1())?;
+            }
+            match Vtype::from_imm(imm()) {
+                Some(vtype) => write!(Compact(f), "{vtype}"),
+                None => write!(f, "{}", imm()),
+            }
+        }
+        InstFormat::VlFormat => write!(f, "{},({})", v(rd()), x(rs1())),
+        InstFormat::VlsFormat => write!(f, "{},({}),{}", v(rd()), x(rs1()), x(rs2())),
+        InstFormat::VlxFormat => write!(f, "{},({}),{}", v(rd()), x(rs1()), v(rs2())),
+        InstFormat::VsFormat => write!(f, "{},({})", v(rs3()), x(rs1())),
+        InstFormat::VssFormat => write!(f, "{},({}),{}", v(rs3()), x(rs1()), x(rs2())),
+        InstFormat::VsxFormat => write!(f, "{},({}),{}", v(rs3()), x(rs1()), v(rs2())),
+        InstFormat::VvFormat
+        | InstFormat::VxFormat
+        | InstFormat::ViFormat
+        | InstFormat::VfFormat
+        | InstFormat::VUnaryFormat => {
+            write!(f, "{}", v(rd()))?;
+            if let Some(vs2) = inst.rs2 {
+                write!(f, ",{}", v(vs2))?;
+            }
+            match inst.inst_format {
+                InstFormat::VvFormat => write!(f, ",{}", v(rs1())),
+                InstFormat::VxFormat => write!(f, ",{}", x(rs1())),
+                InstFormat::ViFormat => write!(f, ",{}", imm()),
+                InstFormat::VfFormat => write!(f, ",{}", fr(rs1())),
+                _ => Ok(()),
+            }
+        }
+        InstFormat::VmaVvFormat => write!(f, "{},{},{}", v(rd()), v(rs1()), v(rs2())),
+        InstFormat::VmaVxFormat => write!(f, "{},{},{}", v(rd()), x(rs1()), v(rs2())),
+        InstFormat::VmaVfFormat => write!(f, "{},{},{}", v(rd()), fr(rs1()), v(rs2())),
+        InstFormat::VToXFormat => write!(f, "{},{}", x(rd()), v(rs2())),
+        InstFormat::VToFFormat => write!(f, "{},{}", fr(rd()), v(rs2())),
+        InstFormat::HfenceFormat => write!(f, "{},{}", x(rs1()), x(rs2())),
+        InstFormat::HlvFormat => write!(f, "{},({})", x(rd()), x(rs1())),
+        InstFormat::HsvFormat => write!(f, "{},({})", x(rs2()), x(rs1())),
+        InstFormat::FenceFormat => {
+            let fence = Fence::from_imm(imm());
+            write!(f, "{},{}", fence.pred, fence.succ)
+        }
+        InstFormat::NoOperand => match inst.opc {
+            OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPUSH) => write!(f, "{}", x(1)),
+            _ => write!(f, "{}", x(5)),
+        },
+    }?;
+
+    match inst.vector_mask() {
+        Some(mask) => write!(f, ",{mask}"),
+        None => Ok(()),
+    }
+}
+
+/// Print the lowercase mnemonic with the ordering suffix of atomic instructions.
+fn fmt_mnemonic(inst: &Instruction, f: &mut Formatter) -> fmt::Result {
+    match &inst.opc {
+        // counters are printed as the csr instruction.
+        OpcodeKind::Zicntr(_) => return write!(f, "csrrs"),
+        opc => write!(Compact(f), "{opc}")?,
+    }
+
+    match (&inst.opc, inst.imm) {
+        (
+            OpcodeKind::A(_)
+            | OpcodeKind::Zicfiss(ZicfissOpcode::SSAMOSWAP_W | ZicfissOpcode::SSAMOSWAP_D),
+            Some(aq_rl),
+        ) => match aq_rl {
+            0b10 => write!(f, ".aq"),
+            0b01 => write!(f, ".rl"),
+            0b11 => write!(f, ".aqrl"),
+            _ => Ok(()),
+        },
+        _ => Ok(()),
+    }
+}
+
+/// Print the rounding mode.
+///
+/// `rne` is omitted for the conversions that are always exact (e.g. `fcvt.d.w`).
+fn fmt_rounding_mode(inst: &Instruction, f: &mut Formatter) -> fmt::Result {
+    let exact = matches!(
+        inst.opc,
+        OpcodeKind::D(DOpcode::FCVT_D_W | DOpcode::FCVT_D_WU | DOpcode::FCVT_D_S)
+            | OpcodeKind::Q(
+                QOpcode::FCVT_Q_W
+                    | QOpcode::FCVT_Q_WU
+                    | QOpcode::FCVT_Q_L
+                    | QOpcode::FCVT_Q_LU
+                    | QOpcode::FCVT_Q_S
+                    | QOpcode::FCVT_Q_D
+            )
+    );
+    match inst.rm {
+        Some(RoundingMode::RNE) if exact => Ok(()),
+        Some(rm) => write!(f, ",{rm}"),
+        None => Ok(()),
+    }
+}
+
+#[cfg(test)]
+mod test_objdump {
+    #[test]
+    fn objdump_test() {
+        use crate::{Decode, Isa};
+
+        let objdump = |inst: u32| match u16::try_from(inst) {
+            Ok(inst) if inst & 0b11 != 0b11 => {
+                inst.decode(Isa::Rv64).unwrap().objdump().to_string()
+            }
+            _ => inst.decode(Isa::Rv64).unwrap().objdump().to_string(),
+        };
+
+        assert_eq!(objdump(0x00b5_2223), "sw\ta1,4(a0)");
+        assert_eq!(objdump(0xfeb5_0ee3), "beq\ta0,a1,-4");
+        assert_eq!(objdump(0x0005_8567), "jalr\ta0,0(a1)");
+        assert_eq!(objdump(0x03f5_9513), "slli\ta0,a1,0x3f");
+        assert_eq!(objdump(0x1405_a52f), "lr.w.aq\ta0,(a1)");
+        assert_eq!(objdump(0x1a05_a52f), "sc.w.rl\ta0,zero,(a1)");
+        assert_eq!(objdump(0x3000_22f3), "csrrs\tt0,mstatus,zero");
+        assert_eq!(objdump(0xc000_2573), "csrrs\ta0,cycle,zero");
+        assert_eq!(objdump(0x00b5_7553), "fadd.s\tfa0,fa0,fa1,dyn");
+        assert_eq!(objdump(0xd205_0553), "fcvt.d.w\tfa0,a0");
+        assert_eq!(objdump(0x0d05_f557), "vsetvli\ta0,a1,e32,m1,ta,ma");
+        assert_eq!(objdump(0x0000_0073), "ecall");
+        assert_eq!(objdump(0x0ff0_000f), "fence\tiorw,iorw");
+        assert_eq!(objdump(0x0310_000f), "fence\trw,w");
+        assert_eq!(objdump(0x8330_000f), "fence.tso");
+        assert_eq!(objdump(0x0100_000f), "pause");
+        assert_eq!(objdump(0x0000_100f), "fence.i");
+        assert_eq!(objdump(0x817d), "c.srli\ta0,0x1f");
+        assert_eq!(objdump(0x65fd), "c.lui\ta1,0x1f");
+        assert_eq!(objdump(0xc111), "c.beqz\ta0,4");
+        assert_eq!(objdump(0x8082), "c.jr\tra");
+
+        // default formatting prints the same operand order.
+        assert_eq!(
+            0x00b5_2223_u32.decode(Isa::Rv64).unwrap().to_string(),
+            "sw a1, 4(a0)"
+        );
+        assert_eq!(
+            0xfeb5_0ee3_u32.decode(Isa::Rv64).unwrap().to_string(),
+            "beq a0, a1, -4"
+        );
+        assert_eq!(
+            0x817d_u16.decode(Isa::Rv64).unwrap().to_string(),
+            "C.srli a0, 31"
+        );
+    }
+}
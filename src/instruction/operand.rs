@@ -0,0 +1,361 @@
+//! Structured operands of instructions.
+
+use super::{
+    a_extension::AOpcode,
+    base_i::{BaseIOpcode, FenceSet},
+    c_extension::COpcode,
+    f_extension::RoundingMode,
+    v_extension::Vtype,
+    zicboz_extension::ZicbozOpcode,
+    zicfiss_extension::ZicfissOpcode,
+    zicsr_extension::Csr,
+    InstFormat, Instruction, OpcodeKind, Register, VReg, XReg,
+};
+
+/// Operand of an instruction.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Operand {
+    /// Register
+    Reg(Register),
+    /// Immediate (branch/jump offsets are relative to the pc)
+    Imm(i32),
+    /// Control and status register
+    Csr(Csr),
+    /// Memory operand `offset(base)`
+    Mem {
+        /// Base address register
+        base: XReg,
+        /// Offset from the base address
+        offset: i32,
+    },
+    /// Predecessor or successor set of FENCE
+    Fence(FenceSet),
+    /// Static rounding mode (including `dyn`)
+    RoundingMode(RoundingMode),
+    /// Vector type of vsetvli/vsetivli
+    Vtype(Vtype),
+    /// Vector mask `v0.t`
+    Mask,
+    /// Memory ordering bits of atomic instructions
+    Ordering {
+        /// Acquire
+        aq: bool,
+        /// Release
+        rl: bool,
+    },
+}
+
+/// Maximum number of operands (e.g. `fmadd.s rd, rs1, rs2, rs3, rm`).
+const MAX_OPERANDS: usize = 5;
+
+/// Iterator over the operands of an instruction in assembly order.
+///
+/// # Example
+/// ```
+/// use raki::{Decode, Isa, Operand, Register, XReg};
+///
+/// // sw a1, 4(a0)
+/// let inst = 0x00b5_2223_u32.decode(Isa::Rv64).unwrap();
+/// let operands: Vec<Operand> = inst.operands().collect();
+/// assert_eq!(
+///     operands,
+///     vec![
+///         Operand::Reg(Register::X(XReg::A1)),
+///         Operand::Mem {
+///             base: XReg::A0,
+///             offset: 4
+///         },
+///     ]
+/// );
+/// ```
+#[derive(Debug, Clone)]
+pub struct Operands {
+    list: [Option<Operand>; MAX_OPERANDS],
+    len: usize,
+    pos: usize,
+}
+
+impl Operands {
+    fn push(&mut self, operand: Option<Operand>) {
+        if let Some(operand) = operand {
+            self.list[self.len] = Some(operand);
+            self.len += 1;
+        }
+    }
+}
+
+impl Iterator for Operands {
+    type Item = Operand;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let operand = self.list.get(self.pos).copied().flatten()?;
+        self.pos += 1;
+        Some(operand)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.len - self.pos;
+        (len, Some(len))
+    }
+}
+
+impl ExactSizeIterator for Operands {}
+
+impl Instruction {
+    /// Operands in assembly order (e.g. `rd, offset(rs1)` for loads).
+    ///
+    /// Implicit operands (e.g. `sp` of `c.lwsp`) are included if they appear in the assembly.
+    #[must_use]
+    #[allow(clippy::too_many_lines)]
+    pub fn operands(&self) -> Operands {
+        let mut ops = Operands {
+            list: [None; MAX_OPERANDS],
+            len: 0,
+            pos: 0,
+        };
+        let (rd, rs1, rs2, rs3) = (
+            self.rd_reg().map(Operand::Reg),
+            self.rs1_reg().map(Operand::Reg),
+            self.rs2_reg().map(Operand::Reg),
+            self.rs3_reg().map(Operand::Reg),
+        );
+        let imm = self.imm.map(Operand::Imm);
+        let x = |reg: XReg| Some(Operand::Reg(Register::X(reg)));
+        let mem = |base: Option<usize>, offset: Option<i32>| {
+            Some(Operand::Mem {
+                base: XReg::new(base?)?,
+                offset: offset.unwrap_or(0),
+            })
+        };
+        let sp_mem = mem(Some(XReg::SP.num()), self.imm);
+        let rounding_mode = self.rm.map(Operand::RoundingMode);
+
+        let operands: [Option<Operand>; MAX_OPERANDS] = match self.inst_format {
+            InstFormat::RFormat
+            | InstFormat::MFormat
+            | InstFormat::FrCmpFormat
+            | InstFormat::VmaVvFormat
+            | InstFormat::VmaVxFormat
+            | InstFormat::VmaVfFormat => [rd, rs1, rs2, None, None],
+            InstFormat::AFormat | InstFormat::ALrFormat => {
+                let ordering = self.imm.map(|aq_rl| Operand::Ordering {
+                    aq: aq_rl & 0b10 != 0,
+                    rl: aq_rl & 0b01 != 0,
+                });
+                match self.opc {
+                    OpcodeKind::A(AOpcode::LR_W | AOpcode::LR_D) => {
+                        [rd, mem(self.rs1, None), ordering, None, None]
+                    }
+                    _ => [rd, rs2, mem(self.rs1, None), ordering, None],
+                }
+            }
+            InstFormat::RShamtFormat => [rd, rs1, imm, None, None],
+            InstFormat::RUnaryFormat => [rd, rs1, None, None, None],
+            InstFormat::IFormat => match self.opc {
+                OpcodeKind::BaseI(
+                    BaseIOpcode::LB
+                    | BaseIOpcode::LH
+                    | BaseIOpcode::LW
+                    | BaseIOpcode::LBU
+                    | BaseIOpcode::LHU
+                    | BaseIOpcode::LWU
+                    | BaseIOpcode::LD,
+                ) => [rd, mem(self.rs1, self.imm), None, None, None],
+                _ => [rd, rs1, imm, None, None],
+            },
+            InstFormat::ClFormat | InstFormat::FlFormat | InstFormat::CflFormat => {
+                [rd, mem(self.rs1, self.imm), None, None, None]
+            }
+            InstFormat::SFormat
+            | InstFormat::CsFormat
+            | InstFormat::FsFormat
+            | InstFormat::CfsFormat => [rs2, mem(self.rs1, self.imm), None, None, None],
+            InstFormat::BFormat => [rs1, rs2, imm, None, None],
+            InstFormat::UFormat | InstFormat::JFormat => [rd, imm, None, None, None],
+            InstFormat::CjFormat => [imm, None, None, None, None],
+            InstFormat::CiwFormat => [rd, x(XReg::SP), imm, None, None],
+            InstFormat::CssFormat | InstFormat::CfsspFormat => [rs2, sp_mem, None, None, None],
+            InstFormat::CflspFormat => [rd, sp_mem, None, None, None],
+            InstFormat::CiFormat => match self.opc {
+                OpcodeKind::C(COpcode::LWSP | COpcode::LDSP) => [rd, sp_mem, None, None, None],
+                _ => [rd, imm, None, None, None],
+            },
+            InstFormat::CrFormat => match self.opc {
+                OpcodeKind::C(COpcode::JR | COpcode::JALR) => [rs1, None, None, None, None],
+                _ => [rd, rs2, None, None, None],
+            },
+            InstFormat::CaFormat | InstFormat::VToXFormat | InstFormat::VToFFormat => {
+                [rd, rs2, None, None, None]
+            }
+            InstFormat::CbFormat => match self.opc {
+                OpcodeKind::C(COpcode::BEQZ | COpcode::BNEZ) => [rs1, imm, None, None, None],
+                _ => [rd, imm, None, None, None],
+            },
+            InstFormat::CsrFormat => [rd, self.csr().map(Operand::Csr), rs1, None, None],
+            InstFormat::CsrUiFormat => [rd, self.csr().map(Operand::Csr), imm, None, None],
+            InstFormat::OnlyRd => [rd, None, None, None, None],
+            InstFormat::OnlyRs1 => match self.opc {
+                OpcodeKind::Zicboz(ZicbozOpcode::CBO_ZERO) => {
+                    [mem(self.rs1, None), None, None, None, None]
+                }
+                _ => [rs1, None, None, None, None],
+            },
+            InstFormat::OnlyRs2 => [rs2, None, None, None, None],
+            InstFormat::R4Format => [rd, rs1, rs2, rs3, rounding_mode],
+            InstFormat::FrFormat => [rd, rs1, rs2, rounding_mode, None],
+            InstFormat::FrUnaryFormat | InstFormat::FrToXFormat | InstFormat::XToFrFormat => {
+                [rd, rs1, rounding_mode, None, None]
+            }
+            InstFormat::VsetvliFormat | InstFormat::VsetivliFormat => {
+                let avl = match self.inst_format {
+                    InstFormat::VsetvliFormat => rs1,
+                    _ => self
+                        .rs1
+                        .and_then(|uimm| i32::try_from(uimm).ok())
+                        .map(Operand::Imm),
+                };
+                let vtype = self
+                    .imm
+                    .map(|zimm| Vtype::from_imm(zimm).map_or(Operand::Imm(zimm), Operand::Vtype));
+                [rd, avl, vtype, None, None]
+            }
+            InstFormat::VlFormat | InstFormat::HlvFormat => {
+                [rd, mem(self.rs1, None), None, None, None]
+            }
+            InstFormat::VlsFormat | InstFormat::VlxFormat => {
+                [rd, mem(self.rs1, None), rs2, None, None]
+            }
+            InstFormat::VsFormat => [rs3, mem(self.rs1, None), None, None, None],
+            InstFormat::VssFormat | InstFormat::VsxFormat => {
+                [rs3, mem(self.rs1, None), rs2, None, None]
+            }
+            InstFormat::VvFormat
+            | InstFormat::VxFormat
+            | InstFormat::VfFormat
+            | InstFormat::VUnaryFormat => [rd, rs2, rs1, None, None],
+            InstFormat::ViFormat => [rd, rs2, imm, None, None],
+            InstFormat::HfenceFormat => [rs1, rs2, None, None, None],
+            InstFormat::HsvFormat => [rs2, mem(self.rs1, None), None, None, None],
+            InstFormat::FenceFormat => match self.fence() {
+                Some(fence) => [
+                    Some(Operand::Fence(fence.pred)),
+                    Some(Operand::Fence(fence.succ)),
+                    None,
+                    None,
+                    None,
+                ],
+                None => [None; MAX_OPERANDS],
+            },
+            InstFormat::NoOperand => match self.opc {
+                OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPUSH) => {
+                    [x(XReg::RA), None, None, None, None]
+                }
+                OpcodeKind::Zicfiss(ZicfissOpcode::C_SSPOPCHK) => {
+                    [x(XReg::T0), None, None, None, None]
+                }
+                _ => [None; MAX_OPERANDS],
+            },
+        };
+        for operand in operands {
+            ops.push(operand);
+        }
+
+        match self.vector_mask() {
+            Some("v0.t") => ops.push(Some(Operand::Mask)),
+            Some(_) => ops.push(VReg::new(0).map(|v0| Operand::Reg(Register::V(v0)))),
+            None => (),
+        }
+        ops
+    }
+}
+
+#[cfg(test)]
+mod test_operand {
+    #[test]
+    fn operands_test() {
+        use super::Operand;
+        use crate::{Csr, Decode, FReg, FenceSet, Isa, Register, RoundingMode, VReg, Vtype, XReg};
+
+        let operands = |inst: u32| -> Vec<Operand> {
+            match u16::try_from(inst) {
+                Ok(inst) if inst & 0b11 != 0b11 => inst.decode(Isa::Rv64).unwrap().operands(),
+                _ => inst.decode(Isa::Rv64).unwrap().operands(),
+            }
+            .collect()
+        };
+        let x = |num| Operand::Reg(Register::X(XReg::new(num).unwrap()));
+        let f = |num| Operand::Reg(Register::F(FReg::new(num).unwrap()));
+        let v = |num| Operand::Reg(Register::V(VReg::new(num).unwrap()));
+        let mem = |base, offset| Operand::Mem {
+            base: XReg::new(base).unwrap(),
+            offset,
+        };
+
+        // ld a0, -8(sp)
+        assert_eq!(operands(0xff81_3503), vec![x(10), mem(2, -8)]);
+        // amoadd.w.aqrl a0, a2, (a1)
+        assert_eq!(
+            operands(0x06c5_a52f),
+            vec![
+                x(10),
+                x(12),
+                mem(11, 0),
+                Operand::Ordering { aq: true, rl: true }
+            ]
+        );
+        // ssamoswap.w.aqrl a0, a2, (a1)
+        assert_eq!(
+            operands(0x4ec5_a52f),
+            vec![
+                x(10),
+                x(12),
+                mem(11, 0),
+                Operand::Ordering { aq: true, rl: true }
+            ]
+        );
+        // csrrwi a0, mtvec, 8
+        assert_eq!(
+            operands(0x3054_5573),
+            vec![x(10), Operand::Csr(Csr::MTVEC), Operand::Imm(8)]
+        );
+        // fmadd.s fa0, fa1, fa2, fa3, rtz
+        assert_eq!(
+            operands(0x68c5_9543),
+            vec![
+                f(10),
+                f(11),
+                f(12),
+                f(13),
+                Operand::RoundingMode(RoundingMode::RTZ)
+            ]
+        );
+        // fence rw, w
+        assert_eq!(
+            operands(0x0310_000f),
+            vec![
+                Operand::Fence(FenceSet::new(0b0011)),
+                Operand::Fence(FenceSet::new(0b0001))
+            ]
+        );
+        // vsetvli a0, a1, e32, m1, ta, ma
+        assert_eq!(
+            operands(0x0d05_f557),
+            vec![x(10), x(11), Operand::Vtype(Vtype::from_imm(0xd0).unwrap())]
+        );
+        // vadd.vx v8, v9, a0, v0.t / vmerge.vvm v8, v9, v10, v0
+        assert_eq!(
+            operands(0x0095_4457),
+            vec![v(8), v(9), x(10), Operand::Mask]
+        );
+        assert_eq!(operands(0x5c95_0457), vec![v(8), v(9), v(10), v(0)]);
+        // vsse16.v v8, (a0), a1
+        assert_eq!(operands(0x0ab5_5427), vec![v(8), mem(10, 0), x(11)]);
+        // c.lwsp a0, 12(sp) / c.j -4
+        assert_eq!(operands(0x4532), vec![x(10), mem(2, 12)]);
+        assert_eq!(operands(0xbff5), vec![Operand::Imm(-4)]);
+        // ecall
+        assert_eq!(operands(0x0000_0073), vec![]);
+        assert_eq!(FenceSet::new(0b1111).to_string(), "iorw");
+        assert_eq!(FenceSet::new(0).to_string(), "0");
+    }
+}
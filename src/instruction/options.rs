@@ -0,0 +1,298 @@
+//! Configurable formatting of instructions.
+
+use core::fmt::{self, Display, Formatter};
+
+use super::{alias::fmt_alias, FReg, Instruction, VReg, XReg};
+
+/// Radix of immediates.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Radix {
+    /// Decimal (e.g. `-16`)
+    Decimal,
+    /// Hexadecimal with the `0x` prefix (e.g. `-0x10`)
+    Hex,
+}
+
+/// Options of `Instruction::display_with`.
+///
+/// The default options print the same text as `Display`.
+///
+/// # Example
+/// ```
+/// use raki::{Decode, FormatOptions, Isa, Radix};
+///
+/// // addi s0, sp, 16
+/// let inst = 0x0101_0413_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(
+///     inst.display_with(&FormatOptions::new()).to_string(),
+///     "addi s0, sp, 16"
+/// );
+///
+/// let opts = FormatOptions::new()
+///     .with_fp(true)
+///     .with_radix(Radix::Hex)
+///     .with_separator(",");
+/// assert_eq!(inst.display_with(&opts).to_string(), "addi fp,sp,0x10");
+///
+/// let opts = FormatOptions::new().with_numeric_names(true);
+/// assert_eq!(inst.display_with(&opts).to_string(), "addi x8, x2, 16");
+///
+/// // addi a0, a1, 0
+/// let inst = 0x0005_8513_u32.decode(Isa::Rv64).unwrap();
+/// let opts = FormatOptions::new().with_aliases(true).with_separator(",");
+/// assert_eq!(inst.display_with(&opts).to_string(), "mv a0,a1");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct FormatOptions {
+    numeric_names: bool,
+    fp: bool,
+    radix: Option<Radix>,
+    separator: &'static str,
+    aliases: bool,
+}
+
+impl FormatOptions {
+    /// ABI register names, `s0`, the default radix of each instruction, `, ` separators
+    /// and no pseudo-instructions.
+    #[must_use]
+    pub const fn new() -> Self {
+        FormatOptions {
+            numeric_names: false,
+            fp: false,
+            radix: None,
+            separator: ", ",
+            aliases: false,
+        }
+    }
+
+    /// Print raw register names (e.g. `x10`, `f10`) instead of ABI names.
+    #[must_use]
+    pub const fn with_numeric_names(self, numeric_names: bool) -> Self {
+        FormatOptions {
+            numeric_names,
+            ..self
+        }
+    }
+
+    /// Print `x8` as `fp` instead of `s0`.
+    #[must_use]
+    pub const fn with_fp(self, fp: bool) -> Self {
+        FormatOptions { fp, ..self }
+    }
+
+    /// Print every immediate in `radix`.
+    ///
+    /// By default, upper immediates (`lui`, `auipc`) and jump offsets are printed in hex
+    /// and the others in decimal.
+    #[must_use]
+    pub const fn with_radix(self, radix: Radix) -> Self {
+        FormatOptions {
+            radix: Some(radix),
+            ..self
+        }
+    }
+
+    /// Separate operands with `separator`.
+    #[must_use]
+    pub const fn with_separator(self, separator: &'static str) -> Self {
+        FormatOptions { separator, ..self }
+    }
+
+    /// Print pseudo-instructions (e.g. `li`, `ret`, `csrr`) if `aliases` is true.
+    #[must_use]
+    pub const fn with_aliases(self, aliases: bool) -> Self {
+        FormatOptions { aliases, ..self }
+    }
+
+    /// Whether pseudo-instructions are printed.
+    pub(crate) fn aliases(&self) -> bool {
+        self.aliases
+    }
+
+    /// Operand separator.
+    pub(crate) fn separator(&self) -> &'static str {
+        self.separator
+    }
+
+    /// Name of the integer register.
+    pub(crate) fn xreg(&self, reg: usize) -> RegName {
+        match XReg::new(reg) {
+            Some(reg) if self.numeric_names => RegName::Name(reg.raw_name()),
+            Some(XReg::S0) if self.fp => RegName::Name("fp"),
+            Some(reg) => RegName::Name(reg.abi_name()),
+            None => RegName::OutOfRange('x', reg),
+        }
+    }
+
+    /// Name of the floating-point register.
+    pub(crate) fn freg(&self, reg: usize) -> RegName {
+        match FReg::new(reg) {
+            Some(reg) if self.numeric_names => RegName::Name(reg.raw_name()),
+            Some(reg) => RegName::Name(reg.abi_name()),
+            None => RegName::OutOfRange('f', reg),
+        }
+    }
+
+    /// Name of the vector register (vector registers have no ABI names).
+    #[allow(clippy::unused_self)]
+    pub(crate) fn vreg(&self, reg: usize) -> RegName {
+        match VReg::new(reg) {
+            Some(reg) => RegName::Name(reg.name()),
+            None => RegName::OutOfRange('v', reg),
+        }
+    }
+
+    /// Immediate printed in the radix of the options, or `default` if it is not given.
+    pub(crate) fn imm(&self, value: i64, default: Radix) -> Imm {
+        Imm {
+            value,
+            radix: self.radix.unwrap_or(default),
+        }
+    }
+}
+
+impl Default for FormatOptions {
+    fn default() -> Self {
+        FormatOptions::new()
+    }
+}
+
+/// Register name chosen by `FormatOptions`.
+pub(crate) enum RegName {
+    /// Name of a valid register.
+    Name(&'static str),
+    /// Register number out of 0-31 (only in hand-made instructions), printed with its prefix.
+    OutOfRange(char, usize),
+}
+
+impl Display for RegName {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            RegName::Name(name) => write!(f, "{name}"),
+            RegName::OutOfRange(prefix, num) => write!(f, "{prefix}{num}"),
+        }
+    }
+}
+
+/// Immediate formatted in a radix.
+pub(crate) struct Imm {
+    value: i64,
+    radix: Radix,
+}
+
+impl Display for Imm {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.radix {
+            Radix::Decimal => write!(f, "{}", self.value),
+            Radix::Hex if self.value < 0 => write!(f, "-{:#x}", self.value.unsigned_abs()),
+            Radix::Hex => write!(f, "{:#x}", self.value),
+        }
+    }
+}
+
+/// Instruction formatted with `FormatOptions`.
+pub struct DisplayWith<'a> {
+    inst: &'a Instruction,
+    opts: &'a FormatOptions,
+}
+
+impl Instruction {
+    /// Format the instruction with `opts`.
+    #[must_use]
+    pub fn display_with<'a>(&'a self, opts: &'a FormatOptions) -> DisplayWith<'a> {
+        DisplayWith { inst: self, opts }
+    }
+}
+
+impl Display for DisplayWith<'_> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if self.opts.aliases() {
+            if let Some(result) = fmt_alias(self.inst, f, self.opts, None) {
+                return result;
+            }
+        }
+        self.inst.fmt_with(f, self.opts)
+    }
+}
+
+#[cfg(test)]
+mod test_options {
+    #[test]
+    fn display_with_test() {
+        use super::{FormatOptions, Radix};
+        use crate::{BaseIOpcode, Decode, InstFormat, Instruction, Isa, OpcodeKind};
+
+        let format = |inst: u32, opts: &FormatOptions| match u16::try_from(inst) {
+            Ok(inst) if inst & 0b11 != 0b11 => inst
+                .decode(Isa::Rv64)
+                .unwrap()
+                .display_with(opts)
+                .to_string(),
+            _ => inst
+                .decode(Isa::Rv64)
+                .unwrap()
+                .display_with(opts)
+                .to_string(),
+        };
+        let hex = FormatOptions::new().with_radix(Radix::Hex);
+        let numeric = FormatOptions::new().with_numeric_names(true);
+        let compact = FormatOptions::new().with_separator(",").with_fp(true);
+
+        // addi sp, sp, -16
+        assert_eq!(format(0xff01_0113, &hex), "addi sp, sp, -0x10");
+        // sd s0, 8(sp)
+        assert_eq!(format(0x0081_3423, &compact), "sd fp,8(sp)");
+        assert_eq!(format(0x0081_3423, &numeric), "sd x8, 8(x2)");
+        // lui a0, 0x80000
+        assert_eq!(
+            format(0x8000_0537, &FormatOptions::new()),
+            "lui a0, 0x80000"
+        );
+        assert_eq!(
+            format(
+                0x8000_0537,
+                &FormatOptions::new().with_radix(Radix::Decimal)
+            ),
+            "lui a0, 524288"
+        );
+        // c.addi4spn a0, sp, 16
+        assert_eq!(format(0x0808, &hex), "C.addi4spn a0, sp, 0x10");
+        // fadd.s fa0, fa1, fa2, rtz
+        assert_eq!(format(0x00c5_9553, &numeric), "fadd.s f10, f11, f12, rtz");
+        assert_eq!(format(0x00c5_9553, &compact), "fadd.s fa0,fa1,fa2,rtz");
+        // vadd.vx v8, v9, a0, v0.t / vsetvli a0, a1, e32, m1, ta, ma
+        assert_eq!(format(0x0095_4457, &compact), "vadd.vx v8,v9,a0,v0.t");
+        assert_eq!(format(0x0d05_f557, &compact), "vsetvli a0,a1,e32,m1,ta,ma");
+
+        // pseudo-instructions follow the options too.
+        let compact = compact.with_aliases(true);
+        // addi s0, a1, 0 / addi a0, zero, 16 / csrrs a0, mstatus, zero
+        assert_eq!(format(0x0005_8413, &compact), "mv fp,a1");
+        assert_eq!(
+            format(0x0100_0513, &compact.with_radix(Radix::Hex)),
+            "li a0,0x10"
+        );
+        assert_eq!(
+            format(0x3000_2573, &numeric.with_aliases(true)),
+            "csrr x10, mstatus"
+        );
+        // vrsub.vx v8, v8, zero, v0.t
+        assert_eq!(format(0x0c80_4457, &compact), "vneg.v v8,v8,v0.t");
+
+        // out-of-range registers of hand-made instructions are printed without panicking.
+        let inst = Instruction {
+            opc: OpcodeKind::BaseI(BaseIOpcode::ADD),
+            rd: Some(40),
+            rs1: Some(1),
+            rs2: Some(2),
+            rs3: None,
+            imm: None,
+            vm: None,
+            rm: None,
+            inst_format: InstFormat::RFormat,
+            is_compressed: false,
+        };
+        assert_eq!(inst.to_string(), "add x40, ra, sp");
+        assert_eq!(inst.objdump().to_string(), "add\tx40,ra,sp");
+    }
+}
@@ -11,6 +11,32 @@ pub enum PrivOpcode {
     SRET,
     WFI,
     SFENCE_VMA,
+    MNRET,
+    DRET,
+
+    // Svinval Extension
+    SINVAL_VMA,
+    SFENCE_W_INVAL,
+    SFENCE_INVAL_IR,
+
+    // Hypervisor Extension
+    HFENCE_VVMA,
+    HFENCE_GVMA,
+    HLV_B,
+    HLV_BU,
+    HLV_H,
+    HLV_HU,
+    HLVX_HU,
+    HLV_W,
+    HLVX_WU,
+    HSV_B,
+    HSV_H,
+    HSV_W,
+
+    //-- rv64 --
+    HLV_WU,
+    HLV_D,
+    HSV_D,
 }
 
 impl Display for PrivOpcode {
@@ -20,6 +46,26 @@ impl Display for PrivOpcode {
             PrivOpcode::MRET => write!(f, "mret"),
             PrivOpcode::WFI => write!(f, "wfi"),
             PrivOpcode::SFENCE_VMA => write!(f, "sfence.vma"),
+            PrivOpcode::MNRET => write!(f, "mnret"),
+            PrivOpcode::DRET => write!(f, "dret"),
+            PrivOpcode::SINVAL_VMA => write!(f, "sinval.vma"),
+            PrivOpcode::SFENCE_W_INVAL => write!(f, "sfence.w.inval"),
+            PrivOpcode::SFENCE_INVAL_IR => write!(f, "sfence.inval.ir"),
+            PrivOpcode::HFENCE_VVMA => write!(f, "hfence.vvma"),
+            PrivOpcode::HFENCE_GVMA => write!(f, "hfence.gvma"),
+            PrivOpcode::HLV_B => write!(f, "hlv.b"),
+            PrivOpcode::HLV_BU => write!(f, "hlv.bu"),
+            PrivOpcode::HLV_H => write!(f, "hlv.h"),
+            PrivOpcode::HLV_HU => write!(f, "hlv.hu"),
+            PrivOpcode::HLVX_HU => write!(f, "hlvx.hu"),
+            PrivOpcode::HLV_W => write!(f, "hlv.w"),
+            PrivOpcode::HLVX_WU => write!(f, "hlvx.wu"),
+            PrivOpcode::HSV_B => write!(f, "hsv.b"),
+            PrivOpcode::HSV_H => write!(f, "hsv.h"),
+            PrivOpcode::HSV_W => write!(f, "hsv.w"),
+            PrivOpcode::HLV_WU => write!(f, "hlv.wu"),
+            PrivOpcode::HLV_D => write!(f, "hlv.d"),
+            PrivOpcode::HSV_D => write!(f, "hsv.d"),
         }
     }
 }
@@ -27,8 +73,29 @@ impl Display for PrivOpcode {
 impl Opcode for PrivOpcode {
     fn get_format(&self) -> InstFormat {
         match self {
-            PrivOpcode::SRET | PrivOpcode::MRET | PrivOpcode::WFI => InstFormat::NoOperand,
-            PrivOpcode::SFENCE_VMA => InstFormat::RFormat,
+            PrivOpcode::SRET
+            | PrivOpcode::MRET
+            | PrivOpcode::WFI
+            | PrivOpcode::MNRET
+            | PrivOpcode::DRET
+            | PrivOpcode::SFENCE_W_INVAL
+            | PrivOpcode::SFENCE_INVAL_IR => InstFormat::NoOperand,
+            PrivOpcode::SFENCE_VMA
+            | PrivOpcode::SINVAL_VMA
+            | PrivOpcode::HFENCE_VVMA
+            | PrivOpcode::HFENCE_GVMA => InstFormat::HfenceFormat,
+            PrivOpcode::HLV_B
+            | PrivOpcode::HLV_BU
+            | PrivOpcode::HLV_H
+            | PrivOpcode::HLV_HU
+            | PrivOpcode::HLVX_HU
+            | PrivOpcode::HLV_W
+            | PrivOpcode::HLVX_WU
+            | PrivOpcode::HLV_WU
+            | PrivOpcode::HLV_D => InstFormat::HlvFormat,
+            PrivOpcode::HSV_B | PrivOpcode::HSV_H | PrivOpcode::HSV_W | PrivOpcode::HSV_D => {
+                InstFormat::HsvFormat
+            }
         }
     }
 }
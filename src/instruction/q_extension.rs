@@ -0,0 +1,118 @@
+//! Q extension Instruction.
+
+use super::{InstFormat, Opcode};
+use core::fmt::{self, Display, Formatter};
+
+/// Insturctions in Q Extension.
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq)]
+pub enum QOpcode {
+    FLQ,
+    FSQ,
+    FMADD_Q,
+    FMSUB_Q,
+    FNMSUB_Q,
+    FNMADD_Q,
+    FADD_Q,
+    FSUB_Q,
+    FMUL_Q,
+    FDIV_Q,
+    FSQRT_Q,
+    FSGNJ_Q,
+    FSGNJN_Q,
+    FSGNJX_Q,
+    FMIN_Q,
+    FMAX_Q,
+    FCVT_S_Q,
+    FCVT_Q_S,
+    FCVT_D_Q,
+    FCVT_Q_D,
+    FEQ_Q,
+    FLT_Q,
+    FLE_Q,
+    FCLASS_Q,
+    FCVT_W_Q,
+    FCVT_WU_Q,
+    FCVT_Q_W,
+    FCVT_Q_WU,
+
+    //-- rv64 only --
+    FCVT_L_Q,
+    FCVT_LU_Q,
+    FCVT_Q_L,
+    FCVT_Q_LU,
+}
+
+impl Display for QOpcode {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            QOpcode::FLQ => write!(f, "flq"),
+            QOpcode::FSQ => write!(f, "fsq"),
+            QOpcode::FMADD_Q => write!(f, "fmadd.q"),
+            QOpcode::FMSUB_Q => write!(f, "fmsub.q"),
+            QOpcode::FNMSUB_Q => write!(f, "fnmsub.q"),
+            QOpcode::FNMADD_Q => write!(f, "fnmadd.q"),
+            QOpcode::FADD_Q => write!(f, "fadd.q"),
+            QOpcode::FSUB_Q => write!(f, "fsub.q"),
+            QOpcode::FMUL_Q => write!(f, "fmul.q"),
+            QOpcode::FDIV_Q => write!(f, "fdiv.q"),
+            QOpcode::FSQRT_Q => write!(f, "fsqrt.q"),
+            QOpcode::FSGNJ_Q => write!(f, "fsgnj.q"),
+            QOpcode::FSGNJN_Q => write!(f, "fsgnjn.q"),
+            QOpcode::FSGNJX_Q => write!(f, "fsgnjx.q"),
+            QOpcode::FMIN_Q => write!(f, "fmin.q"),
+            QOpcode::FMAX_Q => write!(f, "fmax.q"),
+            QOpcode::FCVT_S_Q => write!(f, "fcvt.s.q"),
+            QOpcode::FCVT_Q_S => write!(f, "fcvt.q.s"),
+            QOpcode::FCVT_D_Q => write!(f, "fcvt.d.q"),
+            QOpcode::FCVT_Q_D => write!(f, "fcvt.q.d"),
+            QOpcode::FEQ_Q => write!(f, "feq.q"),
+            QOpcode::FLT_Q => write!(f, "flt.q"),
+            QOpcode::FLE_Q => write!(f, "fle.q"),
+            QOpcode::FCLASS_Q => write!(f, "fclass.q"),
+            QOpcode::FCVT_W_Q => write!(f, "fcvt.w.q"),
+            QOpcode::FCVT_WU_Q => write!(f, "fcvt.wu.q"),
+            QOpcode::FCVT_Q_W => write!(f, "fcvt.q.w"),
+            QOpcode::FCVT_Q_WU => write!(f, "fcvt.q.wu"),
+            QOpcode::FCVT_L_Q => write!(f, "fcvt.l.q"),
+            QOpcode::FCVT_LU_Q => write!(f, "fcvt.lu.q"),
+            QOpcode::FCVT_Q_L => write!(f, "fcvt.q.l"),
+            QOpcode::FCVT_Q_LU => write!(f, "fcvt.q.lu"),
+        }
+    }
+}
+
+impl Opcode for QOpcode {
+    fn get_format(&self) -> InstFormat {
+        match self {
+            QOpcode::FLQ => InstFormat::FlFormat,
+            QOpcode::FSQ => InstFormat::FsFormat,
+            QOpcode::FMADD_Q | QOpcode::FMSUB_Q | QOpcode::FNMSUB_Q | QOpcode::FNMADD_Q => {
+                InstFormat::R4Format
+            }
+            QOpcode::FADD_Q
+            | QOpcode::FSUB_Q
+            | QOpcode::FMUL_Q
+            | QOpcode::FDIV_Q
+            | QOpcode::FSGNJ_Q
+            | QOpcode::FSGNJN_Q
+            | QOpcode::FSGNJX_Q
+            | QOpcode::FMIN_Q
+            | QOpcode::FMAX_Q => InstFormat::FrFormat,
+            QOpcode::FSQRT_Q
+            | QOpcode::FCVT_S_Q
+            | QOpcode::FCVT_Q_S
+            | QOpcode::FCVT_D_Q
+            | QOpcode::FCVT_Q_D => InstFormat::FrUnaryFormat,
+            QOpcode::FEQ_Q | QOpcode::FLT_Q | QOpcode::FLE_Q => InstFormat::FrCmpFormat,
+            QOpcode::FCLASS_Q
+            | QOpcode::FCVT_W_Q
+            | QOpcode::FCVT_WU_Q
+            | QOpcode::FCVT_L_Q
+            | QOpcode::FCVT_LU_Q => InstFormat::FrToXFormat,
+            QOpcode::FCVT_Q_W | QOpcode::FCVT_Q_WU | QOpcode::FCVT_Q_L | QOpcode::FCVT_Q_LU => {
+                InstFormat::XToFrFormat
+            }
+        }
+    }
+}
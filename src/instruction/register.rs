@@ -0,0 +1,421 @@
+//! Typed register operands.
+
+use core::fmt::{self, Display, Formatter};
+
+use super::{
+    c_extension::COpcode, zicntr_extension::ZicntrOpcode, zicsr_extension::Csr, InstFormat,
+    Instruction, OpcodeKind,
+};
+
+/// ABI names of the integer registers.
+const XREG_ABI_NAMES: [&str; 32] = [
+    "zero", "ra", "sp", "gp", "tp", "t0", "t1", "t2", "s0", "s1", "a0", "a1", "a2", "a3", "a4",
+    "a5", "a6", "a7", "s2", "s3", "s4", "s5", "s6", "s7", "s8", "s9", "s10", "s11", "t3", "t4",
+    "t5", "t6",
+];
+
+/// Raw names of the integer registers.
+const XREG_RAW_NAMES: [&str; 32] = [
+    "x0", "x1", "x2", "x3", "x4", "x5", "x6", "x7", "x8", "x9", "x10", "x11", "x12", "x13", "x14",
+    "x15", "x16", "x17", "x18", "x19", "x20", "x21", "x22", "x23", "x24", "x25", "x26", "x27",
+    "x28", "x29", "x30", "x31",
+];
+
+/// ABI names of the floating-point registers.
+const FREG_ABI_NAMES: [&str; 32] = [
+    "ft0", "ft1", "ft2", "ft3", "ft4", "ft5", "ft6", "ft7", "fs0", "fs1", "fa0", "fa1", "fa2",
+    "fa3", "fa4", "fa5", "fa6", "fa7", "fs2", "fs3", "fs4", "fs5", "fs6", "fs7", "fs8", "fs9",
+    "fs10", "fs11", "ft8", "ft9", "ft10", "ft11",
+];
+
+/// Raw names of the floating-point registers.
+const FREG_RAW_NAMES: [&str; 32] = [
+    "f0", "f1", "f2", "f3", "f4", "f5", "f6", "f7", "f8", "f9", "f10", "f11", "f12", "f13", "f14",
+    "f15", "f16", "f17", "f18", "f19", "f20", "f21", "f22", "f23", "f24", "f25", "f26", "f27",
+    "f28", "f29", "f30", "f31",
+];
+
+/// Names of the vector registers.
+const VREG_NAMES: [&str; 32] = [
+    "v0", "v1", "v2", "v3", "v4", "v5", "v6", "v7", "v8", "v9", "v10", "v11", "v12", "v13", "v14",
+    "v15", "v16", "v17", "v18", "v19", "v20", "v21", "v22", "v23", "v24", "v25", "v26", "v27",
+    "v28", "v29", "v30", "v31",
+];
+
+/// Integer register (`x0`-`x31`).
+///
+/// `Display` prints the ABI name (e.g. `a0`) and `{:#}` prints the raw name (e.g. `x10`).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct XReg(u8);
+
+/// Floating-point register (`f0`-`f31`).
+///
+/// `Display` prints the ABI name (e.g. `fa0`) and `{:#}` prints the raw name (e.g. `f10`).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct FReg(u8);
+
+/// Vector register (`v0`-`v31`).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct VReg(u8);
+
+/// Register operand tagged with its register file.
+///
+/// # Example
+/// ```
+/// use raki::{Csr, Decode, FReg, Isa, Register, XReg};
+///
+/// // fsw fa0, 4(a1)
+/// let inst = 0x00a5_a227_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(inst.rs1_reg(), Some(Register::X(XReg::A1)));
+/// assert_eq!(inst.rs2_reg(), Some(Register::F(FReg::new(10).unwrap())));
+/// assert_eq!(inst.rd_reg(), None);
+///
+/// // csrrw a0, mstatus, a1: the csr number is not a register.
+/// let inst = 0x3005_9573_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(inst.rs2_reg(), None);
+/// assert_eq!(inst.csr(), Some(Csr::MSTATUS));
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Register {
+    /// Integer register
+    X(XReg),
+    /// Floating-point register
+    F(FReg),
+    /// Vector register
+    V(VReg),
+}
+
+macro_rules! register_common {
+    ($reg:ident) => {
+        impl $reg {
+            /// Create a register from its number.
+            /// It returns `None` if the number is not in 0-31.
+            #[must_use]
+            pub const fn new(num: usize) -> Option<Self> {
+                if num < 32 {
+                    #[allow(clippy::cast_possible_truncation)]
+                    Some($reg(num as u8))
+                } else {
+                    None
+                }
+            }
+
+            /// Register number.
+            #[must_use]
+            pub const fn num(self) -> usize {
+                self.0 as usize
+            }
+        }
+    };
+}
+
+register_common!(XReg);
+register_common!(FReg);
+register_common!(VReg);
+
+impl XReg {
+    /// ABI name (e.g. `a0`).
+    pub(crate) const fn abi_name(self) -> &'static str {
+        XREG_ABI_NAMES[self.0 as usize]
+    }
+
+    /// Raw name (e.g. `x10`).
+    pub(crate) const fn raw_name(self) -> &'static str {
+        XREG_RAW_NAMES[self.0 as usize]
+    }
+
+    /// Hard-wired zero
+    pub const ZERO: XReg = XReg(0);
+    /// Return address
+    pub const RA: XReg = XReg(1);
+    /// Stack pointer
+    pub const SP: XReg = XReg(2);
+    /// Global pointer
+    pub const GP: XReg = XReg(3);
+    /// Thread pointer
+    pub const TP: XReg = XReg(4);
+    /// Temporary / alternate link register
+    pub const T0: XReg = XReg(5);
+    /// Temporary
+    pub const T1: XReg = XReg(6);
+    /// Temporary
+    pub const T2: XReg = XReg(7);
+    /// Saved register / frame pointer
+    pub const S0: XReg = XReg(8);
+    /// Saved register
+    pub const S1: XReg = XReg(9);
+    /// Function argument / return value
+    pub const A0: XReg = XReg(10);
+    /// Function argument / return value
+    pub const A1: XReg = XReg(11);
+    /// Function argument
+    pub const A2: XReg = XReg(12);
+    /// Function argument
+    pub const A3: XReg = XReg(13);
+    /// Function argument
+    pub const A4: XReg = XReg(14);
+    /// Function argument
+    pub const A5: XReg = XReg(15);
+    /// Function argument
+    pub const A6: XReg = XReg(16);
+    /// Function argument
+    pub const A7: XReg = XReg(17);
+    /// Saved register
+    pub const S2: XReg = XReg(18);
+    /// Saved register
+    pub const S3: XReg = XReg(19);
+    /// Saved register
+    pub const S4: XReg = XReg(20);
+    /// Saved register
+    pub const S5: XReg = XReg(21);
+    /// Saved register
+    pub const S6: XReg = XReg(22);
+    /// Saved register
+    pub const S7: XReg = XReg(23);
+    /// Saved register
+    pub const S8: XReg = XReg(24);
+    /// Saved register
+    pub const S9: XReg = XReg(25);
+    /// Saved register
+    pub const S10: XReg = XReg(26);
+    /// Saved register
+    pub const S11: XReg = XReg(27);
+    /// Temporary
+    pub const T3: XReg = XReg(28);
+    /// Temporary
+    pub const T4: XReg = XReg(29);
+    /// Temporary
+    pub const T5: XReg = XReg(30);
+    /// Temporary
+    pub const T6: XReg = XReg(31);
+}
+
+impl FReg {
+    /// ABI name (e.g. `fa0`).
+    pub(crate) const fn abi_name(self) -> &'static str {
+        FREG_ABI_NAMES[self.0 as usize]
+    }
+
+    /// Raw name (e.g. `f10`).
+    pub(crate) const fn raw_name(self) -> &'static str {
+        FREG_RAW_NAMES[self.0 as usize]
+    }
+}
+
+impl VReg {
+    /// Name (e.g. `v8`).
+    pub(crate) const fn name(self) -> &'static str {
+        VREG_NAMES[self.0 as usize]
+    }
+}
+
+impl Display for XReg {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.raw_name())
+        } else {
+            write!(f, "{}", self.abi_name())
+        }
+    }
+}
+
+impl Display for FReg {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if f.alternate() {
+            write!(f, "{}", self.raw_name())
+        } else {
+            write!(f, "{}", self.abi_name())
+        }
+    }
+}
+
+impl Display for VReg {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl Display for Register {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match (self, f.alternate()) {
+            (Register::X(reg), true) => write!(f, "{reg:#}"),
+            (Register::X(reg), false) => write!(f, "{reg}"),
+            (Register::F(reg), true) => write!(f, "{reg:#}"),
+            (Register::F(reg), false) => write!(f, "{reg}"),
+            (Register::V(reg), _) => write!(f, "{reg}"),
+        }
+    }
+}
+
+/// Register file of an operand field.
+#[derive(Clone, Copy)]
+enum File {
+    X,
+    F,
+    V,
+}
+
+impl File {
+    fn register(self, num: Option<usize>) -> Option<Register> {
+        let num = num?;
+        match self {
+            File::X => XReg::new(num).map(Register::X),
+            File::F => FReg::new(num).map(Register::F),
+            File::V => VReg::new(num).map(Register::V),
+        }
+    }
+}
+
+impl Instruction {
+    /// Destination register.
+    #[must_use]
+    pub fn rd_reg(&self) -> Option<Register> {
+        self.register_files()[0]?.register(self.rd)
+    }
+
+    /// First source register (`None` if `rs1` holds an immediate).
+    #[must_use]
+    pub fn rs1_reg(&self) -> Option<Register> {
+        self.register_files()[1]?.register(self.rs1)
+    }
+
+    /// Second source register (`None` if `rs2` holds a csr number).
+    #[must_use]
+    pub fn rs2_reg(&self) -> Option<Register> {
+        self.register_files()[2]?.register(self.rs2)
+    }
+
+    /// Third source register (including the data of vector stores).
+    #[must_use]
+    pub fn rs3_reg(&self) -> Option<Register> {
+        self.register_files()[3]?.register(self.rs3)
+    }
+
+    /// Csr accessed by the instruction (including the counters read by `Zicntr`).
+    #[must_use]
+    pub fn csr(&self) -> Option<Csr> {
+        match (&self.inst_format, &self.opc) {
+            (InstFormat::CsrFormat | InstFormat::CsrUiFormat, _) => self.rs2.map(Csr::from_field),
+            (_, OpcodeKind::Zicntr(opc)) => Some(match opc {
+                ZicntrOpcode::RDCYCLE => Csr::CYCLE,
+                ZicntrOpcode::RDTIME => Csr::TIME,
+                ZicntrOpcode::RDINSTRET => Csr::INSTRET,
+                ZicntrOpcode::RDCYCLE_H => Csr::CYCLEH,
+                ZicntrOpcode::RDTIME_H => Csr::TIMEH,
+                ZicntrOpcode::RDINSTRET_H => Csr::INSTRETH,
+            }),
+            _ => None,
+        }
+    }
+
+    /// Register files of rd, rs1, rs2 and rs3 (`None` if the field is not a register).
+    fn register_files(&self) -> [Option<File>; 4] {
+        let (x, f, v) = (Some(File::X), Some(File::F), Some(File::V));
+        match self.inst_format {
+            InstFormat::CsrFormat => [x, x, None, None],
+            InstFormat::CsrUiFormat | InstFormat::VsetivliFormat | InstFormat::OnlyRd => {
+                [x, None, None, None]
+            }
+            InstFormat::OnlyRs1 => [None, x, None, None],
+            InstFormat::OnlyRs2 => [None, None, x, None],
+            InstFormat::FenceFormat | InstFormat::NoOperand => [None; 4],
+            InstFormat::CrFormat
+                if matches!(self.opc, OpcodeKind::C(COpcode::JR | COpcode::JALR)) =>
+            {
+                [None, x, None, None]
+            }
+            InstFormat::FlFormat | InstFormat::CflFormat | InstFormat::CflspFormat => {
+                [f, x, None, None]
+            }
+            InstFormat::FsFormat | InstFormat::CfsFormat | InstFormat::CfsspFormat => {
+                [None, x, f, None]
+            }
+            InstFormat::R4Format => [f, f, f, f],
+            InstFormat::FrFormat | InstFormat::FrUnaryFormat => [f, f, f, None],
+            InstFormat::FrCmpFormat | InstFormat::FrToXFormat => [x, f, f, None],
+            InstFormat::XToFrFormat => [f, x, None, None],
+            InstFormat::VlFormat | InstFormat::VlsFormat => [v, x, x, None],
+            InstFormat::VsFormat | InstFormat::VssFormat => [None, x, x, v],
+            InstFormat::VsxFormat => [None, x, v, v],
+            InstFormat::VvFormat | InstFormat::VmaVvFormat => [v, v, v, None],
+            InstFormat::VlxFormat | InstFormat::VxFormat | InstFormat::VmaVxFormat => {
+                [v, x, v, None]
+            }
+            InstFormat::VfFormat | InstFormat::VmaVfFormat => [v, f, v, None],
+            InstFormat::ViFormat | InstFormat::VUnaryFormat => [v, None, v, None],
+            InstFormat::VToXFormat => [x, None, v, None],
+            InstFormat::VToFFormat => [f, None, v, None],
+            _ => [x, x, x, None],
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_register {
+    #[test]
+    fn register_test() {
+        use super::{FReg, Register, VReg, XReg};
+        use crate::{Csr, Decode, Isa};
+
+        assert_eq!(XReg::new(32), None);
+        assert_eq!(XReg::new(10), Some(XReg::A0));
+        assert_eq!(XReg::SP.num(), 2);
+        assert_eq!(XReg::S0.to_string(), "s0");
+        assert_eq!(format!("{:#}", XReg::S0), "x8");
+        assert_eq!(format!("{:#}", Register::F(FReg::new(10).unwrap())), "f10");
+        assert_eq!(Register::V(VReg::new(8).unwrap()).to_string(), "v8");
+
+        let x = |num| XReg::new(num).map(Register::X);
+        let f = |num| FReg::new(num).map(Register::F);
+        let v = |num| VReg::new(num).map(Register::V);
+
+        // fmadd.s fa0, fa1, fa2, fa3
+        let inst = 0x68c5_8543_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(
+            [
+                inst.rd_reg(),
+                inst.rs1_reg(),
+                inst.rs2_reg(),
+                inst.rs3_reg()
+            ],
+            [f(10), f(11), f(12), f(13)]
+        );
+        // feq.s a0, fa0, fa1
+        let inst = 0xa0b5_2553_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!([inst.rd_reg(), inst.rs1_reg()], [x(10), f(10)]);
+        // vfadd.vf v8, v9, fa0
+        let inst = 0x0295_5457_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(
+            [inst.rd_reg(), inst.rs1_reg(), inst.rs2_reg()],
+            [v(8), f(10), v(9)]
+        );
+        // vsse16.v v8, (a0), a1
+        let inst = 0x0ab5_5427_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(
+            [
+                inst.rd_reg(),
+                inst.rs1_reg(),
+                inst.rs2_reg(),
+                inst.rs3_reg()
+            ],
+            [None, x(10), x(11), v(8)]
+        );
+        // vsetivli a0, 16, e64, m8, ta, mu
+        let inst = 0xc5b8_7557_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(inst.rs1_reg(), None);
+        // csrrwi a0, mtvec, 8
+        let inst = 0x3054_5573_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!([inst.rs1_reg(), inst.rs2_reg()], [None, None]);
+        assert_eq!(inst.csr(), Some(Csr::MTVEC));
+        // rdtime a0
+        let inst = 0xc010_2573_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(inst.csr(), Some(Csr::TIME));
+        // c.jr ra
+        let inst = 0x8082_u16.decode(Isa::Rv64).unwrap();
+        assert_eq!([inst.rs1_reg(), inst.rs2_reg()], [x(1), None]);
+        // add a0, a1, a2
+        let inst = 0x00c5_8533_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(inst.csr(), None);
+        assert_eq!(inst.rs3_reg(), None);
+    }
+}
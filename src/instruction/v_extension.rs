@@ -0,0 +1,1417 @@
+//! V extension Instruction.
+
+use super::{InstFormat, Opcode};
+use core::fmt::{self, Display, Formatter};
+
+/// Insturctions in V Extension.
+///
+/// Segment loads/stores (`nf != 0`) are not supported and fail to decode with `DecodingError::InvalidFunct6`.
+#[allow(non_camel_case_types)]
+#[derive(Debug, PartialEq)]
+pub enum VOpcode {
+    // Configuration-Setting
+    VSETVLI,
+    VSETIVLI,
+    VSETVL,
+
+    // Loads and Stores
+    VLE8_V,
+    VLE8FF_V,
+    VLSE8_V,
+    VLUXEI8_V,
+    VLOXEI8_V,
+    VLE16_V,
+    VLE16FF_V,
+    VLSE16_V,
+    VLUXEI16_V,
+    VLOXEI16_V,
+    VLE32_V,
+    VLE32FF_V,
+    VLSE32_V,
+    VLUXEI32_V,
+    VLOXEI32_V,
+    VLE64_V,
+    VLE64FF_V,
+    VLSE64_V,
+    VLUXEI64_V,
+    VLOXEI64_V,
+    VSE8_V,
+    VSSE8_V,
+    VSUXEI8_V,
+    VSOXEI8_V,
+    VSE16_V,
+    VSSE16_V,
+    VSUXEI16_V,
+    VSOXEI16_V,
+    VSE32_V,
+    VSSE32_V,
+    VSUXEI32_V,
+    VSOXEI32_V,
+    VSE64_V,
+    VSSE64_V,
+    VSUXEI64_V,
+    VSOXEI64_V,
+    VLM_V,
+    VSM_V,
+    VL1RE8_V,
+    VL1RE16_V,
+    VL1RE32_V,
+    VL1RE64_V,
+    VL2RE8_V,
+    VL2RE16_V,
+    VL2RE32_V,
+    VL2RE64_V,
+    VL4RE8_V,
+    VL4RE16_V,
+    VL4RE32_V,
+    VL4RE64_V,
+    VL8RE8_V,
+    VL8RE16_V,
+    VL8RE32_V,
+    VL8RE64_V,
+    VS1R_V,
+    VS2R_V,
+    VS4R_V,
+    VS8R_V,
+
+    // Integer Arithmetic (OPIVV, OPIVX, OPIVI)
+    VADD_VV,
+    VADD_VX,
+    VADD_VI,
+    VSUB_VV,
+    VSUB_VX,
+    VRSUB_VX,
+    VRSUB_VI,
+    VMINU_VV,
+    VMINU_VX,
+    VMIN_VV,
+    VMIN_VX,
+    VMAXU_VV,
+    VMAXU_VX,
+    VMAX_VV,
+    VMAX_VX,
+    VAND_VV,
+    VAND_VX,
+    VAND_VI,
+    VOR_VV,
+    VOR_VX,
+    VOR_VI,
+    VXOR_VV,
+    VXOR_VX,
+    VXOR_VI,
+    VRGATHER_VV,
+    VRGATHER_VX,
+    VRGATHER_VI,
+    VRGATHEREI16_VV,
+    VSLIDEUP_VX,
+    VSLIDEUP_VI,
+    VSLIDEDOWN_VX,
+    VSLIDEDOWN_VI,
+    VADC_VVM,
+    VADC_VXM,
+    VADC_VIM,
+    VMADC_VVM,
+    VMADC_VXM,
+    VMADC_VIM,
+    VMADC_VV,
+    VMADC_VX,
+    VMADC_VI,
+    VSBC_VVM,
+    VSBC_VXM,
+    VMSBC_VVM,
+    VMSBC_VXM,
+    VMSBC_VV,
+    VMSBC_VX,
+    VMERGE_VVM,
+    VMERGE_VXM,
+    VMERGE_VIM,
+    VMV_V_V,
+    VMV_V_X,
+    VMV_V_I,
+    VMSEQ_VV,
+    VMSEQ_VX,
+    VMSEQ_VI,
+    VMSNE_VV,
+    VMSNE_VX,
+    VMSNE_VI,
+    VMSLTU_VV,
+    VMSLTU_VX,
+    VMSLT_VV,
+    VMSLT_VX,
+    VMSLEU_VV,
+    VMSLEU_VX,
+    VMSLEU_VI,
+    VMSLE_VV,
+    VMSLE_VX,
+    VMSLE_VI,
+    VMSGTU_VX,
+    VMSGTU_VI,
+    VMSGT_VX,
+    VMSGT_VI,
+    VSADDU_VV,
+    VSADDU_VX,
+    VSADDU_VI,
+    VSADD_VV,
+    VSADD_VX,
+    VSADD_VI,
+    VSSUBU_VV,
+    VSSUBU_VX,
+    VSSUB_VV,
+    VSSUB_VX,
+    VSLL_VV,
+    VSLL_VX,
+    VSLL_VI,
+    VSMUL_VV,
+    VSMUL_VX,
+    VMV1R_V,
+    VMV2R_V,
+    VMV4R_V,
+    VMV8R_V,
+    VSRL_VV,
+    VSRL_VX,
+    VSRL_VI,
+    VSRA_VV,
+    VSRA_VX,
+    VSRA_VI,
+    VSSRL_VV,
+    VSSRL_VX,
+    VSSRL_VI,
+    VSSRA_VV,
+    VSSRA_VX,
+    VSSRA_VI,
+    VNSRL_WV,
+    VNSRL_WX,
+    VNSRL_WI,
+    VNSRA_WV,
+    VNSRA_WX,
+    VNSRA_WI,
+    VNCLIPU_WV,
+    VNCLIPU_WX,
+    VNCLIPU_WI,
+    VNCLIP_WV,
+    VNCLIP_WX,
+    VNCLIP_WI,
+    VWREDSUMU_VS,
+    VWREDSUM_VS,
+
+    // Integer Arithmetic (OPMVV, OPMVX)
+    VREDSUM_VS,
+    VREDAND_VS,
+    VREDOR_VS,
+    VREDXOR_VS,
+    VREDMINU_VS,
+    VREDMIN_VS,
+    VREDMAXU_VS,
+    VREDMAX_VS,
+    VAADDU_VV,
+    VAADDU_VX,
+    VAADD_VV,
+    VAADD_VX,
+    VASUBU_VV,
+    VASUBU_VX,
+    VASUB_VV,
+    VASUB_VX,
+    VSLIDE1UP_VX,
+    VSLIDE1DOWN_VX,
+    VMV_X_S,
+    VCPOP_M,
+    VFIRST_M,
+    VMV_S_X,
+    VZEXT_VF8,
+    VSEXT_VF8,
+    VZEXT_VF4,
+    VSEXT_VF4,
+    VZEXT_VF2,
+    VSEXT_VF2,
+    VMSBF_M,
+    VMSOF_M,
+    VMSIF_M,
+    VIOTA_M,
+    VID_V,
+    VCOMPRESS_VM,
+    VMANDN_MM,
+    VMAND_MM,
+    VMOR_MM,
+    VMXOR_MM,
+    VMORN_MM,
+    VMNAND_MM,
+    VMNOR_MM,
+    VMXNOR_MM,
+    VDIVU_VV,
+    VDIVU_VX,
+    VDIV_VV,
+    VDIV_VX,
+    VREMU_VV,
+    VREMU_VX,
+    VREM_VV,
+    VREM_VX,
+    VMULHU_VV,
+    VMULHU_VX,
+    VMUL_VV,
+    VMUL_VX,
+    VMULHSU_VV,
+    VMULHSU_VX,
+    VMULH_VV,
+    VMULH_VX,
+    VMADD_VV,
+    VMADD_VX,
+    VNMSUB_VV,
+    VNMSUB_VX,
+    VMACC_VV,
+    VMACC_VX,
+    VNMSAC_VV,
+    VNMSAC_VX,
+    VWADDU_VV,
+    VWADDU_VX,
+    VWADD_VV,
+    VWADD_VX,
+    VWSUBU_VV,
+    VWSUBU_VX,
+    VWSUB_VV,
+    VWSUB_VX,
+    VWADDU_WV,
+    VWADDU_WX,
+    VWADD_WV,
+    VWADD_WX,
+    VWSUBU_WV,
+    VWSUBU_WX,
+    VWSUB_WV,
+    VWSUB_WX,
+    VWMULU_VV,
+    VWMULU_VX,
+    VWMULSU_VV,
+    VWMULSU_VX,
+    VWMUL_VV,
+    VWMUL_VX,
+    VWMACCU_VV,
+    VWMACCU_VX,
+    VWMACC_VV,
+    VWMACC_VX,
+    VWMACCUS_VX,
+    VWMACCSU_VV,
+    VWMACCSU_VX,
+
+    // Floating-Point Arithmetic (OPFVV, OPFVF)
+    VFADD_VV,
+    VFADD_VF,
+    VFSUB_VV,
+    VFSUB_VF,
+    VFMIN_VV,
+    VFMIN_VF,
+    VFMAX_VV,
+    VFMAX_VF,
+    VFSGNJ_VV,
+    VFSGNJ_VF,
+    VFSGNJN_VV,
+    VFSGNJN_VF,
+    VFSGNJX_VV,
+    VFSGNJX_VF,
+    VFREDUSUM_VS,
+    VFREDOSUM_VS,
+    VFREDMIN_VS,
+    VFREDMAX_VS,
+    VFSLIDE1UP_VF,
+    VFSLIDE1DOWN_VF,
+    VFMV_F_S,
+    VFMV_S_F,
+    VFCVT_XU_F_V,
+    VFCVT_X_F_V,
+    VFCVT_F_XU_V,
+    VFCVT_F_X_V,
+    VFCVT_RTZ_XU_F_V,
+    VFCVT_RTZ_X_F_V,
+    VFWCVT_XU_F_V,
+    VFWCVT_X_F_V,
+    VFWCVT_F_XU_V,
+    VFWCVT_F_X_V,
+    VFWCVT_F_F_V,
+    VFWCVT_RTZ_XU_F_V,
+    VFWCVT_RTZ_X_F_V,
+    VFNCVT_XU_F_W,
+    VFNCVT_X_F_W,
+    VFNCVT_F_XU_W,
+    VFNCVT_F_X_W,
+    VFNCVT_F_F_W,
+    VFNCVT_ROD_F_F_W,
+    VFNCVT_RTZ_XU_F_W,
+    VFNCVT_RTZ_X_F_W,
+    VFSQRT_V,
+    VFRSQRT7_V,
+    VFREC7_V,
+    VFCLASS_V,
+    VFMERGE_VFM,
+    VFMV_V_F,
+    VMFEQ_VV,
+    VMFEQ_VF,
+    VMFLE_VV,
+    VMFLE_VF,
+    VMFLT_VV,
+    VMFLT_VF,
+    VMFNE_VV,
+    VMFNE_VF,
+    VMFGT_VF,
+    VMFGE_VF,
+    VFDIV_VV,
+    VFDIV_VF,
+    VFRDIV_VF,
+    VFMUL_VV,
+    VFMUL_VF,
+    VFRSUB_VF,
+    VFMADD_VV,
+    VFMADD_VF,
+    VFNMADD_VV,
+    VFNMADD_VF,
+    VFMSUB_VV,
+    VFMSUB_VF,
+    VFNMSUB_VV,
+    VFNMSUB_VF,
+    VFMACC_VV,
+    VFMACC_VF,
+    VFNMACC_VV,
+    VFNMACC_VF,
+    VFMSAC_VV,
+    VFMSAC_VF,
+    VFNMSAC_VV,
+    VFNMSAC_VF,
+    VFWADD_VV,
+    VFWADD_VF,
+    VFWSUB_VV,
+    VFWSUB_VF,
+    VFWREDUSUM_VS,
+    VFWREDOSUM_VS,
+    VFWADD_WV,
+    VFWADD_WF,
+    VFWSUB_WV,
+    VFWSUB_WF,
+    VFWMUL_VV,
+    VFWMUL_VF,
+    VFWMACC_VV,
+    VFWMACC_VF,
+    VFWNMACC_VV,
+    VFWNMACC_VF,
+    VFWMSAC_VV,
+    VFWMSAC_VF,
+    VFWNMSAC_VV,
+    VFWNMSAC_VF,
+}
+
+impl Display for VOpcode {
+    #[allow(clippy::too_many_lines)]
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            VOpcode::VSETVLI => write!(f, "vsetvli"),
+            VOpcode::VSETIVLI => write!(f, "vsetivli"),
+            VOpcode::VSETVL => write!(f, "vsetvl"),
+            VOpcode::VLE8_V => write!(f, "vle8.v"),
+            VOpcode::VLE8FF_V => write!(f, "vle8ff.v"),
+            VOpcode::VLSE8_V => write!(f, "vlse8.v"),
+            VOpcode::VLUXEI8_V => write!(f, "vluxei8.v"),
+            VOpcode::VLOXEI8_V => write!(f, "vloxei8.v"),
+            VOpcode::VLE16_V => write!(f, "vle16.v"),
+            VOpcode::VLE16FF_V => write!(f, "vle16ff.v"),
+            VOpcode::VLSE16_V => write!(f, "vlse16.v"),
+            VOpcode::VLUXEI16_V => write!(f, "vluxei16.v"),
+            VOpcode::VLOXEI16_V => write!(f, "vloxei16.v"),
+            VOpcode::VLE32_V => write!(f, "vle32.v"),
+            VOpcode::VLE32FF_V => write!(f, "vle32ff.v"),
+            VOpcode::VLSE32_V => write!(f, "vlse32.v"),
+            VOpcode::VLUXEI32_V => write!(f, "vluxei32.v"),
+            VOpcode::VLOXEI32_V => write!(f, "vloxei32.v"),
+            VOpcode::VLE64_V => write!(f, "vle64.v"),
+            VOpcode::VLE64FF_V => write!(f, "vle64ff.v"),
+            VOpcode::VLSE64_V => write!(f, "vlse64.v"),
+            VOpcode::VLUXEI64_V => write!(f, "vluxei64.v"),
+            VOpcode::VLOXEI64_V => write!(f, "vloxei64.v"),
+            VOpcode::VSE8_V => write!(f, "vse8.v"),
+            VOpcode::VSSE8_V => write!(f, "vsse8.v"),
+            VOpcode::VSUXEI8_V => write!(f, "vsuxei8.v"),
+            VOpcode::VSOXEI8_V => write!(f, "vsoxei8.v"),
+            VOpcode::VSE16_V => write!(f, "vse16.v"),
+            VOpcode::VSSE16_V => write!(f, "vsse16.v"),
+            VOpcode::VSUXEI16_V => write!(f, "vsuxei16.v"),
+            VOpcode::VSOXEI16_V => write!(f, "vsoxei16.v"),
+            VOpcode::VSE32_V => write!(f, "vse32.v"),
+            VOpcode::VSSE32_V => write!(f, "vsse32.v"),
+            VOpcode::VSUXEI32_V => write!(f, "vsuxei32.v"),
+            VOpcode::VSOXEI32_V => write!(f, "vsoxei32.v"),
+            VOpcode::VSE64_V => write!(f, "vse64.v"),
+            VOpcode::VSSE64_V => write!(f, "vsse64.v"),
+            VOpcode::VSUXEI64_V => write!(f, "vsuxei64.v"),
+            VOpcode::VSOXEI64_V => write!(f, "vsoxei64.v"),
+            VOpcode::VLM_V => write!(f, "vlm.v"),
+            VOpcode::VSM_V => write!(f, "vsm.v"),
+            VOpcode::VL1RE8_V => write!(f, "vl1re8.v"),
+            VOpcode::VL1RE16_V => write!(f, "vl1re16.v"),
+            VOpcode::VL1RE32_V => write!(f, "vl1re32.v"),
+            VOpcode::VL1RE64_V => write!(f, "vl1re64.v"),
+            VOpcode::VL2RE8_V => write!(f, "vl2re8.v"),
+            VOpcode::VL2RE16_V => write!(f, "vl2re16.v"),
+            VOpcode::VL2RE32_V => write!(f, "vl2re32.v"),
+            VOpcode::VL2RE64_V => write!(f, "vl2re64.v"),
+            VOpcode::VL4RE8_V => write!(f, "vl4re8.v"),
+            VOpcode::VL4RE16_V => write!(f, "vl4re16.v"),
+            VOpcode::VL4RE32_V => write!(f, "vl4re32.v"),
+            VOpcode::VL4RE64_V => write!(f, "vl4re64.v"),
+            VOpcode::VL8RE8_V => write!(f, "vl8re8.v"),
+            VOpcode::VL8RE16_V => write!(f, "vl8re16.v"),
+            VOpcode::VL8RE32_V => write!(f, "vl8re32.v"),
+            VOpcode::VL8RE64_V => write!(f, "vl8re64.v"),
+            VOpcode::VS1R_V => write!(f, "vs1r.v"),
+            VOpcode::VS2R_V => write!(f, "vs2r.v"),
+            VOpcode::VS4R_V => write!(f, "vs4r.v"),
+            VOpcode::VS8R_V => write!(f, "vs8r.v"),
+            VOpcode::VADD_VV => write!(f, "vadd.vv"),
+            VOpcode::VADD_VX => write!(f, "vadd.vx"),
+            VOpcode::VADD_VI => write!(f, "vadd.vi"),
+            VOpcode::VSUB_VV => write!(f, "vsub.vv"),
+            VOpcode::VSUB_VX => write!(f, "vsub.vx"),
+            VOpcode::VRSUB_VX => write!(f, "vrsub.vx"),
+            VOpcode::VRSUB_VI => write!(f, "vrsub.vi"),
+            VOpcode::VMINU_VV => write!(f, "vminu.vv"),
+            VOpcode::VMINU_VX => write!(f, "vminu.vx"),
+            VOpcode::VMIN_VV => write!(f, "vmin.vv"),
+            VOpcode::VMIN_VX => write!(f, "vmin.vx"),
+            VOpcode::VMAXU_VV => write!(f, "vmaxu.vv"),
+            VOpcode::VMAXU_VX => write!(f, "vmaxu.vx"),
+            VOpcode::VMAX_VV => write!(f, "vmax.vv"),
+            VOpcode::VMAX_VX => write!(f, "vmax.vx"),
+            VOpcode::VAND_VV => write!(f, "vand.vv"),
+            VOpcode::VAND_VX => write!(f, "vand.vx"),
+            VOpcode::VAND_VI => write!(f, "vand.vi"),
+            VOpcode::VOR_VV => write!(f, "vor.vv"),
+            VOpcode::VOR_VX => write!(f, "vor.vx"),
+            VOpcode::VOR_VI => write!(f, "vor.vi"),
+            VOpcode::VXOR_VV => write!(f, "vxor.vv"),
+            VOpcode::VXOR_VX => write!(f, "vxor.vx"),
+            VOpcode::VXOR_VI => write!(f, "vxor.vi"),
+            VOpcode::VRGATHER_VV => write!(f, "vrgather.vv"),
+            VOpcode::VRGATHER_VX => write!(f, "vrgather.vx"),
+            VOpcode::VRGATHER_VI => write!(f, "vrgather.vi"),
+            VOpcode::VRGATHEREI16_VV => write!(f, "vrgatherei16.vv"),
+            VOpcode::VSLIDEUP_VX => write!(f, "vslideup.vx"),
+            VOpcode::VSLIDEUP_VI => write!(f, "vslideup.vi"),
+            VOpcode::VSLIDEDOWN_VX => write!(f, "vslidedown.vx"),
+            VOpcode::VSLIDEDOWN_VI => write!(f, "vslidedown.vi"),
+            VOpcode::VADC_VVM => write!(f, "vadc.vvm"),
+            VOpcode::VADC_VXM => write!(f, "vadc.vxm"),
+            VOpcode::VADC_VIM => write!(f, "vadc.vim"),
+            VOpcode::VMADC_VVM => write!(f, "vmadc.vvm"),
+            VOpcode::VMADC_VXM => write!(f, "vmadc.vxm"),
+            VOpcode::VMADC_VIM => write!(f, "vmadc.vim"),
+            VOpcode::VMADC_VV => write!(f, "vmadc.vv"),
+            VOpcode::VMADC_VX => write!(f, "vmadc.vx"),
+            VOpcode::VMADC_VI => write!(f, "vmadc.vi"),
+            VOpcode::VSBC_VVM => write!(f, "vsbc.vvm"),
+            VOpcode::VSBC_VXM => write!(f, "vsbc.vxm"),
+            VOpcode::VMSBC_VVM => write!(f, "vmsbc.vvm"),
+            VOpcode::VMSBC_VXM => write!(f, "vmsbc.vxm"),
+            VOpcode::VMSBC_VV => write!(f, "vmsbc.vv"),
+            VOpcode::VMSBC_VX => write!(f, "vmsbc.vx"),
+            VOpcode::VMERGE_VVM => write!(f, "vmerge.vvm"),
+            VOpcode::VMERGE_VXM => write!(f, "vmerge.vxm"),
+            VOpcode::VMERGE_VIM => write!(f, "vmerge.vim"),
+            VOpcode::VMV_V_V => write!(f, "vmv.v.v"),
+            VOpcode::VMV_V_X => write!(f, "vmv.v.x"),
+            VOpcode::VMV_V_I => write!(f, "vmv.v.i"),
+            VOpcode::VMSEQ_VV => write!(f, "vmseq.vv"),
+            VOpcode::VMSEQ_VX => write!(f, "vmseq.vx"),
+            VOpcode::VMSEQ_VI => write!(f, "vmseq.vi"),
+            VOpcode::VMSNE_VV => write!(f, "vmsne.vv"),
+            VOpcode::VMSNE_VX => write!(f, "vmsne.vx"),
+            VOpcode::VMSNE_VI => write!(f, "vmsne.vi"),
+            VOpcode::VMSLTU_VV => write!(f, "vmsltu.vv"),
+            VOpcode::VMSLTU_VX => write!(f, "vmsltu.vx"),
+            VOpcode::VMSLT_VV => write!(f, "vmslt.vv"),
+            VOpcode::VMSLT_VX => write!(f, "vmslt.vx"),
+            VOpcode::VMSLEU_VV => write!(f, "vmsleu.vv"),
+            VOpcode::VMSLEU_VX => write!(f, "vmsleu.vx"),
+            VOpcode::VMSLEU_VI => write!(f, "vmsleu.vi"),
+            VOpcode::VMSLE_VV => write!(f, "vmsle.vv"),
+            VOpcode::VMSLE_VX => write!(f, "vmsle.vx"),
+            VOpcode::VMSLE_VI => write!(f, "vmsle.vi"),
+            VOpcode::VMSGTU_VX => write!(f, "vmsgtu.vx"),
+            VOpcode::VMSGTU_VI => write!(f, "vmsgtu.vi"),
+            VOpcode::VMSGT_VX => write!(f, "vmsgt.vx"),
+            VOpcode::VMSGT_VI => write!(f, "vmsgt.vi"),
+            VOpcode::VSADDU_VV => write!(f, "vsaddu.vv"),
+            VOpcode::VSADDU_VX => write!(f, "vsaddu.vx"),
+            VOpcode::VSADDU_VI => write!(f, "vsaddu.vi"),
+            VOpcode::VSADD_VV => write!(f, "vsadd.vv"),
+            VOpcode::VSADD_VX => write!(f, "vsadd.vx"),
+            VOpcode::VSADD_VI => write!(f, "vsadd.vi"),
+            VOpcode::VSSUBU_VV => write!(f, "vssubu.vv"),
+            VOpcode::VSSUBU_VX => write!(f, "vssubu.vx"),
+            VOpcode::VSSUB_VV => write!(f, "vssub.vv"),
+            VOpcode::VSSUB_VX => write!(f, "vssub.vx"),
+            VOpcode::VSLL_VV => write!(f, "vsll.vv"),
+            VOpcode::VSLL_VX => write!(f, "vsll.vx"),
+            VOpcode::VSLL_VI => write!(f, "vsll.vi"),
+            VOpcode::VSMUL_VV => write!(f, "vsmul.vv"),
+            VOpcode::VSMUL_VX => write!(f, "vsmul.vx"),
+            VOpcode::VMV1R_V => write!(f, "vmv1r.v"),
+            VOpcode::VMV2R_V => write!(f, "vmv2r.v"),
+            VOpcode::VMV4R_V => write!(f, "vmv4r.v"),
+            VOpcode::VMV8R_V => write!(f, "vmv8r.v"),
+            VOpcode::VSRL_VV => write!(f, "vsrl.vv"),
+            VOpcode::VSRL_VX => write!(f, "vsrl.vx"),
+            VOpcode::VSRL_VI => write!(f, "vsrl.vi"),
+            VOpcode::VSRA_VV => write!(f, "vsra.vv"),
+            VOpcode::VSRA_VX => write!(f, "vsra.vx"),
+            VOpcode::VSRA_VI => write!(f, "vsra.vi"),
+            VOpcode::VSSRL_VV => write!(f, "vssrl.vv"),
+            VOpcode::VSSRL_VX => write!(f, "vssrl.vx"),
+            VOpcode::VSSRL_VI => write!(f, "vssrl.vi"),
+            VOpcode::VSSRA_VV => write!(f, "vssra.vv"),
+            VOpcode::VSSRA_VX => write!(f, "vssra.vx"),
+            VOpcode::VSSRA_VI => write!(f, "vssra.vi"),
+            VOpcode::VNSRL_WV => write!(f, "vnsrl.wv"),
+            VOpcode::VNSRL_WX => write!(f, "vnsrl.wx"),
+            VOpcode::VNSRL_WI => write!(f, "vnsrl.wi"),
+            VOpcode::VNSRA_WV => write!(f, "vnsra.wv"),
+            VOpcode::VNSRA_WX => write!(f, "vnsra.wx"),
+            VOpcode::VNSRA_WI => write!(f, "vnsra.wi"),
+            VOpcode::VNCLIPU_WV => write!(f, "vnclipu.wv"),
+            VOpcode::VNCLIPU_WX => write!(f, "vnclipu.wx"),
+            VOpcode::VNCLIPU_WI => write!(f, "vnclipu.wi"),
+            VOpcode::VNCLIP_WV => write!(f, "vnclip.wv"),
+            VOpcode::VNCLIP_WX => write!(f, "vnclip.wx"),
+            VOpcode::VNCLIP_WI => write!(f, "vnclip.wi"),
+            VOpcode::VWREDSUMU_VS => write!(f, "vwredsumu.vs"),
+            VOpcode::VWREDSUM_VS => write!(f, "vwredsum.vs"),
+            VOpcode::VREDSUM_VS => write!(f, "vredsum.vs"),
+            VOpcode::VREDAND_VS => write!(f, "vredand.vs"),
+            VOpcode::VREDOR_VS => write!(f, "vredor.vs"),
+            VOpcode::VREDXOR_VS => write!(f, "vredxor.vs"),
+            VOpcode::VREDMINU_VS => write!(f, "vredminu.vs"),
+            VOpcode::VREDMIN_VS => write!(f, "vredmin.vs"),
+            VOpcode::VREDMAXU_VS => write!(f, "vredmaxu.vs"),
+            VOpcode::VREDMAX_VS => write!(f, "vredmax.vs"),
+            VOpcode::VAADDU_VV => write!(f, "vaaddu.vv"),
+            VOpcode::VAADDU_VX => write!(f, "vaaddu.vx"),
+            VOpcode::VAADD_VV => write!(f, "vaadd.vv"),
+            VOpcode::VAADD_VX => write!(f, "vaadd.vx"),
+            VOpcode::VASUBU_VV => write!(f, "vasubu.vv"),
+            VOpcode::VASUBU_VX => write!(f, "vasubu.vx"),
+            VOpcode::VASUB_VV => write!(f, "vasub.vv"),
+            VOpcode::VASUB_VX => write!(f, "vasub.vx"),
+            VOpcode::VSLIDE1UP_VX => write!(f, "vslide1up.vx"),
+            VOpcode::VSLIDE1DOWN_VX => write!(f, "vslide1down.vx"),
+            VOpcode::VMV_X_S => write!(f, "vmv.x.s"),
+            VOpcode::VCPOP_M => write!(f, "vcpop.m"),
+            VOpcode::VFIRST_M => write!(f, "vfirst.m"),
+            VOpcode::VMV_S_X => write!(f, "vmv.s.x"),
+            VOpcode::VZEXT_VF8 => write!(f, "vzext.vf8"),
+            VOpcode::VSEXT_VF8 => write!(f, "vsext.vf8"),
+            VOpcode::VZEXT_VF4 => write!(f, "vzext.vf4"),
+            VOpcode::VSEXT_VF4 => write!(f, "vsext.vf4"),
+            VOpcode::VZEXT_VF2 => write!(f, "vzext.vf2"),
+            VOpcode::VSEXT_VF2 => write!(f, "vsext.vf2"),
+            VOpcode::VMSBF_M => write!(f, "vmsbf.m"),
+            VOpcode::VMSOF_M => write!(f, "vmsof.m"),
+            VOpcode::VMSIF_M => write!(f, "vmsif.m"),
+            VOpcode::VIOTA_M => write!(f, "viota.m"),
+            VOpcode::VID_V => write!(f, "vid.v"),
+            VOpcode::VCOMPRESS_VM => write!(f, "vcompress.vm"),
+            VOpcode::VMANDN_MM => write!(f, "vmandn.mm"),
+            VOpcode::VMAND_MM => write!(f, "vmand.mm"),
+            VOpcode::VMOR_MM => write!(f, "vmor.mm"),
+            VOpcode::VMXOR_MM => write!(f, "vmxor.mm"),
+            VOpcode::VMORN_MM => write!(f, "vmorn.mm"),
+            VOpcode::VMNAND_MM => write!(f, "vmnand.mm"),
+            VOpcode::VMNOR_MM => write!(f, "vmnor.mm"),
+            VOpcode::VMXNOR_MM => write!(f, "vmxnor.mm"),
+            VOpcode::VDIVU_VV => write!(f, "vdivu.vv"),
+            VOpcode::VDIVU_VX => write!(f, "vdivu.vx"),
+            VOpcode::VDIV_VV => write!(f, "vdiv.vv"),
+            VOpcode::VDIV_VX => write!(f, "vdiv.vx"),
+            VOpcode::VREMU_VV => write!(f, "vremu.vv"),
+            VOpcode::VREMU_VX => write!(f, "vremu.vx"),
+            VOpcode::VREM_VV => write!(f, "vrem.vv"),
+            VOpcode::VREM_VX => write!(f, "vrem.vx"),
+            VOpcode::VMULHU_VV => write!(f, "vmulhu.vv"),
+            VOpcode::VMULHU_VX => write!(f, "vmulhu.vx"),
+            VOpcode::VMUL_VV => write!(f, "vmul.vv"),
+            VOpcode::VMUL_VX => write!(f, "vmul.vx"),
+            VOpcode::VMULHSU_VV => write!(f, "vmulhsu.vv"),
+            VOpcode::VMULHSU_VX => write!(f, "vmulhsu.vx"),
+            VOpcode::VMULH_VV => write!(f, "vmulh.vv"),
+            VOpcode::VMULH_VX => write!(f, "vmulh.vx"),
+            VOpcode::VMADD_VV => write!(f, "vmadd.vv"),
+            VOpcode::VMADD_VX => write!(f, "vmadd.vx"),
+            VOpcode::VNMSUB_VV => write!(f, "vnmsub.vv"),
+            VOpcode::VNMSUB_VX => write!(f, "vnmsub.vx"),
+            VOpcode::VMACC_VV => write!(f, "vmacc.vv"),
+            VOpcode::VMACC_VX => write!(f, "vmacc.vx"),
+            VOpcode::VNMSAC_VV => write!(f, "vnmsac.vv"),
+            VOpcode::VNMSAC_VX => write!(f, "vnmsac.vx"),
+            VOpcode::VWADDU_VV => write!(f, "vwaddu.vv"),
+            VOpcode::VWADDU_VX => write!(f, "vwaddu.vx"),
+            VOpcode::VWADD_VV => write!(f, "vwadd.vv"),
+            VOpcode::VWADD_VX => write!(f, "vwadd.vx"),
+            VOpcode::VWSUBU_VV => write!(f, "vwsubu.vv"),
+            VOpcode::VWSUBU_VX => write!(f, "vwsubu.vx"),
+            VOpcode::VWSUB_VV => write!(f, "vwsub.vv"),
+            VOpcode::VWSUB_VX => write!(f, "vwsub.vx"),
+            VOpcode::VWADDU_WV => write!(f, "vwaddu.wv"),
+            VOpcode::VWADDU_WX => write!(f, "vwaddu.wx"),
+            VOpcode::VWADD_WV => write!(f, "vwadd.wv"),
+            VOpcode::VWADD_WX => write!(f, "vwadd.wx"),
+            VOpcode::VWSUBU_WV => write!(f, "vwsubu.wv"),
+            VOpcode::VWSUBU_WX => write!(f, "vwsubu.wx"),
+            VOpcode::VWSUB_WV => write!(f, "vwsub.wv"),
+            VOpcode::VWSUB_WX => write!(f, "vwsub.wx"),
+            VOpcode::VWMULU_VV => write!(f, "vwmulu.vv"),
+            VOpcode::VWMULU_VX => write!(f, "vwmulu.vx"),
+            VOpcode::VWMULSU_VV => write!(f, "vwmulsu.vv"),
+            VOpcode::VWMULSU_VX => write!(f, "vwmulsu.vx"),
+            VOpcode::VWMUL_VV => write!(f, "vwmul.vv"),
+            VOpcode::VWMUL_VX => write!(f, "vwmul.vx"),
+            VOpcode::VWMACCU_VV => write!(f, "vwmaccu.vv"),
+            VOpcode::VWMACCU_VX => write!(f, "vwmaccu.vx"),
+            VOpcode::VWMACC_VV => write!(f, "vwmacc.vv"),
+            VOpcode::VWMACC_VX => write!(f, "vwmacc.vx"),
+            VOpcode::VWMACCUS_VX => write!(f, "vwmaccus.vx"),
+            VOpcode::VWMACCSU_VV => write!(f, "vwmaccsu.vv"),
+            VOpcode::VWMACCSU_VX => write!(f, "vwmaccsu.vx"),
+            VOpcode::VFADD_VV => write!(f, "vfadd.vv"),
+            VOpcode::VFADD_VF => write!(f, "vfadd.vf"),
+            VOpcode::VFSUB_VV => write!(f, "vfsub.vv"),
+            VOpcode::VFSUB_VF => write!(f, "vfsub.vf"),
+            VOpcode::VFMIN_VV => write!(f, "vfmin.vv"),
+            VOpcode::VFMIN_VF => write!(f, "vfmin.vf"),
+            VOpcode::VFMAX_VV => write!(f, "vfmax.vv"),
+            VOpcode::VFMAX_VF => write!(f, "vfmax.vf"),
+            VOpcode::VFSGNJ_VV => write!(f, "vfsgnj.vv"),
+            VOpcode::VFSGNJ_VF => write!(f, "vfsgnj.vf"),
+            VOpcode::VFSGNJN_VV => write!(f, "vfsgnjn.vv"),
+            VOpcode::VFSGNJN_VF => write!(f, "vfsgnjn.vf"),
+            VOpcode::VFSGNJX_VV => write!(f, "vfsgnjx.vv"),
+            VOpcode::VFSGNJX_VF => write!(f, "vfsgnjx.vf"),
+            VOpcode::VFREDUSUM_VS => write!(f, "vfredusum.vs"),
+            VOpcode::VFREDOSUM_VS => write!(f, "vfredosum.vs"),
+            VOpcode::VFREDMIN_VS => write!(f, "vfredmin.vs"),
+            VOpcode::VFREDMAX_VS => write!(f, "vfredmax.vs"),
+            VOpcode::VFSLIDE1UP_VF => write!(f, "vfslide1up.vf"),
+            VOpcode::VFSLIDE1DOWN_VF => write!(f, "vfslide1down.vf"),
+            VOpcode::VFMV_F_S => write!(f, "vfmv.f.s"),
+            VOpcode::VFMV_S_F => write!(f, "vfmv.s.f"),
+            VOpcode::VFCVT_XU_F_V => write!(f, "vfcvt.xu.f.v"),
+            VOpcode::VFCVT_X_F_V => write!(f, "vfcvt.x.f.v"),
+            VOpcode::VFCVT_F_XU_V => write!(f, "vfcvt.f.xu.v"),
+            VOpcode::VFCVT_F_X_V => write!(f, "vfcvt.f.x.v"),
+            VOpcode::VFCVT_RTZ_XU_F_V => write!(f, "vfcvt.rtz.xu.f.v"),
+            VOpcode::VFCVT_RTZ_X_F_V => write!(f, "vfcvt.rtz.x.f.v"),
+            VOpcode::VFWCVT_XU_F_V => write!(f, "vfwcvt.xu.f.v"),
+            VOpcode::VFWCVT_X_F_V => write!(f, "vfwcvt.x.f.v"),
+            VOpcode::VFWCVT_F_XU_V => write!(f, "vfwcvt.f.xu.v"),
+            VOpcode::VFWCVT_F_X_V => write!(f, "vfwcvt.f.x.v"),
+            VOpcode::VFWCVT_F_F_V => write!(f, "vfwcvt.f.f.v"),
+            VOpcode::VFWCVT_RTZ_XU_F_V => write!(f, "vfwcvt.rtz.xu.f.v"),
+            VOpcode::VFWCVT_RTZ_X_F_V => write!(f, "vfwcvt.rtz.x.f.v"),
+            VOpcode::VFNCVT_XU_F_W => write!(f, "vfncvt.xu.f.w"),
+            VOpcode::VFNCVT_X_F_W => write!(f, "vfncvt.x.f.w"),
+            VOpcode::VFNCVT_F_XU_W => write!(f, "vfncvt.f.xu.w"),
+            VOpcode::VFNCVT_F_X_W => write!(f, "vfncvt.f.x.w"),
+            VOpcode::VFNCVT_F_F_W => write!(f, "vfncvt.f.f.w"),
+            VOpcode::VFNCVT_ROD_F_F_W => write!(f, "vfncvt.rod.f.f.w"),
+            VOpcode::VFNCVT_RTZ_XU_F_W => write!(f, "vfncvt.rtz.xu.f.w"),
+            VOpcode::VFNCVT_RTZ_X_F_W => write!(f, "vfncvt.rtz.x.f.w"),
+            VOpcode::VFSQRT_V => write!(f, "vfsqrt.v"),
+            VOpcode::VFRSQRT7_V => write!(f, "vfrsqrt7.v"),
+            VOpcode::VFREC7_V => write!(f, "vfrec7.v"),
+            VOpcode::VFCLASS_V => write!(f, "vfclass.v"),
+            VOpcode::VFMERGE_VFM => write!(f, "vfmerge.vfm"),
+            VOpcode::VFMV_V_F => write!(f, "vfmv.v.f"),
+            VOpcode::VMFEQ_VV => write!(f, "vmfeq.vv"),
+            VOpcode::VMFEQ_VF => write!(f, "vmfeq.vf"),
+            VOpcode::VMFLE_VV => write!(f, "vmfle.vv"),
+            VOpcode::VMFLE_VF => write!(f, "vmfle.vf"),
+            VOpcode::VMFLT_VV => write!(f, "vmflt.vv"),
+            VOpcode::VMFLT_VF => write!(f, "vmflt.vf"),
+            VOpcode::VMFNE_VV => write!(f, "vmfne.vv"),
+            VOpcode::VMFNE_VF => write!(f, "vmfne.vf"),
+            VOpcode::VMFGT_VF => write!(f, "vmfgt.vf"),
+            VOpcode::VMFGE_VF => write!(f, "vmfge.vf"),
+            VOpcode::VFDIV_VV => write!(f, "vfdiv.vv"),
+            VOpcode::VFDIV_VF => write!(f, "vfdiv.vf"),
+            VOpcode::VFRDIV_VF => write!(f, "vfrdiv.vf"),
+            VOpcode::VFMUL_VV => write!(f, "vfmul.vv"),
+            VOpcode::VFMUL_VF => write!(f, "vfmul.vf"),
+            VOpcode::VFRSUB_VF => write!(f, "vfrsub.vf"),
+            VOpcode::VFMADD_VV => write!(f, "vfmadd.vv"),
+            VOpcode::VFMADD_VF => write!(f, "vfmadd.vf"),
+            VOpcode::VFNMADD_VV => write!(f, "vfnmadd.vv"),
+            VOpcode::VFNMADD_VF => write!(f, "vfnmadd.vf"),
+            VOpcode::VFMSUB_VV => write!(f, "vfmsub.vv"),
+            VOpcode::VFMSUB_VF => write!(f, "vfmsub.vf"),
+            VOpcode::VFNMSUB_VV => write!(f, "vfnmsub.vv"),
+            VOpcode::VFNMSUB_VF => write!(f, "vfnmsub.vf"),
+            VOpcode::VFMACC_VV => write!(f, "vfmacc.vv"),
+            VOpcode::VFMACC_VF => write!(f, "vfmacc.vf"),
+            VOpcode::VFNMACC_VV => write!(f, "vfnmacc.vv"),
+            VOpcode::VFNMACC_VF => write!(f, "vfnmacc.vf"),
+            VOpcode::VFMSAC_VV => write!(f, "vfmsac.vv"),
+            VOpcode::VFMSAC_VF => write!(f, "vfmsac.vf"),
+            VOpcode::VFNMSAC_VV => write!(f, "vfnmsac.vv"),
+            VOpcode::VFNMSAC_VF => write!(f, "vfnmsac.vf"),
+            VOpcode::VFWADD_VV => write!(f, "vfwadd.vv"),
+            VOpcode::VFWADD_VF => write!(f, "vfwadd.vf"),
+            VOpcode::VFWSUB_VV => write!(f, "vfwsub.vv"),
+            VOpcode::VFWSUB_VF => write!(f, "vfwsub.vf"),
+            VOpcode::VFWREDUSUM_VS => write!(f, "vfwredusum.vs"),
+            VOpcode::VFWREDOSUM_VS => write!(f, "vfwredosum.vs"),
+            VOpcode::VFWADD_WV => write!(f, "vfwadd.wv"),
+            VOpcode::VFWADD_WF => write!(f, "vfwadd.wf"),
+            VOpcode::VFWSUB_WV => write!(f, "vfwsub.wv"),
+            VOpcode::VFWSUB_WF => write!(f, "vfwsub.wf"),
+            VOpcode::VFWMUL_VV => write!(f, "vfwmul.vv"),
+            VOpcode::VFWMUL_VF => write!(f, "vfwmul.vf"),
+            VOpcode::VFWMACC_VV => write!(f, "vfwmacc.vv"),
+            VOpcode::VFWMACC_VF => write!(f, "vfwmacc.vf"),
+            VOpcode::VFWNMACC_VV => write!(f, "vfwnmacc.vv"),
+            VOpcode::VFWNMACC_VF => write!(f, "vfwnmacc.vf"),
+            VOpcode::VFWMSAC_VV => write!(f, "vfwmsac.vv"),
+            VOpcode::VFWMSAC_VF => write!(f, "vfwmsac.vf"),
+            VOpcode::VFWNMSAC_VV => write!(f, "vfwnmsac.vv"),
+            VOpcode::VFWNMSAC_VF => write!(f, "vfwnmsac.vf"),
+        }
+    }
+}
+
+impl VOpcode {
+    /// Whether the instruction operates on floating-point elements (requires F).
+    #[allow(clippy::too_many_lines)]
+    pub(crate) fn is_floating_point(&self) -> bool {
+        matches!(
+            self,
+            VOpcode::VFADD_VV
+                | VOpcode::VFADD_VF
+                | VOpcode::VFSUB_VV
+                | VOpcode::VFSUB_VF
+                | VOpcode::VFMIN_VV
+                | VOpcode::VFMIN_VF
+                | VOpcode::VFMAX_VV
+                | VOpcode::VFMAX_VF
+                | VOpcode::VFSGNJ_VV
+                | VOpcode::VFSGNJ_VF
+                | VOpcode::VFSGNJN_VV
+                | VOpcode::VFSGNJN_VF
+                | VOpcode::VFSGNJX_VV
+                | VOpcode::VFSGNJX_VF
+                | VOpcode::VFREDUSUM_VS
+                | VOpcode::VFREDOSUM_VS
+                | VOpcode::VFREDMIN_VS
+                | VOpcode::VFREDMAX_VS
+                | VOpcode::VFSLIDE1UP_VF
+                | VOpcode::VFSLIDE1DOWN_VF
+                | VOpcode::VFMV_F_S
+                | VOpcode::VFMV_S_F
+                | VOpcode::VFCVT_XU_F_V
+                | VOpcode::VFCVT_X_F_V
+                | VOpcode::VFCVT_F_XU_V
+                | VOpcode::VFCVT_F_X_V
+                | VOpcode::VFCVT_RTZ_XU_F_V
+                | VOpcode::VFCVT_RTZ_X_F_V
+                | VOpcode::VFWCVT_XU_F_V
+                | VOpcode::VFWCVT_X_F_V
+                | VOpcode::VFWCVT_F_XU_V
+                | VOpcode::VFWCVT_F_X_V
+                | VOpcode::VFWCVT_F_F_V
+                | VOpcode::VFWCVT_RTZ_XU_F_V
+                | VOpcode::VFWCVT_RTZ_X_F_V
+                | VOpcode::VFNCVT_XU_F_W
+                | VOpcode::VFNCVT_X_F_W
+                | VOpcode::VFNCVT_F_XU_W
+                | VOpcode::VFNCVT_F_X_W
+                | VOpcode::VFNCVT_F_F_W
+                | VOpcode::VFNCVT_ROD_F_F_W
+                | VOpcode::VFNCVT_RTZ_XU_F_W
+                | VOpcode::VFNCVT_RTZ_X_F_W
+                | VOpcode::VFSQRT_V
+                | VOpcode::VFRSQRT7_V
+                | VOpcode::VFREC7_V
+                | VOpcode::VFCLASS_V
+                | VOpcode::VFMERGE_VFM
+                | VOpcode::VFMV_V_F
+                | VOpcode::VMFEQ_VV
+                | VOpcode::VMFEQ_VF
+                | VOpcode::VMFLE_VV
+                | VOpcode::VMFLE_VF
+                | VOpcode::VMFLT_VV
+                | VOpcode::VMFLT_VF
+                | VOpcode::VMFNE_VV
+                | VOpcode::VMFNE_VF
+                | VOpcode::VMFGT_VF
+                | VOpcode::VMFGE_VF
+                | VOpcode::VFDIV_VV
+                | VOpcode::VFDIV_VF
+                | VOpcode::VFRDIV_VF
+                | VOpcode::VFMUL_VV
+                | VOpcode::VFMUL_VF
+                | VOpcode::VFRSUB_VF
+                | VOpcode::VFMADD_VV
+                | VOpcode::VFMADD_VF
+                | VOpcode::VFNMADD_VV
+                | VOpcode::VFNMADD_VF
+                | VOpcode::VFMSUB_VV
+                | VOpcode::VFMSUB_VF
+                | VOpcode::VFNMSUB_VV
+                | VOpcode::VFNMSUB_VF
+                | VOpcode::VFMACC_VV
+                | VOpcode::VFMACC_VF
+                | VOpcode::VFNMACC_VV
+                | VOpcode::VFNMACC_VF
+                | VOpcode::VFMSAC_VV
+                | VOpcode::VFMSAC_VF
+                | VOpcode::VFNMSAC_VV
+                | VOpcode::VFNMSAC_VF
+                | VOpcode::VFWADD_VV
+                | VOpcode::VFWADD_VF
+                | VOpcode::VFWSUB_VV
+                | VOpcode::VFWSUB_VF
+                | VOpcode::VFWREDUSUM_VS
+                | VOpcode::VFWREDOSUM_VS
+                | VOpcode::VFWADD_WV
+                | VOpcode::VFWADD_WF
+                | VOpcode::VFWSUB_WV
+                | VOpcode::VFWSUB_WF
+                | VOpcode::VFWMUL_VV
+                | VOpcode::VFWMUL_VF
+                | VOpcode::VFWMACC_VV
+                | VOpcode::VFWMACC_VF
+                | VOpcode::VFWNMACC_VV
+                | VOpcode::VFWNMACC_VF
+                | VOpcode::VFWMSAC_VV
+                | VOpcode::VFWMSAC_VF
+                | VOpcode::VFWNMSAC_VV
+                | VOpcode::VFWNMSAC_VF
+        )
+    }
+
+    /// Whether the instruction is only encoded with vm = 1 (vm = 0 is reserved).
+    pub(crate) fn is_unmasked_only(&self) -> bool {
+        matches!(
+            self,
+            VOpcode::VLM_V
+                | VOpcode::VSM_V
+                | VOpcode::VL1RE8_V
+                | VOpcode::VL1RE16_V
+                | VOpcode::VL1RE32_V
+                | VOpcode::VL1RE64_V
+                | VOpcode::VL2RE8_V
+                | VOpcode::VL2RE16_V
+                | VOpcode::VL2RE32_V
+                | VOpcode::VL2RE64_V
+                | VOpcode::VL4RE8_V
+                | VOpcode::VL4RE16_V
+                | VOpcode::VL4RE32_V
+                | VOpcode::VL4RE64_V
+                | VOpcode::VL8RE8_V
+                | VOpcode::VL8RE16_V
+                | VOpcode::VL8RE32_V
+                | VOpcode::VL8RE64_V
+                | VOpcode::VS1R_V
+                | VOpcode::VS2R_V
+                | VOpcode::VS4R_V
+                | VOpcode::VS8R_V
+                | VOpcode::VMV1R_V
+                | VOpcode::VMV2R_V
+                | VOpcode::VMV4R_V
+                | VOpcode::VMV8R_V
+                | VOpcode::VMV_X_S
+                | VOpcode::VMV_S_X
+                | VOpcode::VFMV_F_S
+                | VOpcode::VFMV_S_F
+                | VOpcode::VCOMPRESS_VM
+                | VOpcode::VMANDN_MM
+                | VOpcode::VMAND_MM
+                | VOpcode::VMOR_MM
+                | VOpcode::VMXOR_MM
+                | VOpcode::VMORN_MM
+                | VOpcode::VMNAND_MM
+                | VOpcode::VMNOR_MM
+                | VOpcode::VMXNOR_MM
+        )
+    }
+}
+
+impl Opcode for VOpcode {
+    #[allow(clippy::too_many_lines)]
+    fn get_format(&self) -> InstFormat {
+        match self {
+            VOpcode::VSETVLI => InstFormat::VsetvliFormat,
+            VOpcode::VSETIVLI => InstFormat::VsetivliFormat,
+            VOpcode::VSETVL => InstFormat::RFormat,
+            VOpcode::VLE8_V
+            | VOpcode::VLE8FF_V
+            | VOpcode::VLE16_V
+            | VOpcode::VLE16FF_V
+            | VOpcode::VLE32_V
+            | VOpcode::VLE32FF_V
+            | VOpcode::VLE64_V
+            | VOpcode::VLE64FF_V
+            | VOpcode::VLM_V
+            | VOpcode::VL1RE8_V
+            | VOpcode::VL1RE16_V
+            | VOpcode::VL1RE32_V
+            | VOpcode::VL1RE64_V
+            | VOpcode::VL2RE8_V
+            | VOpcode::VL2RE16_V
+            | VOpcode::VL2RE32_V
+            | VOpcode::VL2RE64_V
+            | VOpcode::VL4RE8_V
+            | VOpcode::VL4RE16_V
+            | VOpcode::VL4RE32_V
+            | VOpcode::VL4RE64_V
+            | VOpcode::VL8RE8_V
+            | VOpcode::VL8RE16_V
+            | VOpcode::VL8RE32_V
+            | VOpcode::VL8RE64_V => InstFormat::VlFormat,
+            VOpcode::VLSE8_V | VOpcode::VLSE16_V | VOpcode::VLSE32_V | VOpcode::VLSE64_V => {
+                InstFormat::VlsFormat
+            }
+            VOpcode::VLUXEI8_V
+            | VOpcode::VLOXEI8_V
+            | VOpcode::VLUXEI16_V
+            | VOpcode::VLOXEI16_V
+            | VOpcode::VLUXEI32_V
+            | VOpcode::VLOXEI32_V
+            | VOpcode::VLUXEI64_V
+            | VOpcode::VLOXEI64_V => InstFormat::VlxFormat,
+            VOpcode::VSE8_V
+            | VOpcode::VSE16_V
+            | VOpcode::VSE32_V
+            | VOpcode::VSE64_V
+            | VOpcode::VSM_V
+            | VOpcode::VS1R_V
+            | VOpcode::VS2R_V
+            | VOpcode::VS4R_V
+            | VOpcode::VS8R_V => InstFormat::VsFormat,
+            VOpcode::VSSE8_V | VOpcode::VSSE16_V | VOpcode::VSSE32_V | VOpcode::VSSE64_V => {
+                InstFormat::VssFormat
+            }
+            VOpcode::VSUXEI8_V
+            | VOpcode::VSOXEI8_V
+            | VOpcode::VSUXEI16_V
+            | VOpcode::VSOXEI16_V
+            | VOpcode::VSUXEI32_V
+            | VOpcode::VSOXEI32_V
+            | VOpcode::VSUXEI64_V
+            | VOpcode::VSOXEI64_V => InstFormat::VsxFormat,
+            VOpcode::VADD_VV
+            | VOpcode::VSUB_VV
+            | VOpcode::VMINU_VV
+            | VOpcode::VMIN_VV
+            | VOpcode::VMAXU_VV
+            | VOpcode::VMAX_VV
+            | VOpcode::VAND_VV
+            | VOpcode::VOR_VV
+            | VOpcode::VXOR_VV
+            | VOpcode::VRGATHER_VV
+            | VOpcode::VRGATHEREI16_VV
+            | VOpcode::VADC_VVM
+            | VOpcode::VMADC_VVM
+            | VOpcode::VMADC_VV
+            | VOpcode::VSBC_VVM
+            | VOpcode::VMSBC_VVM
+            | VOpcode::VMSBC_VV
+            | VOpcode::VMERGE_VVM
+            | VOpcode::VMV_V_V
+            | VOpcode::VMSEQ_VV
+            | VOpcode::VMSNE_VV
+            | VOpcode::VMSLTU_VV
+            | VOpcode::VMSLT_VV
+            | VOpcode::VMSLEU_VV
+            | VOpcode::VMSLE_VV
+            | VOpcode::VSADDU_VV
+            | VOpcode::VSADD_VV
+            | VOpcode::VSSUBU_VV
+            | VOpcode::VSSUB_VV
+            | VOpcode::VSLL_VV
+            | VOpcode::VSMUL_VV
+            | VOpcode::VSRL_VV
+            | VOpcode::VSRA_VV
+            | VOpcode::VSSRL_VV
+            | VOpcode::VSSRA_VV
+            | VOpcode::VNSRL_WV
+            | VOpcode::VNSRA_WV
+            | VOpcode::VNCLIPU_WV
+            | VOpcode::VNCLIP_WV
+            | VOpcode::VWREDSUMU_VS
+            | VOpcode::VWREDSUM_VS
+            | VOpcode::VREDSUM_VS
+            | VOpcode::VREDAND_VS
+            | VOpcode::VREDOR_VS
+            | VOpcode::VREDXOR_VS
+            | VOpcode::VREDMINU_VS
+            | VOpcode::VREDMIN_VS
+            | VOpcode::VREDMAXU_VS
+            | VOpcode::VREDMAX_VS
+            | VOpcode::VAADDU_VV
+            | VOpcode::VAADD_VV
+            | VOpcode::VASUBU_VV
+            | VOpcode::VASUB_VV
+            | VOpcode::VCOMPRESS_VM
+            | VOpcode::VMANDN_MM
+            | VOpcode::VMAND_MM
+            | VOpcode::VMOR_MM
+            | VOpcode::VMXOR_MM
+            | VOpcode::VMORN_MM
+            | VOpcode::VMNAND_MM
+            | VOpcode::VMNOR_MM
+            | VOpcode::VMXNOR_MM
+            | VOpcode::VDIVU_VV
+            | VOpcode::VDIV_VV
+            | VOpcode::VREMU_VV
+            | VOpcode::VREM_VV
+            | VOpcode::VMULHU_VV
+            | VOpcode::VMUL_VV
+            | VOpcode::VMULHSU_VV
+            | VOpcode::VMULH_VV
+            | VOpcode::VWADDU_VV
+            | VOpcode::VWADD_VV
+            | VOpcode::VWSUBU_VV
+            | VOpcode::VWSUB_VV
+            | VOpcode::VWADDU_WV
+            | VOpcode::VWADD_WV
+            | VOpcode::VWSUBU_WV
+            | VOpcode::VWSUB_WV
+            | VOpcode::VWMULU_VV
+            | VOpcode::VWMULSU_VV
+            | VOpcode::VWMUL_VV
+            | VOpcode::VFADD_VV
+            | VOpcode::VFSUB_VV
+            | VOpcode::VFMIN_VV
+            | VOpcode::VFMAX_VV
+            | VOpcode::VFSGNJ_VV
+            | VOpcode::VFSGNJN_VV
+            | VOpcode::VFSGNJX_VV
+            | VOpcode::VFREDUSUM_VS
+            | VOpcode::VFREDOSUM_VS
+            | VOpcode::VFREDMIN_VS
+            | VOpcode::VFREDMAX_VS
+            | VOpcode::VMFEQ_VV
+            | VOpcode::VMFLE_VV
+            | VOpcode::VMFLT_VV
+            | VOpcode::VMFNE_VV
+            | VOpcode::VFDIV_VV
+            | VOpcode::VFMUL_VV
+            | VOpcode::VFWADD_VV
+            | VOpcode::VFWSUB_VV
+            | VOpcode::VFWREDUSUM_VS
+            | VOpcode::VFWREDOSUM_VS
+            | VOpcode::VFWADD_WV
+            | VOpcode::VFWSUB_WV
+            | VOpcode::VFWMUL_VV => InstFormat::VvFormat,
+            VOpcode::VADD_VX
+            | VOpcode::VSUB_VX
+            | VOpcode::VRSUB_VX
+            | VOpcode::VMINU_VX
+            | VOpcode::VMIN_VX
+            | VOpcode::VMAXU_VX
+            | VOpcode::VMAX_VX
+            | VOpcode::VAND_VX
+            | VOpcode::VOR_VX
+            | VOpcode::VXOR_VX
+            | VOpcode::VRGATHER_VX
+            | VOpcode::VSLIDEUP_VX
+            | VOpcode::VSLIDEDOWN_VX
+            | VOpcode::VADC_VXM
+            | VOpcode::VMADC_VXM
+            | VOpcode::VMADC_VX
+            | VOpcode::VSBC_VXM
+            | VOpcode::VMSBC_VXM
+            | VOpcode::VMSBC_VX
+            | VOpcode::VMERGE_VXM
+            | VOpcode::VMV_V_X
+            | VOpcode::VMSEQ_VX
+            | VOpcode::VMSNE_VX
+            | VOpcode::VMSLTU_VX
+            | VOpcode::VMSLT_VX
+            | VOpcode::VMSLEU_VX
+            | VOpcode::VMSLE_VX
+            | VOpcode::VMSGTU_VX
+            | VOpcode::VMSGT_VX
+            | VOpcode::VSADDU_VX
+            | VOpcode::VSADD_VX
+            | VOpcode::VSSUBU_VX
+            | VOpcode::VSSUB_VX
+            | VOpcode::VSLL_VX
+            | VOpcode::VSMUL_VX
+            | VOpcode::VSRL_VX
+            | VOpcode::VSRA_VX
+            | VOpcode::VSSRL_VX
+            | VOpcode::VSSRA_VX
+            | VOpcode::VNSRL_WX
+            | VOpcode::VNSRA_WX
+            | VOpcode::VNCLIPU_WX
+            | VOpcode::VNCLIP_WX
+            | VOpcode::VAADDU_VX
+            | VOpcode::VAADD_VX
+            | VOpcode::VASUBU_VX
+            | VOpcode::VASUB_VX
+            | VOpcode::VSLIDE1UP_VX
+            | VOpcode::VSLIDE1DOWN_VX
+            | VOpcode::VMV_S_X
+            | VOpcode::VDIVU_VX
+            | VOpcode::VDIV_VX
+            | VOpcode::VREMU_VX
+            | VOpcode::VREM_VX
+            | VOpcode::VMULHU_VX
+            | VOpcode::VMUL_VX
+            | VOpcode::VMULHSU_VX
+            | VOpcode::VMULH_VX
+            | VOpcode::VWADDU_VX
+            | VOpcode::VWADD_VX
+            | VOpcode::VWSUBU_VX
+            | VOpcode::VWSUB_VX
+            | VOpcode::VWADDU_WX
+            | VOpcode::VWADD_WX
+            | VOpcode::VWSUBU_WX
+            | VOpcode::VWSUB_WX
+            | VOpcode::VWMULU_VX
+            | VOpcode::VWMULSU_VX
+            | VOpcode::VWMUL_VX => InstFormat::VxFormat,
+            VOpcode::VADD_VI
+            | VOpcode::VRSUB_VI
+            | VOpcode::VAND_VI
+            | VOpcode::VOR_VI
+            | VOpcode::VXOR_VI
+            | VOpcode::VRGATHER_VI
+            | VOpcode::VSLIDEUP_VI
+            | VOpcode::VSLIDEDOWN_VI
+            | VOpcode::VADC_VIM
+            | VOpcode::VMADC_VIM
+            | VOpcode::VMADC_VI
+            | VOpcode::VMERGE_VIM
+            | VOpcode::VMV_V_I
+            | VOpcode::VMSEQ_VI
+            | VOpcode::VMSNE_VI
+            | VOpcode::VMSLEU_VI
+            | VOpcode::VMSLE_VI
+            | VOpcode::VMSGTU_VI
+            | VOpcode::VMSGT_VI
+            | VOpcode::VSADDU_VI
+            | VOpcode::VSADD_VI
+            | VOpcode::VSLL_VI
+            | VOpcode::VSRL_VI
+            | VOpcode::VSRA_VI
+            | VOpcode::VSSRL_VI
+            | VOpcode::VSSRA_VI
+            | VOpcode::VNSRL_WI
+            | VOpcode::VNSRA_WI
+            | VOpcode::VNCLIPU_WI
+            | VOpcode::VNCLIP_WI => InstFormat::ViFormat,
+            VOpcode::VMV1R_V
+            | VOpcode::VMV2R_V
+            | VOpcode::VMV4R_V
+            | VOpcode::VMV8R_V
+            | VOpcode::VZEXT_VF8
+            | VOpcode::VSEXT_VF8
+            | VOpcode::VZEXT_VF4
+            | VOpcode::VSEXT_VF4
+            | VOpcode::VZEXT_VF2
+            | VOpcode::VSEXT_VF2
+            | VOpcode::VMSBF_M
+            | VOpcode::VMSOF_M
+            | VOpcode::VMSIF_M
+            | VOpcode::VIOTA_M
+            | VOpcode::VID_V
+            | VOpcode::VFCVT_XU_F_V
+            | VOpcode::VFCVT_X_F_V
+            | VOpcode::VFCVT_F_XU_V
+            | VOpcode::VFCVT_F_X_V
+            | VOpcode::VFCVT_RTZ_XU_F_V
+            | VOpcode::VFCVT_RTZ_X_F_V
+            | VOpcode::VFWCVT_XU_F_V
+            | VOpcode::VFWCVT_X_F_V
+            | VOpcode::VFWCVT_F_XU_V
+            | VOpcode::VFWCVT_F_X_V
+            | VOpcode::VFWCVT_F_F_V
+            | VOpcode::VFWCVT_RTZ_XU_F_V
+            | VOpcode::VFWCVT_RTZ_X_F_V
+            | VOpcode::VFNCVT_XU_F_W
+            | VOpcode::VFNCVT_X_F_W
+            | VOpcode::VFNCVT_F_XU_W
+            | VOpcode::VFNCVT_F_X_W
+            | VOpcode::VFNCVT_F_F_W
+            | VOpcode::VFNCVT_ROD_F_F_W
+            | VOpcode::VFNCVT_RTZ_XU_F_W
+            | VOpcode::VFNCVT_RTZ_X_F_W
+            | VOpcode::VFSQRT_V
+            | VOpcode::VFRSQRT7_V
+            | VOpcode::VFREC7_V
+            | VOpcode::VFCLASS_V => InstFormat::VUnaryFormat,
+            VOpcode::VMV_X_S | VOpcode::VCPOP_M | VOpcode::VFIRST_M => InstFormat::VToXFormat,
+            VOpcode::VMADD_VV
+            | VOpcode::VNMSUB_VV
+            | VOpcode::VMACC_VV
+            | VOpcode::VNMSAC_VV
+            | VOpcode::VWMACCU_VV
+            | VOpcode::VWMACC_VV
+            | VOpcode::VWMACCSU_VV
+            | VOpcode::VFMADD_VV
+            | VOpcode::VFNMADD_VV
+            | VOpcode::VFMSUB_VV
+            | VOpcode::VFNMSUB_VV
+            | VOpcode::VFMACC_VV
+            | VOpcode::VFNMACC_VV
+            | VOpcode::VFMSAC_VV
+            | VOpcode::VFNMSAC_VV
+            | VOpcode::VFWMACC_VV
+            | VOpcode::VFWNMACC_VV
+            | VOpcode::VFWMSAC_VV
+            | VOpcode::VFWNMSAC_VV => InstFormat::VmaVvFormat,
+            VOpcode::VMADD_VX
+            | VOpcode::VNMSUB_VX
+            | VOpcode::VMACC_VX
+            | VOpcode::VNMSAC_VX
+            | VOpcode::VWMACCU_VX
+            | VOpcode::VWMACC_VX
+            | VOpcode::VWMACCUS_VX
+            | VOpcode::VWMACCSU_VX => InstFormat::VmaVxFormat,
+            VOpcode::VFADD_VF
+            | VOpcode::VFSUB_VF
+            | VOpcode::VFMIN_VF
+            | VOpcode::VFMAX_VF
+            | VOpcode::VFSGNJ_VF
+            | VOpcode::VFSGNJN_VF
+            | VOpcode::VFSGNJX_VF
+            | VOpcode::VFSLIDE1UP_VF
+            | VOpcode::VFSLIDE1DOWN_VF
+            | VOpcode::VFMV_S_F
+            | VOpcode::VFMERGE_VFM
+            | VOpcode::VFMV_V_F
+            | VOpcode::VMFEQ_VF
+            | VOpcode::VMFLE_VF
+            | VOpcode::VMFLT_VF
+            | VOpcode::VMFNE_VF
+            | VOpcode::VMFGT_VF
+            | VOpcode::VMFGE_VF
+            | VOpcode::VFDIV_VF
+            | VOpcode::VFRDIV_VF
+            | VOpcode::VFMUL_VF
+            | VOpcode::VFRSUB_VF
+            | VOpcode::VFWADD_VF
+            | VOpcode::VFWSUB_VF
+            | VOpcode::VFWADD_WF
+            | VOpcode::VFWSUB_WF
+            | VOpcode::VFWMUL_VF => InstFormat::VfFormat,
+            VOpcode::VFMV_F_S => InstFormat::VToFFormat,
+            VOpcode::VFMADD_VF
+            | VOpcode::VFNMADD_VF
+            | VOpcode::VFMSUB_VF
+            | VOpcode::VFNMSUB_VF
+            | VOpcode::VFMACC_VF
+            | VOpcode::VFNMACC_VF
+            | VOpcode::VFMSAC_VF
+            | VOpcode::VFNMSAC_VF
+            | VOpcode::VFWMACC_VF
+            | VOpcode::VFWNMACC_VF
+            | VOpcode::VFWMSAC_VF
+            | VOpcode::VFWNMSAC_VF => InstFormat::VmaVfFormat,
+        }
+    }
+}
+
+/// Vector type (`vtype`) encoded in the immediate of vsetvli/vsetivli.
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Vtype {
+    /// Selected element width (8, 16, 32 or 64)
+    pub sew: u32,
+    /// Vector register group multiplier
+    pub lmul: Lmul,
+    /// Vector tail agnostic
+    pub tail_agnostic: bool,
+    /// Vector mask agnostic
+    pub mask_agnostic: bool,
+}
+
+impl Vtype {
+    /// Convert the raw `zimm` field into `Vtype`.
+    /// It returns `None` if the field is reserved.
+    #[must_use]
+    pub fn from_imm(zimm: i32) -> Option<Self> {
+        if zimm >> 8 != 0 {
+            return None;
+        }
+
+        let sew = match zimm >> 3 & 0b111 {
+            0b000 => 8,
+            0b001 => 16,
+            0b010 => 32,
+            0b011 => 64,
+            _ => return None,
+        };
+        let lmul = match zimm & 0b111 {
+            0b000 => Lmul::M1,
+            0b001 => Lmul::M2,
+            0b010 => Lmul::M4,
+            0b011 => Lmul::M8,
+            0b101 => Lmul::MF8,
+            0b110 => Lmul::MF4,
+            0b111 => Lmul::MF2,
+            _ => return None,
+        };
+
+        Some(Vtype {
+            sew,
+            lmul,
+            tail_agnostic: zimm >> 6 & 0b1 == 1,
+            mask_agnostic: zimm >> 7 & 0b1 == 1,
+        })
+    }
+}
+
+impl Vtype {
+    /// Print the fields separated by `sep`.
+    pub(crate) fn fmt_separated(self, f: &mut Formatter, sep: &str) -> fmt::Result {
+        write!(
+            f,
+            "e{}{sep}{}{sep}{}{sep}{}",
+            self.sew,
+            self.lmul,
+            if self.tail_agnostic { "ta" } else { "tu" },
+            if self.mask_agnostic { "ma" } else { "mu" }
+        )
+    }
+}
+
+impl Display for Vtype {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        self.fmt_separated(f, ", ")
+    }
+}
+
+/// Vector register group multiplier (LMUL).
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum Lmul {
+    /// LMUL = 1/8
+    MF8,
+    /// LMUL = 1/4
+    MF4,
+    /// LMUL = 1/2
+    MF2,
+    /// LMUL = 1
+    M1,
+    /// LMUL = 2
+    M2,
+    /// LMUL = 4
+    M4,
+    /// LMUL = 8
+    M8,
+}
+
+impl Display for Lmul {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self {
+            Lmul::MF8 => write!(f, "mf8"),
+            Lmul::MF4 => write!(f, "mf4"),
+            Lmul::MF2 => write!(f, "mf2"),
+            Lmul::M1 => write!(f, "m1"),
+            Lmul::M2 => write!(f, "m2"),
+            Lmul::M4 => write!(f, "m4"),
+            Lmul::M8 => write!(f, "m8"),
+        }
+    }
+}
@@ -38,3 +38,495 @@ impl Opcode for ZicsrOpcode {
         }
     }
 }
+
+/// Control and status register address.
+///
+/// See: The RISC-V Instruction Set Manual Volume II, Table 2.2 - 2.7
+///
+/// # Example
+/// ```
+/// use raki::Csr;
+///
+/// assert_eq!(Csr::new(0x300), Csr::MSTATUS);
+/// assert_eq!(Csr::MSTATUS.name(), Some("mstatus"));
+/// assert_eq!(Csr::from_name("satp"), Some(Csr::SATP));
+/// assert_eq!(Csr::CYCLE.to_string(), "cycle");
+/// // unknown (e.g. custom) csr is printed as the address.
+/// assert_eq!(Csr::new(0x7c0).to_string(), "0x7c0");
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub struct Csr(u16);
+
+impl Csr {
+    /// Create a csr from the 12bit address (the upper bits are ignored).
+    #[must_use]
+    pub const fn new(addr: u16) -> Self {
+        Csr(addr & 0xfff)
+    }
+
+    /// Create a csr from the `rs2` field of `Instruction`.
+    pub(crate) fn from_field(csr: usize) -> Self {
+        Csr(u16::try_from(csr & 0xfff).unwrap())
+    }
+
+    /// 12bit address.
+    #[must_use]
+    pub const fn addr(self) -> u16 {
+        self.0
+    }
+
+    /// Standard name of the csr.
+    /// It returns `None` if the address is not in the table (e.g. custom csr).
+    #[must_use]
+    pub fn name(self) -> Option<&'static str> {
+        CSR_TABLE
+            .binary_search_by_key(&self.0, |(addr, _)| *addr)
+            .ok()
+            .map(|index| CSR_TABLE[index].1)
+    }
+
+    /// Look up the csr by its standard name.
+    #[must_use]
+    pub fn from_name(name: &str) -> Option<Self> {
+        CSR_TABLE
+            .iter()
+            .find(|(_, csr_name)| *csr_name == name)
+            .map(|(addr, _)| Csr(*addr))
+    }
+
+    /// Return true if the csr is read-only (`csr[11:10] == 0b11`).
+    #[must_use]
+    pub const fn is_read_only(self) -> bool {
+        self.0 >> 10 == 0b11
+    }
+
+    /// Lowest privilege level that can access the csr (`csr[9:8]`).
+    /// 0: User, 1: Supervisor, 2: Hypervisor, 3: Machine
+    #[must_use]
+    pub const fn privilege_level(self) -> u8 {
+        ((self.0 >> 8) & 0b11) as u8
+    }
+}
+
+impl Display for Csr {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{name}"),
+            None => write!(f, "{:#x}", self.0),
+        }
+    }
+}
+
+/// Define `Csr` constants and the name table sorted by address.
+macro_rules! csr_table {
+    ($($name:ident = $addr:literal, $str:literal;)*) => {
+        impl Csr {
+            $(
+                #[doc = concat!("`", $str, "`")]
+                pub const $name: Csr = Csr($addr);
+            )*
+        }
+
+        const CSR_TABLE: &[(u16, &str)] = &[$(($addr, $str)),*];
+    };
+}
+
+csr_table! {
+    FFLAGS = 0x001, "fflags";
+    FRM = 0x002, "frm";
+    FCSR = 0x003, "fcsr";
+    VSTART = 0x008, "vstart";
+    VXSAT = 0x009, "vxsat";
+    VXRM = 0x00a, "vxrm";
+    VCSR = 0x00f, "vcsr";
+    SSP = 0x011, "ssp";
+    SEED = 0x015, "seed";
+    JVT = 0x017, "jvt";
+    SSTATUS = 0x100, "sstatus";
+    SIE = 0x104, "sie";
+    STVEC = 0x105, "stvec";
+    SCOUNTEREN = 0x106, "scounteren";
+    SENVCFG = 0x10a, "senvcfg";
+    SSCRATCH = 0x140, "sscratch";
+    SEPC = 0x141, "sepc";
+    SCAUSE = 0x142, "scause";
+    STVAL = 0x143, "stval";
+    SIP = 0x144, "sip";
+    STIMECMP = 0x14d, "stimecmp";
+    STIMECMPH = 0x15d, "stimecmph";
+    SATP = 0x180, "satp";
+    VSSTATUS = 0x200, "vsstatus";
+    VSIE = 0x204, "vsie";
+    VSTVEC = 0x205, "vstvec";
+    VSSCRATCH = 0x240, "vsscratch";
+    VSEPC = 0x241, "vsepc";
+    VSCAUSE = 0x242, "vscause";
+    VSTVAL = 0x243, "vstval";
+    VSIP = 0x244, "vsip";
+    VSTIMECMP = 0x24d, "vstimecmp";
+    VSTIMECMPH = 0x25d, "vstimecmph";
+    VSATP = 0x280, "vsatp";
+    MSTATUS = 0x300, "mstatus";
+    MISA = 0x301, "misa";
+    MEDELEG = 0x302, "medeleg";
+    MIDELEG = 0x303, "mideleg";
+    MIE = 0x304, "mie";
+    MTVEC = 0x305, "mtvec";
+    MCOUNTEREN = 0x306, "mcounteren";
+    MENVCFG = 0x30a, "menvcfg";
+    MSTATUSH = 0x310, "mstatush";
+    MEDELEGH = 0x312, "medelegh";
+    MENVCFGH = 0x31a, "menvcfgh";
+    MCOUNTINHIBIT = 0x320, "mcountinhibit";
+    MHPMEVENT3 = 0x323, "mhpmevent3";
+    MHPMEVENT4 = 0x324, "mhpmevent4";
+    MHPMEVENT5 = 0x325, "mhpmevent5";
+    MHPMEVENT6 = 0x326, "mhpmevent6";
+    MHPMEVENT7 = 0x327, "mhpmevent7";
+    MHPMEVENT8 = 0x328, "mhpmevent8";
+    MHPMEVENT9 = 0x329, "mhpmevent9";
+    MHPMEVENT10 = 0x32a, "mhpmevent10";
+    MHPMEVENT11 = 0x32b, "mhpmevent11";
+    MHPMEVENT12 = 0x32c, "mhpmevent12";
+    MHPMEVENT13 = 0x32d, "mhpmevent13";
+    MHPMEVENT14 = 0x32e, "mhpmevent14";
+    MHPMEVENT15 = 0x32f, "mhpmevent15";
+    MHPMEVENT16 = 0x330, "mhpmevent16";
+    MHPMEVENT17 = 0x331, "mhpmevent17";
+    MHPMEVENT18 = 0x332, "mhpmevent18";
+    MHPMEVENT19 = 0x333, "mhpmevent19";
+    MHPMEVENT20 = 0x334, "mhpmevent20";
+    MHPMEVENT21 = 0x335, "mhpmevent21";
+    MHPMEVENT22 = 0x336, "mhpmevent22";
+    MHPMEVENT23 = 0x337, "mhpmevent23";
+    MHPMEVENT24 = 0x338, "mhpmevent24";
+    MHPMEVENT25 = 0x339, "mhpmevent25";
+    MHPMEVENT26 = 0x33a, "mhpmevent26";
+    MHPMEVENT27 = 0x33b, "mhpmevent27";
+    MHPMEVENT28 = 0x33c, "mhpmevent28";
+    MHPMEVENT29 = 0x33d, "mhpmevent29";
+    MHPMEVENT30 = 0x33e, "mhpmevent30";
+    MHPMEVENT31 = 0x33f, "mhpmevent31";
+    MSCRATCH = 0x340, "mscratch";
+    MEPC = 0x341, "mepc";
+    MCAUSE = 0x342, "mcause";
+    MTVAL = 0x343, "mtval";
+    MIP = 0x344, "mip";
+    MTINST = 0x34a, "mtinst";
+    MTVAL2 = 0x34b, "mtval2";
+    PMPCFG0 = 0x3a0, "pmpcfg0";
+    PMPCFG1 = 0x3a1, "pmpcfg1";
+    PMPCFG2 = 0x3a2, "pmpcfg2";
+    PMPCFG3 = 0x3a3, "pmpcfg3";
+    PMPCFG4 = 0x3a4, "pmpcfg4";
+    PMPCFG5 = 0x3a5, "pmpcfg5";
+    PMPCFG6 = 0x3a6, "pmpcfg6";
+    PMPCFG7 = 0x3a7, "pmpcfg7";
+    PMPCFG8 = 0x3a8, "pmpcfg8";
+    PMPCFG9 = 0x3a9, "pmpcfg9";
+    PMPCFG10 = 0x3aa, "pmpcfg10";
+    PMPCFG11 = 0x3ab, "pmpcfg11";
+    PMPCFG12 = 0x3ac, "pmpcfg12";
+    PMPCFG13 = 0x3ad, "pmpcfg13";
+    PMPCFG14 = 0x3ae, "pmpcfg14";
+    PMPCFG15 = 0x3af, "pmpcfg15";
+    PMPADDR0 = 0x3b0, "pmpaddr0";
+    PMPADDR1 = 0x3b1, "pmpaddr1";
+    PMPADDR2 = 0x3b2, "pmpaddr2";
+    PMPADDR3 = 0x3b3, "pmpaddr3";
+    PMPADDR4 = 0x3b4, "pmpaddr4";
+    PMPADDR5 = 0x3b5, "pmpaddr5";
+    PMPADDR6 = 0x3b6, "pmpaddr6";
+    PMPADDR7 = 0x3b7, "pmpaddr7";
+    PMPADDR8 = 0x3b8, "pmpaddr8";
+    PMPADDR9 = 0x3b9, "pmpaddr9";
+    PMPADDR10 = 0x3ba, "pmpaddr10";
+    PMPADDR11 = 0x3bb, "pmpaddr11";
+    PMPADDR12 = 0x3bc, "pmpaddr12";
+    PMPADDR13 = 0x3bd, "pmpaddr13";
+    PMPADDR14 = 0x3be, "pmpaddr14";
+    PMPADDR15 = 0x3bf, "pmpaddr15";
+    PMPADDR16 = 0x3c0, "pmpaddr16";
+    PMPADDR17 = 0x3c1, "pmpaddr17";
+    PMPADDR18 = 0x3c2, "pmpaddr18";
+    PMPADDR19 = 0x3c3, "pmpaddr19";
+    PMPADDR20 = 0x3c4, "pmpaddr20";
+    PMPADDR21 = 0x3c5, "pmpaddr21";
+    PMPADDR22 = 0x3c6, "pmpaddr22";
+    PMPADDR23 = 0x3c7, "pmpaddr23";
+    PMPADDR24 = 0x3c8, "pmpaddr24";
+    PMPADDR25 = 0x3c9, "pmpaddr25";
+    PMPADDR26 = 0x3ca, "pmpaddr26";
+    PMPADDR27 = 0x3cb, "pmpaddr27";
+    PMPADDR28 = 0x3cc, "pmpaddr28";
+    PMPADDR29 = 0x3cd, "pmpaddr29";
+    PMPADDR30 = 0x3ce, "pmpaddr30";
+    PMPADDR31 = 0x3cf, "pmpaddr31";
+    PMPADDR32 = 0x3d0, "pmpaddr32";
+    PMPADDR33 = 0x3d1, "pmpaddr33";
+    PMPADDR34 = 0x3d2, "pmpaddr34";
+    PMPADDR35 = 0x3d3, "pmpaddr35";
+    PMPADDR36 = 0x3d4, "pmpaddr36";
+    PMPADDR37 = 0x3d5, "pmpaddr37";
+    PMPADDR38 = 0x3d6, "pmpaddr38";
+    PMPADDR39 = 0x3d7, "pmpaddr39";
+    PMPADDR40 = 0x3d8, "pmpaddr40";
+    PMPADDR41 = 0x3d9, "pmpaddr41";
+    PMPADDR42 = 0x3da, "pmpaddr42";
+    PMPADDR43 = 0x3db, "pmpaddr43";
+    PMPADDR44 = 0x3dc, "pmpaddr44";
+    PMPADDR45 = 0x3dd, "pmpaddr45";
+    PMPADDR46 = 0x3de, "pmpaddr46";
+    PMPADDR47 = 0x3df, "pmpaddr47";
+    PMPADDR48 = 0x3e0, "pmpaddr48";
+    PMPADDR49 = 0x3e1, "pmpaddr49";
+    PMPADDR50 = 0x3e2, "pmpaddr50";
+    PMPADDR51 = 0x3e3, "pmpaddr51";
+    PMPADDR52 = 0x3e4, "pmpaddr52";
+    PMPADDR53 = 0x3e5, "pmpaddr53";
+    PMPADDR54 = 0x3e6, "pmpaddr54";
+    PMPADDR55 = 0x3e7, "pmpaddr55";
+    PMPADDR56 = 0x3e8, "pmpaddr56";
+    PMPADDR57 = 0x3e9, "pmpaddr57";
+    PMPADDR58 = 0x3ea, "pmpaddr58";
+    PMPADDR59 = 0x3eb, "pmpaddr59";
+    PMPADDR60 = 0x3ec, "pmpaddr60";
+    PMPADDR61 = 0x3ed, "pmpaddr61";
+    PMPADDR62 = 0x3ee, "pmpaddr62";
+    PMPADDR63 = 0x3ef, "pmpaddr63";
+    SCONTEXT = 0x5a8, "scontext";
+    HSTATUS = 0x600, "hstatus";
+    HEDELEG = 0x602, "hedeleg";
+    HIDELEG = 0x603, "hideleg";
+    HIE = 0x604, "hie";
+    HTIMEDELTA = 0x605, "htimedelta";
+    HCOUNTEREN = 0x606, "hcounteren";
+    HGEIE = 0x607, "hgeie";
+    HENVCFG = 0x60a, "henvcfg";
+    HEDELEGH = 0x612, "hedelegh";
+    HTIMEDELTAH = 0x615, "htimedeltah";
+    HENVCFGH = 0x61a, "henvcfgh";
+    HTVAL = 0x643, "htval";
+    HIP = 0x644, "hip";
+    HVIP = 0x645, "hvip";
+    HTINST = 0x64a, "htinst";
+    HGATP = 0x680, "hgatp";
+    HCONTEXT = 0x6a8, "hcontext";
+    MHPMEVENT3H = 0x723, "mhpmevent3h";
+    MHPMEVENT4H = 0x724, "mhpmevent4h";
+    MHPMEVENT5H = 0x725, "mhpmevent5h";
+    MHPMEVENT6H = 0x726, "mhpmevent6h";
+    MHPMEVENT7H = 0x727, "mhpmevent7h";
+    MHPMEVENT8H = 0x728, "mhpmevent8h";
+    MHPMEVENT9H = 0x729, "mhpmevent9h";
+    MHPMEVENT10H = 0x72a, "mhpmevent10h";
+    MHPMEVENT11H = 0x72b, "mhpmevent11h";
+    MHPMEVENT12H = 0x72c, "mhpmevent12h";
+    MHPMEVENT13H = 0x72d, "mhpmevent13h";
+    MHPMEVENT14H = 0x72e, "mhpmevent14h";
+    MHPMEVENT15H = 0x72f, "mhpmevent15h";
+    MHPMEVENT16H = 0x730, "mhpmevent16h";
+    MHPMEVENT17H = 0x731, "mhpmevent17h";
+    MHPMEVENT18H = 0x732, "mhpmevent18h";
+    MHPMEVENT19H = 0x733, "mhpmevent19h";
+    MHPMEVENT20H = 0x734, "mhpmevent20h";
+    MHPMEVENT21H = 0x735, "mhpmevent21h";
+    MHPMEVENT22H = 0x736, "mhpmevent22h";
+    MHPMEVENT23H = 0x737, "mhpmevent23h";
+    MHPMEVENT24H = 0x738, "mhpmevent24h";
+    MHPMEVENT25H = 0x739, "mhpmevent25h";
+    MHPMEVENT26H = 0x73a, "mhpmevent26h";
+    MHPMEVENT27H = 0x73b, "mhpmevent27h";
+    MHPMEVENT28H = 0x73c, "mhpmevent28h";
+    MHPMEVENT29H = 0x73d, "mhpmevent29h";
+    MHPMEVENT30H = 0x73e, "mhpmevent30h";
+    MHPMEVENT31H = 0x73f, "mhpmevent31h";
+    MNSCRATCH = 0x740, "mnscratch";
+    MNEPC = 0x741, "mnepc";
+    MNCAUSE = 0x742, "mncause";
+    MNSTATUS = 0x744, "mnstatus";
+    MSECCFG = 0x747, "mseccfg";
+    MSECCFGH = 0x757, "mseccfgh";
+    TSELECT = 0x7a0, "tselect";
+    TDATA1 = 0x7a1, "tdata1";
+    TDATA2 = 0x7a2, "tdata2";
+    TDATA3 = 0x7a3, "tdata3";
+    MCONTEXT = 0x7a8, "mcontext";
+    DCSR = 0x7b0, "dcsr";
+    DPC = 0x7b1, "dpc";
+    DSCRATCH0 = 0x7b2, "dscratch0";
+    DSCRATCH1 = 0x7b3, "dscratch1";
+    MCYCLE = 0xb00, "mcycle";
+    MINSTRET = 0xb02, "minstret";
+    MHPMCOUNTER3 = 0xb03, "mhpmcounter3";
+    MHPMCOUNTER4 = 0xb04, "mhpmcounter4";
+    MHPMCOUNTER5 = 0xb05, "mhpmcounter5";
+    MHPMCOUNTER6 = 0xb06, "mhpmcounter6";
+    MHPMCOUNTER7 = 0xb07, "mhpmcounter7";
+    MHPMCOUNTER8 = 0xb08, "mhpmcounter8";
+    MHPMCOUNTER9 = 0xb09, "mhpmcounter9";
+    MHPMCOUNTER10 = 0xb0a, "mhpmcounter10";
+    MHPMCOUNTER11 = 0xb0b, "mhpmcounter11";
+    MHPMCOUNTER12 = 0xb0c, "mhpmcounter12";
+    MHPMCOUNTER13 = 0xb0d, "mhpmcounter13";
+    MHPMCOUNTER14 = 0xb0e, "mhpmcounter14";
+    MHPMCOUNTER15 = 0xb0f, "mhpmcounter15";
+    MHPMCOUNTER16 = 0xb10, "mhpmcounter16";
+    MHPMCOUNTER17 = 0xb11, "mhpmcounter17";
+    MHPMCOUNTER18 = 0xb12, "mhpmcounter18";
+    MHPMCOUNTER19 = 0xb13, "mhpmcounter19";
+    MHPMCOUNTER20 = 0xb14, "mhpmcounter20";
+    MHPMCOUNTER21 = 0xb15, "mhpmcounter21";
+    MHPMCOUNTER22 = 0xb16, "mhpmcounter22";
+    MHPMCOUNTER23 = 0xb17, "mhpmcounter23";
+    MHPMCOUNTER24 = 0xb18, "mhpmcounter24";
+    MHPMCOUNTER25 = 0xb19, "mhpmcounter25";
+    MHPMCOUNTER26 = 0xb1a, "mhpmcounter26";
+    MHPMCOUNTER27 = 0xb1b, "mhpmcounter27";
+    MHPMCOUNTER28 = 0xb1c, "mhpmcounter28";
+    MHPMCOUNTER29 = 0xb1d, "mhpmcounter29";
+    MHPMCOUNTER30 = 0xb1e, "mhpmcounter30";
+    MHPMCOUNTER31 = 0xb1f, "mhpmcounter31";
+    MCYCLEH = 0xb80, "mcycleh";
+    MINSTRETH = 0xb82, "minstreth";
+    MHPMCOUNTER3H = 0xb83, "mhpmcounter3h";
+    MHPMCOUNTER4H = 0xb84, "mhpmcounter4h";
+    MHPMCOUNTER5H = 0xb85, "mhpmcounter5h";
+    MHPMCOUNTER6H = 0xb86, "mhpmcounter6h";
+    MHPMCOUNTER7H = 0xb87, "mhpmcounter7h";
+    MHPMCOUNTER8H = 0xb88, "mhpmcounter8h";
+    MHPMCOUNTER9H = 0xb89, "mhpmcounter9h";
+    MHPMCOUNTER10H = 0xb8a, "mhpmcounter10h";
+    MHPMCOUNTER11H = 0xb8b, "mhpmcounter11h";
+    MHPMCOUNTER12H = 0xb8c, "mhpmcounter12h";
+    MHPMCOUNTER13H = 0xb8d, "mhpmcounter13h";
+    MHPMCOUNTER14H = 0xb8e, "mhpmcounter14h";
+    MHPMCOUNTER15H = 0xb8f, "mhpmcounter15h";
+    MHPMCOUNTER16H = 0xb90, "mhpmcounter16h";
+    MHPMCOUNTER17H = 0xb91, "mhpmcounter17h";
+    MHPMCOUNTER18H = 0xb92, "mhpmcounter18h";
+    MHPMCOUNTER19H = 0xb93, "mhpmcounter19h";
+    MHPMCOUNTER20H = 0xb94, "mhpmcounter20h";
+    MHPMCOUNTER21H = 0xb95, "mhpmcounter21h";
+    MHPMCOUNTER22H = 0xb96, "mhpmcounter22h";
+    MHPMCOUNTER23H = 0xb97, "mhpmcounter23h";
+    MHPMCOUNTER24H = 0xb98, "mhpmcounter24h";
+    MHPMCOUNTER25H = 0xb99, "mhpmcounter25h";
+    MHPMCOUNTER26H = 0xb9a, "mhpmcounter26h";
+    MHPMCOUNTER27H = 0xb9b, "mhpmcounter27h";
+    MHPMCOUNTER28H = 0xb9c, "mhpmcounter28h";
+    MHPMCOUNTER29H = 0xb9d, "mhpmcounter29h";
+    MHPMCOUNTER30H = 0xb9e, "mhpmcounter30h";
+    MHPMCOUNTER31H = 0xb9f, "mhpmcounter31h";
+    CYCLE = 0xc00, "cycle";
+    TIME = 0xc01, "time";
+    INSTRET = 0xc02, "instret";
+    HPMCOUNTER3 = 0xc03, "hpmcounter3";
+    HPMCOUNTER4 = 0xc04, "hpmcounter4";
+    HPMCOUNTER5 = 0xc05, "hpmcounter5";
+    HPMCOUNTER6 = 0xc06, "hpmcounter6";
+    HPMCOUNTER7 = 0xc07, "hpmcounter7";
+    HPMCOUNTER8 = 0xc08, "hpmcounter8";
+    HPMCOUNTER9 = 0xc09, "hpmcounter9";
+    HPMCOUNTER10 = 0xc0a, "hpmcounter10";
+    HPMCOUNTER11 = 0xc0b, "hpmcounter11";
+    HPMCOUNTER12 = 0xc0c, "hpmcounter12";
+    HPMCOUNTER13 = 0xc0d, "hpmcounter13";
+    HPMCOUNTER14 = 0xc0e, "hpmcounter14";
+    HPMCOUNTER15 = 0xc0f, "hpmcounter15";
+    HPMCOUNTER16 = 0xc10, "hpmcounter16";
+    HPMCOUNTER17 = 0xc11, "hpmcounter17";
+    HPMCOUNTER18 = 0xc12, "hpmcounter18";
+    HPMCOUNTER19 = 0xc13, "hpmcounter19";
+    HPMCOUNTER20 = 0xc14, "hpmcounter20";
+    HPMCOUNTER21 = 0xc15, "hpmcounter21";
+    HPMCOUNTER22 = 0xc16, "hpmcounter22";
+    HPMCOUNTER23 = 0xc17, "hpmcounter23";
+    HPMCOUNTER24 = 0xc18, "hpmcounter24";
+    HPMCOUNTER25 = 0xc19, "hpmcounter25";
+    HPMCOUNTER26 = 0xc1a, "hpmcounter26";
+    HPMCOUNTER27 = 0xc1b, "hpmcounter27";
+    HPMCOUNTER28 = 0xc1c, "hpmcounter28";
+    HPMCOUNTER29 = 0xc1d, "hpmcounter29";
+    HPMCOUNTER30 = 0xc1e, "hpmcounter30";
+    HPMCOUNTER31 = 0xc1f, "hpmcounter31";
+    VL = 0xc20, "vl";
+    VTYPE = 0xc21, "vtype";
+    VLENB = 0xc22, "vlenb";
+    CYCLEH = 0xc80, "cycleh";
+    TIMEH = 0xc81, "timeh";
+    INSTRETH = 0xc82, "instreth";
+    HPMCOUNTER3H = 0xc83, "hpmcounter3h";
+    HPMCOUNTER4H = 0xc84, "hpmcounter4h";
+    HPMCOUNTER5H = 0xc85, "hpmcounter5h";
+    HPMCOUNTER6H = 0xc86, "hpmcounter6h";
+    HPMCOUNTER7H = 0xc87, "hpmcounter7h";
+    HPMCOUNTER8H = 0xc88, "hpmcounter8h";
+    HPMCOUNTER9H = 0xc89, "hpmcounter9h";
+    HPMCOUNTER10H = 0xc8a, "hpmcounter10h";
+    HPMCOUNTER11H = 0xc8b, "hpmcounter11h";
+    HPMCOUNTER12H = 0xc8c, "hpmcounter12h";
+    HPMCOUNTER13H = 0xc8d, "hpmcounter13h";
+    HPMCOUNTER14H = 0xc8e, "hpmcounter14h";
+    HPMCOUNTER15H = 0xc8f, "hpmcounter15h";
+    HPMCOUNTER16H = 0xc90, "hpmcounter16h";
+    HPMCOUNTER17H = 0xc91, "hpmcounter17h";
+    HPMCOUNTER18H = 0xc92, "hpmcounter18h";
+    HPMCOUNTER19H = 0xc93, "hpmcounter19h";
+    HPMCOUNTER20H = 0xc94, "hpmcounter20h";
+    HPMCOUNTER21H = 0xc95, "hpmcounter21h";
+    HPMCOUNTER22H = 0xc96, "hpmcounter22h";
+    HPMCOUNTER23H = 0xc97, "hpmcounter23h";
+    HPMCOUNTER24H = 0xc98, "hpmcounter24h";
+    HPMCOUNTER25H = 0xc99, "hpmcounter25h";
+    HPMCOUNTER26H = 0xc9a, "hpmcounter26h";
+    HPMCOUNTER27H = 0xc9b, "hpmcounter27h";
+    HPMCOUNTER28H = 0xc9c, "hpmcounter28h";
+    HPMCOUNTER29H = 0xc9d, "hpmcounter29h";
+    HPMCOUNTER30H = 0xc9e, "hpmcounter30h";
+    HPMCOUNTER31H = 0xc9f, "hpmcounter31h";
+    SCOUNTOVF = 0xda0, "scountovf";
+    HGEIP = 0xe12, "hgeip";
+    MVENDORID = 0xf11, "mvendorid";
+    MARCHID = 0xf12, "marchid";
+    MIMPID = 0xf13, "mimpid";
+    MHARTID = 0xf14, "mhartid";
+    MCONFIGPTR = 0xf15, "mconfigptr";
+}
+
+#[cfg(test)]
+mod test_csr {
+    #[test]
+    fn csr_test() {
+        use super::{Csr, CSR_TABLE};
+        use crate::{Decode, Isa};
+
+        assert!(CSR_TABLE.windows(2).all(|w| w[0].0 < w[1].0));
+        for (addr, name) in CSR_TABLE {
+            assert_eq!(Csr::new(*addr).name(), Some(*name));
+            assert_eq!(Csr::from_name(name), Some(Csr::new(*addr)));
+        }
+
+        assert_eq!(Csr::new(0xf300), Csr::MSTATUS);
+        assert_eq!(Csr::MHPMCOUNTER31H.addr(), 0xb9f);
+        assert_eq!(Csr::PMPADDR63.to_string(), "pmpaddr63");
+        assert_eq!(Csr::new(0x800).name(), None);
+        assert_eq!(Csr::from_name("foo"), None);
+
+        assert!(Csr::MHARTID.is_read_only());
+        assert!(Csr::CYCLE.is_read_only());
+        assert!(!Csr::MSTATUS.is_read_only());
+        assert_eq!(Csr::FCSR.privilege_level(), 0);
+        assert_eq!(Csr::SATP.privilege_level(), 1);
+        assert_eq!(Csr::HGATP.privilege_level(), 2);
+        assert_eq!(Csr::VSATP.privilege_level(), 2);
+        assert_eq!(Csr::DCSR.privilege_level(), 3);
+
+        let csrrw = 0x3005_9573_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(csrrw.to_string(), "csrrw a0, mstatus, a1");
+        let csrrsi = 0x1802_e573_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(csrrsi.to_string(), "csrrsi a0, satp, 5");
+        let custom = 0x7c00_2573_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(custom.to_string(), "csrrs a0, 0x7c0, zero");
+    }
+}
@@ -1,4 +1,4 @@
-//! Zicsr extension Instruction.
+//! Zifencei extension Instruction.
 
 use super::{InstFormat, Opcode};
 use core::fmt::{self, Display, Formatter};
@@ -7,13 +7,13 @@ use core::fmt::{self, Display, Formatter};
 #[allow(non_camel_case_types)]
 #[derive(Debug, PartialEq)]
 pub enum ZifenceiOpcode {
-    FENCE,
+    FENCE_I,
 }
 
 impl Display for ZifenceiOpcode {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         match self {
-            ZifenceiOpcode::FENCE => write!(f, "fence.i"),
+            ZifenceiOpcode::FENCE_I => write!(f, "fence.i"),
         }
     }
 }
@@ -21,7 +21,7 @@ impl Display for ZifenceiOpcode {
 impl Opcode for ZifenceiOpcode {
     fn get_format(&self) -> InstFormat {
         match self {
-            ZifenceiOpcode::FENCE => InstFormat::NoOperand,
+            ZifenceiOpcode::FENCE_I => InstFormat::NoOperand,
         }
     }
 }
@@ -0,0 +1,420 @@
+//! Parser of RISC-V ISA naming strings (e.g. `rv64imafdc_zicsr_zifencei`).
+
+use core::fmt::{self, Display, Formatter};
+use core::str::FromStr;
+
+use crate::{ExtensionSet, Extensions, Isa};
+
+/// Single-letter extensions in canonical order.
+const SINGLE_LETTER: [(u8, Extensions); 9] = [
+    (b'm', Extensions::M),
+    (b'a', Extensions::A),
+    (b'f', Extensions::F),
+    (b'd', Extensions::D),
+    (b'q', Extensions::Q),
+    (b'c', Extensions::C),
+    (b'b', Extensions::B),
+    (b'v', Extensions::V),
+    (b'h', Extensions::H),
+];
+
+/// Multi-letter extensions in canonical order.
+const MULTI_LETTER: [(&str, Extensions); 9] = [
+    ("zicboz", Extensions::Zicboz),
+    ("zicfiss", Extensions::Zicfiss),
+    ("zicntr", Extensions::Zicntr),
+    ("zicsr", Extensions::Zicsr),
+    ("zifencei", Extensions::Zifencei),
+    ("zba", Extensions::Zba),
+    ("zbb", Extensions::Zbb),
+    ("zbc", Extensions::Zbc),
+    ("zbs", Extensions::Zbs),
+];
+
+/// Extensions implied by `g`.
+const G_IMPLIED: [Extensions; 7] = [
+    Extensions::BaseI,
+    Extensions::M,
+    Extensions::A,
+    Extensions::F,
+    Extensions::D,
+    Extensions::Zicsr,
+    Extensions::Zifencei,
+];
+
+/// Sub-extensions implied by `b`.
+const B_IMPLIED: [Extensions; 3] = [Extensions::Zba, Extensions::Zbb, Extensions::Zbs];
+
+/// Multi-letter extensions whose instructions are decoded as a part of another extension.
+const SUBSET: [(&str, Extensions); 8] = [
+    ("zmmul", Extensions::M),
+    ("zaamo", Extensions::A),
+    ("zalrsc", Extensions::A),
+    ("zca", Extensions::C),
+    ("zcf", Extensions::C),
+    ("zcd", Extensions::C),
+    ("svinval", Extensions::Priv),
+    ("smrnmi", Extensions::Priv),
+];
+
+/// Cause of ISA string parsing error.
+///
+/// The `usize` value is the byte offset of the invalid component.
+#[derive(Debug, PartialEq)]
+pub enum IsaStringError {
+    /// The string does not start with `rv32` or `rv64`.
+    InvalidXlen,
+    /// The base ISA (`i` or `g`) does not follow the xlen.
+    MissingBaseIsa,
+    /// The extension is unknown or unsupported.
+    UnknownExtension(usize),
+    /// The extension appears twice.
+    DuplicateExtension(usize),
+    /// The version number is malformed (e.g. `2p`).
+    MalformedVersion(usize),
+    /// Unexpected character or empty component.
+    UnexpectedCharacter(usize),
+}
+
+/// Decoder configuration described by an ISA string.
+///
+/// # Example
+/// ```
+/// use raki::{Extensions, Isa, IsaConfig};
+///
+/// let config: IsaConfig = "rv64gc".parse().unwrap();
+/// assert!(matches!(config.isa, Isa::Rv64));
+/// assert!(config.extensions.contains(Extensions::Zifencei));
+/// assert_eq!(config.to_string(), "rv64imafdc_zicsr_zifencei");
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct IsaConfig {
+    /// Target isa.
+    pub isa: Isa,
+    /// Enabled extensions.
+    pub extensions: ExtensionSet,
+}
+
+impl FromStr for IsaConfig {
+    type Err = IsaStringError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parser = Parser {
+            bytes: s.as_bytes(),
+            pos: 0,
+            extensions: ExtensionSet::empty(),
+            implied: ExtensionSet::empty(),
+        };
+
+        let isa = parser.parse_xlen()?;
+        parser.parse_base()?;
+        parser.parse_single_letters()?;
+        parser.parse_multi_letters()?;
+
+        Ok(IsaConfig {
+            isa,
+            // privileged instructions are not named in ISA strings.
+            extensions: parser.extensions.with(Extensions::Priv),
+        })
+    }
+}
+
+impl Display for IsaConfig {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        match self.isa {
+            Isa::Rv32 => write!(f, "rv32i")?,
+            Isa::Rv64 => write!(f, "rv64i")?,
+        }
+        // `b` is printed only if all of its sub-extensions are enabled.
+        let has_b = self.extensions.contains(Extensions::B);
+        for (letter, ext) in SINGLE_LETTER {
+            if self.extensions.contains(ext) {
+                write!(f, "{}", char::from(letter))?;
+            }
+        }
+        for (name, ext) in MULTI_LETTER {
+            if self.extensions.contains(ext) && !(has_b && B_IMPLIED.contains(&ext)) {
+                write!(f, "_{name}")?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// State of ISA string parsing.
+struct Parser<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+    extensions: ExtensionSet,
+    /// Extensions implied by `g` or `b` that may be named again without error.
+    implied: ExtensionSet,
+}
+
+impl Parser<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).map(u8::to_ascii_lowercase)
+    }
+
+    fn enable(&mut self, ext: Extensions, start: usize) -> Result<(), IsaStringError> {
+        if self.extensions.contains(ext) {
+            return Err(IsaStringError::DuplicateExtension(start));
+        }
+        self.extensions = self.extensions.with(ext);
+        Ok(())
+    }
+
+    fn imply(&mut self, exts: &[Extensions]) {
+        for ext in exts {
+            self.extensions = self.extensions.with(*ext);
+            self.implied = self.implied.with(*ext);
+        }
+    }
+
+    fn parse_xlen(&mut self) -> Result<Isa, IsaStringError> {
+        let prefix = self.bytes.get(..4).ok_or(IsaStringError::InvalidXlen)?;
+        self.pos = 4;
+
+        if prefix.eq_ignore_ascii_case(b"rv32") {
+            Ok(Isa::Rv32)
+        } else if prefix.eq_ignore_ascii_case(b"rv64") {
+            Ok(Isa::Rv64)
+        } else {
+            Err(IsaStringError::InvalidXlen)
+        }
+    }
+
+    fn parse_base(&mut self) -> Result<(), IsaStringError> {
+        match self.peek() {
+            Some(b'i') => {
+                self.pos += 1;
+                self.extensions = self.extensions.with(Extensions::BaseI);
+            }
+            Some(b'g') => {
+                self.pos += 1;
+                self.imply(&G_IMPLIED);
+            }
+            _ => return Err(IsaStringError::MissingBaseIsa),
+        }
+
+        self.parse_version()
+    }
+
+    /// Skip the optional version number (e.g. `2`, `2p1`).
+    fn parse_version(&mut self) -> Result<(), IsaStringError> {
+        let start = self.pos;
+        let skip_digits = |parser: &mut Self| {
+            let from = parser.pos;
+            while parser.peek().is_some_and(|c| c.is_ascii_digit()) {
+                parser.pos += 1;
+            }
+            parser.pos != from
+        };
+
+        if skip_digits(self) && self.peek() == Some(b'p') {
+            self.pos += 1;
+            if !skip_digits(self) {
+                return Err(IsaStringError::MalformedVersion(start));
+            }
+        }
+
+        Ok(())
+    }
+
+    fn parse_single_letters(&mut self) -> Result<(), IsaStringError> {
+        loop {
+            let start = self.pos;
+            match self.peek() {
+                None | Some(b'z' | b's' | b'x') => return Ok(()),
+                Some(b'_') => {
+                    // single-letter extensions may be separated by underscores.
+                    match self.bytes.get(self.pos + 1).map(u8::to_ascii_lowercase) {
+                        Some(b'z' | b's' | b'x') | None => return Ok(()),
+                        Some(c) if c.is_ascii_alphabetic() => self.pos += 1,
+                        Some(_) => return Err(IsaStringError::UnexpectedCharacter(start)),
+                    }
+                }
+                Some(letter) => {
+                    let ext = SINGLE_LETTER
+                        .iter()
+                        .find(|(l, _)| *l == letter)
+                        .map(|(_, ext)| *ext);
+                    match ext {
+                        Some(Extensions::B) => {
+                            self.enable(Extensions::B, start)?;
+                            self.imply(&B_IMPLIED);
+                        }
+                        Some(ext) => self.enable(ext, start)?,
+                        None if letter.is_ascii_alphabetic() => {
+                            return Err(IsaStringError::UnknownExtension(start))
+                        }
+                        None => return Err(IsaStringError::UnexpectedCharacter(start)),
+                    }
+                    self.pos += 1;
+                    self.parse_version()?;
+                }
+            }
+        }
+    }
+
+    fn parse_multi_letters(&mut self) -> Result<(), IsaStringError> {
+        while self.pos < self.bytes.len() {
+            if self.peek() == Some(b'_') {
+                self.pos += 1;
+            }
+
+            let start = self.pos;
+            let end = self.bytes[start..]
+                .iter()
+                .position(|c| *c == b'_')
+                .map_or(self.bytes.len(), |len| start + len);
+            let component = &self.bytes[start..end];
+            if component.is_empty() || !component.iter().all(u8::is_ascii_alphanumeric) {
+                return Err(IsaStringError::UnexpectedCharacter(start));
+            }
+
+            let name = strip_version(component).ok_or(IsaStringError::MalformedVersion(start))?;
+            let ext = MULTI_LETTER
+                .iter()
+                .chain(SUBSET.iter())
+                .find(|(n, _)| n.as_bytes().eq_ignore_ascii_case(name))
+                .map(|(_, ext)| *ext)
+                .ok_or(IsaStringError::UnknownExtension(start))?;
+
+            let is_subset = SUBSET
+                .iter()
+                .any(|(n, _)| n.as_bytes().eq_ignore_ascii_case(name));
+            if is_subset {
+                // e.g. "zaamo_zalrsc" enables A only once.
+                self.extensions = self.extensions.with(ext);
+            } else if self.implied.contains(ext) {
+                // e.g. "rv64g_zicsr" names Zicsr again, which is allowed once.
+                self.implied = self.implied.without(ext);
+            } else {
+                self.enable(ext, start)?;
+            }
+
+            self.pos = end;
+        }
+
+        Ok(())
+    }
+}
+
+/// Remove the trailing version number (e.g. `1p0`) from a multi-letter extension name.
+/// It returns `None` if the version number is malformed.
+fn strip_version(component: &[u8]) -> Option<&[u8]> {
+    let digits = |s: &[u8]| s.iter().rev().take_while(|c| c.is_ascii_digit()).count();
+
+    let minor = digits(component);
+    let rest = &component[..component.len() - minor];
+    match rest {
+        // "<major>p<minor>"
+        [.., major, p] if major.is_ascii_digit() && p.eq_ignore_ascii_case(&b'p') => {
+            if minor == 0 {
+                return None;
+            }
+            let rest = &rest[..rest.len() - 1];
+            Some(&rest[..rest.len() - digits(rest)])
+        }
+        _ => Some(rest),
+    }
+}
+
+#[cfg(test)]
+mod test_isa_string {
+    #[test]
+    fn isa_string_parse_test() {
+        use crate::{ExtensionSet, Extensions, Isa, IsaConfig, IsaStringError};
+
+        let rv64gc: IsaConfig = "rv64gc".parse().unwrap();
+        assert!(matches!(rv64gc.isa, Isa::Rv64));
+        assert_eq!(
+            rv64gc.extensions,
+            ExtensionSet::empty()
+                .with(Extensions::BaseI)
+                .with(Extensions::M)
+                .with(Extensions::A)
+                .with(Extensions::F)
+                .with(Extensions::D)
+                .with(Extensions::C)
+                .with(Extensions::Zicsr)
+                .with(Extensions::Zifencei)
+                .with(Extensions::Priv)
+        );
+        assert_eq!("rv64imafdc_zicsr_zifencei".parse::<IsaConfig>(), Ok(rv64gc));
+        assert_eq!(
+            "RV64I2P1_M2P0_A2P1_F2P2_D2P2_C2P0_ZICSR2P0_ZIFENCEI2P0".parse::<IsaConfig>(),
+            Ok(rv64gc)
+        );
+
+        let rv32 = "rv32imac_zicfiss1p0_zba_zbb".parse::<IsaConfig>().unwrap();
+        assert!(matches!(rv32.isa, Isa::Rv32));
+        assert!(rv32.extensions.contains(Extensions::Zicfiss));
+        assert!(!rv32.extensions.contains(Extensions::B));
+        assert!(rv32.extensions.contains(Extensions::Zbb));
+        assert!(!rv32.extensions.contains(Extensions::Zbc));
+        assert!(!rv32.extensions.contains(Extensions::F));
+
+        assert_eq!("rv64gc_zicsr_zifencei".parse::<IsaConfig>(), Ok(rv64gc));
+        assert_eq!(
+            "rv64gc_zicsr_zicsr".parse::<IsaConfig>(),
+            Err(IsaStringError::DuplicateExtension(13))
+        );
+        assert_eq!(
+            "rv64ib_zba".parse::<IsaConfig>(),
+            "rv64ib".parse::<IsaConfig>()
+        );
+        assert_eq!(
+            "rv64i_zba_zba".parse::<IsaConfig>(),
+            Err(IsaStringError::DuplicateExtension(10))
+        );
+
+        assert_eq!(
+            "rv128i".parse::<IsaConfig>(),
+            Err(IsaStringError::InvalidXlen)
+        );
+        assert_eq!(
+            "rv64mac".parse::<IsaConfig>(),
+            Err(IsaStringError::MissingBaseIsa)
+        );
+        assert_eq!(
+            "rv64imyc".parse::<IsaConfig>(),
+            Err(IsaStringError::UnknownExtension(6))
+        );
+        assert_eq!(
+            "rv64gm".parse::<IsaConfig>(),
+            Err(IsaStringError::DuplicateExtension(5))
+        );
+        assert_eq!(
+            "rv64i2p_m".parse::<IsaConfig>(),
+            Err(IsaStringError::MalformedVersion(5))
+        );
+        assert_eq!(
+            "rv64i_zicsr_xfoo".parse::<IsaConfig>(),
+            Err(IsaStringError::UnknownExtension(12))
+        );
+        assert_eq!(
+            "rv64i_zicsr__zifencei".parse::<IsaConfig>(),
+            Err(IsaStringError::UnexpectedCharacter(12))
+        );
+    }
+
+    #[test]
+    fn isa_string_display_test() {
+        use crate::IsaConfig;
+
+        let canonical = |s: &str| s.parse::<IsaConfig>().unwrap().to_string();
+
+        assert_eq!(canonical("rv64gc"), "rv64imafdc_zicsr_zifencei");
+        assert_eq!(canonical("rv32i"), "rv32i");
+        assert_eq!(
+            canonical("rv64ivhc_zifencei_zicboz"),
+            "rv64icvh_zicboz_zifencei"
+        );
+        assert_eq!(canonical("rv32i_m_a_zca"), "rv32imac");
+        assert_eq!(canonical("rv32i_zbb_zba"), "rv32i_zba_zbb");
+        assert_eq!(canonical("rv64i_zbs_zbb_zba_zbc"), "rv64ib_zbc");
+        assert_eq!(canonical("rv64gcb"), "rv64imafdcb_zicsr_zifencei");
+    }
+}
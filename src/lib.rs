@@ -3,8 +3,26 @@
 //! `raki` is a RISC-V instruction decoder written in Rust.
 //!
 //! - Both 32/64bit support.
-//! - Support `rv32/64imac`, `Zicsr`, `Zifencei` extensions.
+//! - Support `rv32/64imafdqcbv`, `Zicsr`, `Zifencei` extensions.
 //! - Implement Display trait for formatting.
+//! - Configurable register names, immediate radix and operand separators with `FormatOptions`.
+//! - Switchable pseudo-instruction aliases (e.g. `li`, `mv`, `ret`, `csrr`).
+//! - Symbolic csr names (e.g. `mstatus`) and the `Csr` lookup table.
+//! - Typed register operands (`XReg`, `FReg`, `VReg`) with `rd_reg`/`rs1_reg`/`csr` accessors.
+//! - Ordered operand lists (registers, immediates, memory, CSR, fence sets, ...) with `Instruction::operands`.
+//! - Control-flow classification (branch, call, return, trap, ...) with `Instruction::control_flow`.
+//! - Register def/use sets including implicit operands with `Instruction::reads`/`writes`.
+//! - Memory access descriptors (base, offset, width, signedness, aq/rl) with `Instruction::memory_access`.
+//! - Typed FENCE ordering fields (`fm`, predecessor/successor sets) with `Instruction::fence`.
+//! - Expand compressed instructions and compress 32-bit ones with `Instruction::expand`/`compress`.
+//! - Formatting in the layout of GNU `objdump -d -M no-aliases` with `Instruction::objdump`.
+//! - Encode `Instruction` back into u16/u32 machine code.
+//! - Restrict decoding to the extensions enabled in `ExtensionSet`.
+//! - Parse ISA strings (e.g. `rv64gc_zicsr`) into `IsaConfig`.
+//! - Decode a byte stream mixing 16/32bit instructions with `decode_stream`.
+//! - Resolve branch/jump targets and `auipc` pairs to symbols with `Symbolizer`.
+//! - Disassemble executable sections of ELF files (`elf` feature).
+//! - `raki` command-line disassembler (`cli` feature).
 //!
 //! # Usage
 //! Call the `decode` as u16/u32 method.
@@ -26,20 +44,47 @@
 //! ```
 #![cfg_attr(not(test), no_std)]
 
+#[cfg(feature = "elf")]
+extern crate alloc;
+
 mod decode;
+#[cfg(feature = "elf")]
+mod elf;
+mod encode;
 mod instruction;
+mod isa_string;
+mod symbol;
 
 // re-export
-pub use crate::decode::{Decode, DecodingError};
+pub use crate::decode::{decode_stream, Decode, DecodedInst, Decoder, DecodingError};
+#[cfg(feature = "elf")]
+pub use crate::elf::{ElfError, ElfFile, Listing, Section, Symbol};
+pub use crate::encode::{Encode, EncodingError};
 pub use crate::instruction::{
-    a_extension::AOpcode, base_i::BaseIOpcode, c_extension::COpcode, m_extension::MOpcode,
-    priv_extension::PrivOpcode, zicboz_extension::ZicbozOpcode, zicfiss_extension::ZicfissOpcode,
-    zicntr_extension::ZicntrOpcode, zicsr_extension::ZicsrOpcode,
-    zifencei_extension::ZifenceiOpcode, InstFormat, Instruction, OpcodeKind,
+    a_extension::AOpcode,
+    b_extension::BOpcode,
+    base_i::{BaseIOpcode, Fence, FenceMode, FenceSet},
+    c_extension::COpcode,
+    d_extension::DOpcode,
+    f_extension::{FOpcode, RoundingMode},
+    m_extension::MOpcode,
+    priv_extension::PrivOpcode,
+    q_extension::QOpcode,
+    v_extension::{Lmul, VOpcode, Vtype},
+    zicboz_extension::ZicbozOpcode,
+    zicfiss_extension::ZicfissOpcode,
+    zicntr_extension::ZicntrOpcode,
+    zicsr_extension::{Csr, ZicsrOpcode},
+    zifencei_extension::ZifenceiOpcode,
+    AccessKind, AccessWidth, Aliased, ControlFlow, DisplayWith, FReg, FormatOptions, InstFormat,
+    Instruction, MemoryAccess, Objdump, OpcodeKind, Operand, Operands, Radix, Register,
+    RegisterSet, VReg, XReg,
 };
+pub use crate::isa_string::{IsaConfig, IsaStringError};
+pub use crate::symbol::{SymbolLookup, SymbolicInst, Symbolizer};
 
 /// Target isa.
-#[derive(Copy, Clone)]
+#[derive(Debug, Copy, Clone, PartialEq)]
 pub enum Isa {
     /// 32 bit architecture.
     Rv32,
@@ -48,16 +93,28 @@ pub enum Isa {
 }
 
 /// RISC-V extensions
-#[derive(Debug)]
-enum Extensions {
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Extensions {
     /// Base Integer Instruction Set
     BaseI,
     /// Integer Multiplication and Division
     M,
     /// Atomic Instructions
     A,
+    /// Single-Precision Floating-Point
+    F,
+    /// Double-Precision Floating-Point
+    D,
+    /// Quad-Precision Floating-Point
+    Q,
     /// Compressed Instructions
     C,
+    /// Bit-Manipulation (shorthand for Zba, Zbb and Zbs in `ExtensionSet`)
+    B,
+    /// Vector Operations
+    V,
+    /// Hypervisor
+    H,
     /// Instruction-Fetch Fence
     Zifencei,
     /// Cache-Block Zero Instructions
@@ -68,20 +125,115 @@ enum Extensions {
     Zicfiss,
     /// Base Counters and Timers
     Zicntr,
+    /// Address Generation (subset of B)
+    Zba,
+    /// Basic Bit-Manipulation (subset of B)
+    Zbb,
+    /// Carry-Less Multiplication (subset of B)
+    Zbc,
+    /// Single-Bit Instructions (subset of B)
+    Zbs,
     /// Privileged Instructions
     Priv,
 }
 
+/// Set of enabled extensions.
+///
+/// # Example
+/// ```
+/// use raki::{Decode, DecodingError, ExtensionSet, Extensions, Isa};
+///
+/// // a core without floating-point support.
+/// let rv32imac = ExtensionSet::empty()
+///     .with(Extensions::BaseI)
+///     .with(Extensions::M)
+///     .with(Extensions::A)
+///     .with(Extensions::C)
+///     .with(Extensions::Zicsr)
+///     .with(Extensions::Zifencei);
+///
+/// // mul a0, a0, a1
+/// assert!(0x02b5_0533_u32.decode_with(Isa::Rv32, rv32imac).is_ok());
+/// // fadd.s fa0, fa0, fa1
+/// assert_eq!(
+///     0x00b5_7553_u32.decode_with(Isa::Rv32, rv32imac),
+///     Err(DecodingError::DisabledExtension(Extensions::F))
+/// );
+/// ```
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct ExtensionSet(u32);
+
+impl ExtensionSet {
+    /// Bits of `ext`. `B` has no bit of its own and stands for Zba, Zbb and Zbs.
+    const fn bits(ext: Extensions) -> u32 {
+        match ext {
+            Extensions::B => {
+                1 << Extensions::Zba as u32
+                    | 1 << Extensions::Zbb as u32
+                    | 1 << Extensions::Zbs as u32
+            }
+            _ => 1 << ext as u32,
+        }
+    }
+
+    /// Extension set that contains no extension.
+    #[must_use]
+    pub const fn empty() -> Self {
+        ExtensionSet(0)
+    }
+
+    /// Extension set that contains all extensions supported by raki.
+    #[must_use]
+    pub const fn all() -> Self {
+        // `Priv` is the last variant of `Extensions`.
+        ExtensionSet(((1 << (Extensions::Priv as u32 + 1)) - 1) & !(1 << Extensions::B as u32))
+    }
+
+    /// Return a new set with `ext` enabled.
+    ///
+    /// `Extensions::B` also enables Zba, Zbb and Zbs.
+    #[must_use]
+    pub const fn with(self, ext: Extensions) -> Self {
+        ExtensionSet(self.0 | Self::bits(ext))
+    }
+
+    /// Return a new set with `ext` disabled.
+    ///
+    /// `Extensions::B` also disables Zba, Zbb and Zbs.
+    #[must_use]
+    pub const fn without(self, ext: Extensions) -> Self {
+        ExtensionSet(self.0 & !Self::bits(ext))
+    }
+
+    /// Check whether `ext` is enabled.
+    ///
+    /// `Extensions::B` is enabled only if Zba, Zbb and Zbs are all enabled.
+    #[must_use]
+    pub const fn contains(self, ext: Extensions) -> bool {
+        self.0 & Self::bits(ext) == Self::bits(ext)
+    }
+}
+
+impl Default for ExtensionSet {
+    fn default() -> Self {
+        ExtensionSet::all()
+    }
+}
+
+/// Decode an instruction as `Isa::Rv64`.
+///
+/// Values that do not fit in the instruction length are rejected as `IllegalInstruction`.
+/// Use `Decode` or `decode_stream` to decode for other isa.
 impl TryFrom<usize> for Instruction {
     type Error = DecodingError;
     fn try_from(inst: usize) -> Result<Self, Self::Error> {
         if inst & 0b11 == 0b11 {
             u32::try_from(inst)
-                .expect("Truncation of usize to u32 failed.")
+                .map_err(|_| DecodingError::IllegalInstruction)?
                 .decode(Isa::Rv64)
         } else {
             u16::try_from(inst)
-                .expect("Truncation of usize to u16 failed.")
+                .map_err(|_| DecodingError::IllegalInstruction)?
                 .decode(Isa::Rv64)
         }
     }
@@ -94,6 +246,7 @@ mod tests {
         use crate::instruction::{
             base_i::BaseIOpcode, c_extension::COpcode, InstFormat, Instruction, OpcodeKind,
         };
+        use crate::DecodingError;
 
         assert_eq!(
             Instruction::try_from(0b1111_1111_1001_1111_1111_0000_0110_1111_usize),
@@ -102,7 +255,10 @@ mod tests {
                 rd: Some(0),
                 rs1: None,
                 rs2: None,
+                rs3: None,
                 imm: Some(-8),
+                vm: None,
+                rm: None,
                 inst_format: InstFormat::JFormat,
                 is_compressed: false,
             })
@@ -115,7 +271,10 @@ mod tests {
                 rd: Some(16),
                 rs1: None,
                 rs2: Some(2),
+                rs3: None,
                 imm: None,
+                vm: None,
+                rm: None,
                 inst_format: InstFormat::CrFormat,
                 is_compressed: true,
             })
@@ -128,7 +287,10 @@ mod tests {
                 rd: Some(16),
                 rs1: None,
                 rs2: Some(2),
+                rs3: None,
                 imm: None,
+                vm: None,
+                rm: None,
                 inst_format: InstFormat::CrFormat,
                 is_compressed: true,
             })
@@ -140,6 +302,15 @@ mod tests {
                 .opc,
             OpcodeKind::BaseI(BaseIOpcode::JAL),
         );
+
+        assert_eq!(
+            Instruction::try_from(0x1_0000_880a_usize),
+            Err(DecodingError::IllegalInstruction)
+        );
+        assert_eq!(
+            Instruction::try_from(0x1_0000_0013_usize),
+            Err(DecodingError::IllegalInstruction)
+        );
     }
 
     #[test]
@@ -155,7 +326,10 @@ mod tests {
                 rd: Some(0),
                 rs1: None,
                 rs2: None,
+                rs3: None,
                 imm: Some(-8),
+                vm: None,
+                rm: None,
                 inst_format: InstFormat::JFormat,
                 is_compressed: false,
             })
@@ -169,4 +343,89 @@ mod tests {
             OpcodeKind::BaseI(BaseIOpcode::JAL),
         );
     }
+
+    #[test]
+    fn extension_set_test() {
+        use crate::decode::Decode;
+        use crate::{DecodingError, ExtensionSet, Extensions, Isa};
+
+        let rv64gc = ExtensionSet::all()
+            .without(Extensions::Q)
+            .without(Extensions::B)
+            .without(Extensions::V)
+            .without(Extensions::H)
+            .without(Extensions::Zicfiss);
+        assert!(rv64gc.contains(Extensions::C));
+        assert!(!rv64gc.contains(Extensions::H));
+        assert_eq!(ExtensionSet::default(), ExtensionSet::all());
+
+        // mul a0, a0, a1
+        assert!(0x02b5_0533_u32.decode_with(Isa::Rv64, rv64gc).is_ok());
+        assert_eq!(
+            0x02b5_0533_u32.decode_with(Isa::Rv64, rv64gc.without(Extensions::M)),
+            Err(DecodingError::DisabledExtension(Extensions::M))
+        );
+        // c.addi a0, 1
+        assert_eq!(
+            0x0505_u16.decode_with(Isa::Rv64, rv64gc.without(Extensions::C)),
+            Err(DecodingError::DisabledExtension(Extensions::C))
+        );
+        // hlv.w a0, (a1)
+        assert_eq!(
+            0x6805_c573_u32.decode_with(Isa::Rv64, rv64gc),
+            Err(DecodingError::DisabledExtension(Extensions::H))
+        );
+        // sret
+        assert!(0x1020_0073_u32.decode_with(Isa::Rv64, rv64gc).is_ok());
+        // sspush ra
+        assert_eq!(
+            0xce10_4073_u32.decode_with(Isa::Rv64, rv64gc),
+            Err(DecodingError::DisabledExtension(Extensions::Zicfiss))
+        );
+
+        let rv64imac = rv64gc.without(Extensions::F).without(Extensions::D);
+        // c.fld fa0, 8(a1)
+        assert!(0x2588_u16.decode_with(Isa::Rv64, rv64gc).is_ok());
+        assert_eq!(
+            0x2588_u16.decode_with(Isa::Rv64, rv64imac),
+            Err(DecodingError::DisabledExtension(Extensions::D))
+        );
+        // c.flw fa0, 0(a1)
+        assert_eq!(
+            0x6188_u16.decode_with(Isa::Rv32, rv64imac),
+            Err(DecodingError::DisabledExtension(Extensions::F))
+        );
+        // c.ld a0, 0(a1)
+        assert!(0x6188_u16.decode_with(Isa::Rv64, rv64imac).is_ok());
+        // vfadd.vv v8, v8, v9
+        let rv64iv = rv64imac.with(Extensions::V);
+        assert_eq!(
+            0x0284_9457_u32.decode_with(Isa::Rv64, rv64iv),
+            Err(DecodingError::DisabledExtension(Extensions::F))
+        );
+        // vadd.vv v8, v8, v9
+        assert!(0x0284_8457_u32.decode_with(Isa::Rv64, rv64iv).is_ok());
+
+        let rv64imacb = rv64imac.with(Extensions::B).without(Extensions::Zbc);
+        assert!(rv64imacb.contains(Extensions::Zbs));
+        // sh1add a0, a0, a1
+        assert!(0x20b5_2533_u32.decode_with(Isa::Rv64, rv64imacb).is_ok());
+        // clmul a0, a0, a1
+        assert_eq!(
+            0x0ab5_1533_u32.decode_with(Isa::Rv64, rv64imacb),
+            Err(DecodingError::DisabledExtension(Extensions::Zbc))
+        );
+
+        let zba_only = ExtensionSet::empty()
+            .with(Extensions::BaseI)
+            .with(Extensions::Zba);
+        assert!(!zba_only.contains(Extensions::B));
+        // sh1add a0, a0, a1
+        assert!(0x20b5_2533_u32.decode_with(Isa::Rv64, zba_only).is_ok());
+        // clz a0, a0
+        assert_eq!(
+            0x6005_1513_u32.decode_with(Isa::Rv64, zba_only),
+            Err(DecodingError::DisabledExtension(Extensions::Zbb))
+        );
+    }
 }
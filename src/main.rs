@@ -0,0 +1,273 @@
+//! raki
+//!
+//! Command-line disassembler for hex words, raw binary files and ELF files.
+
+use std::process::ExitCode;
+use std::{env, fs};
+
+use raki::{
+    Decode, DecodedInst, Decoder, DecodingError, ElfFile, ExtensionSet, FormatOptions, Instruction,
+    Isa, IsaConfig, Symbolizer,
+};
+
+const USAGE: &str = "\
+Usage: raki [OPTIONS] <WORD>...
+       raki [OPTIONS] --file <FILE>
+
+Disassemble hex words (e.g. `00150513 8082`), a raw binary file or an ELF file.
+
+Options:
+  --file <FILE>         Disassemble a raw binary file or an ELF file
+  --isa <rv32|rv64>     Target isa [default: rv64, or EI_CLASS for ELF files]
+  --march <ISA-STRING>  Enabled extensions (e.g. rv64imac_zicsr) [default: all]
+  --start <ADDR>        Address of the first instruction [default: 0]
+  --raw-names           Print raw register names (x10) instead of ABI names (a0)
+  --no-aliases          Print canonical instructions instead of pseudo-instructions
+  -h, --help            Print help";
+
+/// Command-line options.
+#[derive(Debug, Default, PartialEq)]
+struct Options {
+    file: Option<String>,
+    words: Vec<String>,
+    isa: Option<Isa>,
+    march: Option<IsaConfig>,
+    start: u64,
+    raw_names: bool,
+    no_aliases: bool,
+}
+
+impl Options {
+    fn parse(mut args: impl Iterator<Item = String>) -> Result<Self, String> {
+        let mut opts = Options::default();
+        while let Some(arg) = args.next() {
+            let mut value = |name: &str| args.next().ok_or(format!("{name} requires a value"));
+            match arg.as_str() {
+                "--file" => opts.file = Some(value("--file")?),
+                "--isa" => {
+                    opts.isa = match value("--isa")?.as_str() {
+                        "rv32" => Some(Isa::Rv32),
+                        "rv64" => Some(Isa::Rv64),
+                        isa => return Err(format!("unknown isa: {isa}")),
+                    }
+                }
+                "--march" => {
+                    let march = value("--march")?;
+                    let config = march
+                        .parse()
+                        .map_err(|e| format!("invalid isa string {march}: {e:?}"))?;
+                    opts.march = Some(config);
+                }
+                "--start" => {
+                    let start = value("--start")?;
+                    opts.start =
+                        parse_hex(&start).ok_or(format!("invalid start address: {start}"))?;
+                }
+                "--raw-names" => opts.raw_names = true,
+                "--no-aliases" => opts.no_aliases = true,
+                "-h" | "--help" => return Err(String::new()),
+                _ if arg.starts_with('-') => return Err(format!("unknown option: {arg}")),
+                _ => opts.words.push(arg),
+            }
+        }
+
+        match (&opts.file, opts.words.is_empty()) {
+            (None, true) => Err("no input".to_string()),
+            (Some(_), false) => Err("hex words and --file cannot be used together".to_string()),
+            _ => Ok(opts),
+        }
+    }
+
+    /// Isa given by `--isa` or `--march`.
+    fn isa(&self) -> Option<Isa> {
+        self.isa.or(self.march.map(|config| config.isa))
+    }
+
+    fn extensions(&self) -> ExtensionSet {
+        self.march
+            .map_or_else(ExtensionSet::all, |config| config.extensions)
+    }
+
+    fn format_options(&self) -> FormatOptions {
+        FormatOptions::new()
+            .with_numeric_names(self.raw_names)
+            .with_aliases(!self.no_aliases)
+    }
+
+    /// Print instructions in `bytes` located at `start`.
+    fn print_stream(&self, bytes: &[u8], isa: Isa, start: u64) {
+        let mut symbolizer = Symbolizer::new(isa).with_options(&self.format_options());
+        let decoder = Decoder::new(bytes, isa).with_extensions(self.extensions());
+        for DecodedInst {
+            offset,
+            raw,
+            len,
+            result,
+        } in decoder
+        {
+            let raw = match (&result, len) {
+                (Ok(_), 4) => format!("{raw:08x}"),
+                _ if offset + 1 == bytes.len() => format!("{raw:02x}"),
+                // undecodable 16bit unit (see resync of `Decoder`).
+                _ => format!("{:04x}", raw & 0xffff),
+            };
+            let addr = start.wrapping_add(offset as u64);
+            print(&mut symbolizer, addr, &raw, result);
+        }
+    }
+}
+
+fn print(
+    symbolizer: &mut Symbolizer,
+    addr: u64,
+    raw: &str,
+    inst: Result<Instruction, DecodingError>,
+) {
+    match inst {
+        Ok(inst) => println!("{addr:8x}:\t{raw:<8}\t{}", symbolizer.format(&inst, addr)),
+        Err(e) => println!("{addr:8x}:\t{raw:<8}\t<{e:?}>"),
+    }
+}
+
+/// Parse a hexadecimal number with an optional `0x` prefix and `_` separators.
+fn parse_hex(s: &str) -> Option<u64> {
+    let digits: String = s
+        .strip_prefix("0x")
+        .unwrap_or(s)
+        .chars()
+        .filter(|c| *c != '_')
+        .collect();
+    u64::from_str_radix(&digits, 16).ok()
+}
+
+fn disassemble_words(opts: &Options) -> Result<(), String> {
+    let isa = opts.isa().unwrap_or(Isa::Rv64);
+    let extensions = opts.extensions();
+    let mut symbolizer = Symbolizer::new(isa).with_options(&opts.format_options());
+
+    let mut addr = opts.start;
+    for word in &opts.words {
+        let value = parse_hex(word).ok_or(format!("invalid hex word: {word}"))?;
+        // the number of digits decides the instruction length.
+        let digits = word.trim_start_matches("0x").replace('_', "").len();
+        if digits <= 4 {
+            let inst = u16::try_from(value).map_err(|_| format!("invalid hex word: {word}"))?;
+            print(
+                &mut symbolizer,
+                addr,
+                &format!("{inst:04x}"),
+                inst.decode_with(isa, extensions),
+            );
+            addr = addr.wrapping_add(2);
+        } else {
+            let inst = u32::try_from(value).map_err(|_| format!("invalid hex word: {word}"))?;
+            print(
+                &mut symbolizer,
+                addr,
+                &format!("{inst:08x}"),
+                inst.decode_with(isa, extensions),
+            );
+            addr = addr.wrapping_add(4);
+        }
+    }
+
+    Ok(())
+}
+
+fn disassemble_file(opts: &Options, path: &str) -> Result<(), String> {
+    let bytes = fs::read(path).map_err(|e| format!("{path}: {e}"))?;
+    if !bytes.starts_with(b"\x7fELF") {
+        let isa = opts.isa().unwrap_or(Isa::Rv64);
+        opts.print_stream(&bytes, isa, opts.start);
+        return Ok(());
+    }
+
+    let elf = ElfFile::parse(&bytes).map_err(|e| format!("{path}: {e:?}"))?;
+    let listing = elf
+        .disassemble()
+        .with_isa(opts.isa().unwrap_or(elf.isa()))
+        .with_extensions(opts.extensions())
+        .with_options(&opts.format_options());
+    print!("{listing}");
+
+    Ok(())
+}
+
+fn main() -> ExitCode {
+    let opts = match Options::parse(env::args().skip(1)) {
+        Ok(opts) => opts,
+        Err(msg) if msg.is_empty() => {
+            println!("{USAGE}");
+            return ExitCode::SUCCESS;
+        }
+        Err(msg) => {
+            eprintln!("error: {msg}\n\n{USAGE}");
+            return ExitCode::from(2);
+        }
+    };
+
+    let result = match &opts.file {
+        Some(path) => disassemble_file(&opts, path),
+        None => disassemble_words(&opts),
+    };
+    if let Err(msg) = result {
+        eprintln!("error: {msg}");
+        return ExitCode::FAILURE;
+    }
+
+    ExitCode::SUCCESS
+}
+
+#[cfg(test)]
+mod test_cli {
+    use super::{parse_hex, Options};
+    use raki::{Extensions, Isa};
+
+    fn parse(args: &[&str]) -> Result<Options, String> {
+        Options::parse(args.iter().map(ToString::to_string))
+    }
+
+    #[test]
+    fn parse_options_test() {
+        let opts = parse(&[
+            "--isa",
+            "rv32",
+            "--start",
+            "0x8000_0000",
+            "00150513",
+            "8082",
+        ])
+        .unwrap();
+        assert_eq!(opts.isa(), Some(Isa::Rv32));
+        assert_eq!(opts.start, 0x8000_0000);
+        assert_eq!(opts.words, vec!["00150513", "8082"]);
+        assert!(!opts.raw_names);
+        assert!(!opts.no_aliases);
+
+        let opts = parse(&[
+            "--march",
+            "rv32imac",
+            "--raw-names",
+            "--no-aliases",
+            "--file",
+            "a.out",
+        ])
+        .unwrap();
+        assert_eq!(opts.isa(), Some(Isa::Rv32));
+        assert!(!opts.extensions().contains(Extensions::F));
+        assert_eq!(opts.file.as_deref(), Some("a.out"));
+        assert!(opts.raw_names);
+        assert!(opts.no_aliases);
+
+        assert!(parse(&[]).is_err());
+        assert!(parse(&["--isa", "rv128", "8082"]).is_err());
+        assert!(parse(&["--start"]).is_err());
+        assert!(parse(&["--file", "a.out", "8082"]).is_err());
+        assert!(parse(&["--unknown", "8082"]).is_err());
+        assert_eq!(parse(&["--help"]), Err(String::new()));
+
+        assert_eq!(parse_hex("0x8000_0000"), Some(0x8000_0000));
+        assert_eq!(parse_hex("8082"), Some(0x8082));
+        assert_eq!(parse_hex("xyz"), None);
+    }
+}
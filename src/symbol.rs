@@ -0,0 +1,395 @@
+//! Symbol-aware formatting of instructions.
+
+use core::fmt::{self, Display, Formatter};
+
+use crate::instruction::{
+    alias::fmt_alias, base_i::BaseIOpcode, d_extension::DOpcode, f_extension::FOpcode,
+    objdump::fmt_objdump, q_extension::QOpcode, ControlFlow, FormatOptions, InstFormat,
+    Instruction, OpcodeKind, Register, XReg,
+};
+use crate::Isa;
+
+/// Table to resolve an address to a symbol.
+pub trait SymbolLookup {
+    /// Return the symbol at or before `addr` and the offset from it.
+    fn lookup(&self, addr: u64) -> Option<(&str, u64)>;
+}
+
+/// Symbols as `(address, name)` pairs sorted by address.
+impl SymbolLookup for [(u64, &str)] {
+    fn lookup(&self, addr: u64) -> Option<(&str, u64)> {
+        let index = self.partition_point(|(sym_addr, _)| *sym_addr <= addr);
+        let (sym_addr, name) = self.get(index.checked_sub(1)?)?;
+        Some((name, addr - sym_addr))
+    }
+}
+
+/// Formatter that prints absolute branch/jump targets and resolves them to symbols.
+///
+/// `auipc` is paired with the following `addi`, `jalr`, loads and stores
+/// that use its destination register, and the effective address is printed as a comment.
+/// Instructions must be formatted in program order for the pairing.
+///
+/// # Example
+/// ```
+/// use raki::{Decode, Isa, Symbolizer};
+///
+/// let symbols = [(0x8000_0000, "_start"), (0x8000_0124, "memcpy")];
+/// let mut symbolizer = Symbolizer::new(Isa::Rv64).with_symbols(&symbols[..]);
+///
+/// // jal ra, 0x120
+/// let jal = 0x1200_00ef_u32.decode(Isa::Rv64).unwrap();
+/// assert_eq!(
+///     symbolizer.format(&jal, 0x8000_0004).to_string(),
+///     "jal ra, 0x80000124 <memcpy>"
+/// );
+///
+/// // auipc a0, 0x0 / addi a0, a0, 0x128
+/// let auipc = 0x0000_0517_u32.decode(Isa::Rv64).unwrap();
+/// let addi = 0x1285_0513_u32.decode(Isa::Rv64).unwrap();
+/// symbolizer.format(&auipc, 0x8000_0008);
+/// assert_eq!(
+///     symbolizer.format(&addi, 0x8000_000c).to_string(),
+///     "addi a0, a0, 296 # 0x80000130 <memcpy+0xc>"
+/// );
+/// ```
+pub struct Symbolizer<'s, S: SymbolLookup + ?Sized = [(u64, &'static str)]> {
+    isa: Isa,
+    symbols: Option<&'s S>,
+    /// Destination register and value of the last `auipc`.
+    auipc: Option<(XReg, u64)>,
+    opts: FormatOptions,
+    objdump: bool,
+}
+
+impl Symbolizer<'_> {
+    /// Create a formatter without symbols.
+    #[must_use]
+    pub fn new(isa: Isa) -> Self {
+        Symbolizer {
+            isa,
+            symbols: None,
+            auipc: None,
+            opts: FormatOptions::new(),
+            objdump: false,
+        }
+    }
+}
+
+impl<'s, S: SymbolLookup + ?Sized> Symbolizer<'s, S> {
+    /// Resolve addresses with the symbol table.
+    #[must_use]
+    pub fn with_symbols<T: SymbolLookup + ?Sized>(self, symbols: &'s T) -> Symbolizer<'s, T> {
+        Symbolizer {
+            isa: self.isa,
+            symbols: Some(symbols),
+            auipc: self.auipc,
+            opts: self.opts,
+            objdump: self.objdump,
+        }
+    }
+
+    /// Print pseudo-instructions (e.g. `j`, `ret`) if `aliases` is true.
+    #[must_use]
+    pub fn with_aliases(self, aliases: bool) -> Self {
+        Symbolizer {
+            opts: self.opts.with_aliases(aliases),
+            ..self
+        }
+    }
+
+    /// Format instructions with `opts` (which also decides whether pseudo-instructions are printed).
+    ///
+    /// Only the register names are taken from `opts` in the objdump mode.
+    #[must_use]
+    pub fn with_options(self, opts: &FormatOptions) -> Self {
+        Symbolizer {
+            opts: *opts,
+            ..self
+        }
+    }
+
+    /// Format instructions like `objdump -d -M no-aliases` if `objdump` is true.
+    ///
+    /// Pseudo-instructions are not printed in this mode.
+    #[must_use]
+    pub fn with_objdump(self, objdump: bool) -> Self {
+        Symbolizer { objdump, ..self }
+    }
+
+    /// Format the instruction located at `pc`.
+    ///
+    /// The alternate flag (`{:#}`) prints raw register names.
+    pub fn format<'a>(&mut self, inst: &'a Instruction, pc: u64) -> SymbolicInst<'a, 's, S> {
+        let isa = self.isa;
+        let offset = |imm: i32| wrap(isa, pc.wrapping_add_signed(i64::from(imm)));
+        let target = match (
+            inst.control_flow().and_then(ControlFlow::offset),
+            &inst.opc,
+            inst.imm,
+        ) {
+            (Some(imm), _, _) => Some(Target::Operand(offset(imm))),
+            (_, opc, Some(imm)) if is_auipc_pair(opc) => match self.auipc {
+                Some((reg, value)) if inst.rs1_reg() == Some(Register::X(reg)) => Some(
+                    Target::Comment(wrap(isa, value.wrapping_add_signed(i64::from(imm)))),
+                ),
+                _ => None,
+            },
+            _ => None,
+        };
+
+        self.auipc = match (&inst.opc, inst.rd_reg(), inst.imm) {
+            (OpcodeKind::BaseI(BaseIOpcode::AUIPC), Some(Register::X(rd)), Some(imm)) => {
+                Some((rd, offset(imm << 12)))
+            }
+            // the register is overwritten.
+            _ if self
+                .auipc
+                .is_some_and(|(reg, _)| inst.writes().contains(Register::X(reg))) =>
+            {
+                None
+            }
+            _ => self.auipc,
+        };
+
+        SymbolicInst {
+            inst,
+            target,
+            symbols: self.symbols,
+            opts: self.opts,
+            objdump: self.objdump,
+        }
+    }
+}
+
+/// Truncate the address to xlen.
+fn wrap(isa: Isa, addr: u64) -> u64 {
+    match isa {
+        Isa::Rv32 => addr & 0xffff_ffff,
+        Isa::Rv64 => addr,
+    }
+}
+
+/// Return true if the instruction adds its immediate to the value of `auipc`.
+fn is_auipc_pair(opc: &OpcodeKind) -> bool {
+    matches!(
+        opc,
+        OpcodeKind::BaseI(
+            BaseIOpcode::ADDI
+                | BaseIOpcode::JALR
+                | BaseIOpcode::LB
+                | BaseIOpcode::LH
+                | BaseIOpcode::LW
+                | BaseIOpcode::LBU
+                | BaseIOpcode::LHU
+                | BaseIOpcode::LWU
+                | BaseIOpcode::LD
+                | BaseIOpcode::SB
+                | BaseIOpcode::SH
+                | BaseIOpcode::SW
+                | BaseIOpcode::SD
+        ) | OpcodeKind::F(FOpcode::FLW | FOpcode::FSW)
+            | OpcodeKind::D(DOpcode::FLD | DOpcode::FSD)
+            | OpcodeKind::Q(QOpcode::FLQ | QOpcode::FSQ)
+    )
+}
+
+/// Address computed from the pc.
+#[derive(Debug, Copy, Clone)]
+enum Target {
+    /// Branch/jump target printed as an operand.
+    Operand(u64),
+    /// Effective address printed as a comment.
+    Comment(u64),
+}
+
+/// Instruction formatted by `Symbolizer`.
+pub struct SymbolicInst<'a, 's, S: SymbolLookup + ?Sized> {
+    inst: &'a Instruction,
+    target: Option<Target>,
+    symbols: Option<&'s S>,
+    opts: FormatOptions,
+    objdump: bool,
+}
+
+impl<S: SymbolLookup + ?Sized> SymbolicInst<'_, '_, S> {
+    fn fmt_symbol(&self, f: &mut Formatter, addr: u64) -> fmt::Result {
+        match self.symbols.and_then(|symbols| symbols.lookup(addr)) {
+            Some((name, 0)) => write!(f, " <{name}>"),
+            Some((name, offset)) => write!(f, " <{name}+{offset:#x}>"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl<S: SymbolLookup + ?Sized> Display for SymbolicInst<'_, '_, S> {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        let inst = self.inst;
+        let opts = if f.alternate() {
+            self.opts.with_numeric_names(true)
+        } else {
+            self.opts
+        };
+
+        if self.objdump {
+            return match self.target {
+                Some(Target::Operand(addr)) => {
+                    fmt_objdump(inst, f, &opts, Some(addr))?;
+                    self.fmt_symbol(f, addr)
+                }
+                Some(Target::Comment(addr)) => {
+                    fmt_objdump(inst, f, &opts, None)?;
+                    write!(f, " # {addr:x}")?;
+                    self.fmt_symbol(f, addr)
+                }
+                None => fmt_objdump(inst, f, &opts, None),
+            };
+        }
+
+        match self.target {
+            Some(Target::Operand(addr)) => {
+                if opts.aliases() {
+                    if let Some(result) = fmt_alias(inst, f, &opts, Some(addr)) {
+                        result?;
+                        return self.fmt_symbol(f, addr);
+                    }
+                }
+                let (x, sep) = (|reg| opts.xreg(reg), opts.separator());
+                match inst.inst_format {
+                    InstFormat::BFormat => write!(
+                        f,
+                        "{} {}{sep}{}{sep}{addr:#x}",
+                        inst.opc,
+                        x(inst.rs1.unwrap()),
+                        x(inst.rs2.unwrap())
+                    )?,
+                    InstFormat::JFormat => {
+                        write!(f, "{} {}{sep}{addr:#x}", inst.opc, x(inst.rd.unwrap()))?;
+                    }
+                    InstFormat::CbFormat => {
+                        write!(f, "{} {}{sep}{addr:#x}", inst.opc, x(inst.rs1.unwrap()))?;
+                    }
+                    _ => write!(f, "{} {addr:#x}", inst.opc)?,
+                }
+                self.fmt_symbol(f, addr)
+            }
+            Some(Target::Comment(addr)) => {
+                write!(f, "{} # {addr:#x}", inst.display_with(&opts))?;
+                self.fmt_symbol(f, addr)
+            }
+            None => write!(f, "{}", inst.display_with(&opts)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test_symbol {
+    #[test]
+    fn symbolizer_test() {
+        use super::{SymbolLookup, Symbolizer};
+        use crate::{Decode, FormatOptions, Isa};
+
+        let symbols = [(0x8000_0000, "_start"), (0x8000_0100, "loop")];
+        assert_eq!(symbols[..].lookup(0x7fff_ffff), None);
+        assert_eq!(symbols[..].lookup(0x8000_0000), Some(("_start", 0)));
+        assert_eq!(symbols[..].lookup(0x8000_0104), Some(("loop", 4)));
+
+        let format = |symbolizer: &mut Symbolizer<'_>, inst: u32, pc| {
+            let inst = match u16::try_from(inst) {
+                Ok(inst) if inst & 0b11 != 0b11 => inst.decode(Isa::Rv32).unwrap(),
+                _ => inst.decode(Isa::Rv32).unwrap(),
+            };
+            symbolizer.format(&inst, pc).to_string()
+        };
+        let mut symbolizer = Symbolizer::new(Isa::Rv32).with_symbols(&symbols[..]);
+
+        // bne a0, a1, -8
+        assert_eq!(
+            format(&mut symbolizer, 0xfeb5_1ce3, 0x8000_0108),
+            "bne a0, a1, 0x80000100 <loop>"
+        );
+        // c.j -4
+        assert_eq!(
+            format(&mut symbolizer, 0xbff5, 0x8000_0104),
+            "C.j 0x80000100 <loop>"
+        );
+        // c.beqz a0, 4
+        assert_eq!(
+            format(&mut symbolizer, 0xc111, 0x8000_0000),
+            "C.beqz a0, 0x80000004 <_start+0x4>"
+        );
+
+        // auipc t0, 0x80000 / lw t1, 16(t0) wraps around on rv32.
+        format(&mut symbolizer, 0x8000_0297, 0x0000_0004);
+        assert_eq!(
+            format(&mut symbolizer, 0x0102_a303, 0x0000_0008),
+            "lw t1, t0, 16 # 0x80000014 <_start+0x14>"
+        );
+        // auipc t0, 0x0 / addi t0, t0, 0 / jalr ra, 0(t0): t0 has been overwritten.
+        format(&mut symbolizer, 0x0000_0297, 0x8000_0100);
+        format(&mut symbolizer, 0x0002_8293, 0x8000_0104);
+        assert_eq!(
+            format(&mut symbolizer, 0x0002_80e7, 0x8000_0108),
+            "jalr ra, t0, 0"
+        );
+        // auipc a0, 0x0 / flw fa0, 0(a0) / addi a1, a0, 256: fa0 is not a0.
+        format(&mut symbolizer, 0x0000_0517, 0x8000_0000);
+        format(&mut symbolizer, 0x0005_2507, 0x8000_0004);
+        assert_eq!(
+            format(&mut symbolizer, 0x1005_0593, 0x8000_0008),
+            "addi a1, a0, 256 # 0x80000100 <loop>"
+        );
+
+        // pseudo-instructions.
+        let mut symbolizer = symbolizer.with_aliases(true);
+        assert_eq!(
+            format(&mut symbolizer, 0xbff5, 0x8000_0104),
+            "j 0x80000100 <loop>"
+        );
+        assert_eq!(
+            format(&mut symbolizer, 0xfe05_1ee3, 0x8000_0104),
+            "bnez a0, 0x80000100 <loop>"
+        );
+        format(&mut symbolizer, 0x0000_0517, 0x8000_0000);
+        assert_eq!(
+            format(&mut symbolizer, 0x1005_0513, 0x8000_0004),
+            "addi a0, a0, 256 # 0x80000100 <loop>"
+        );
+        assert_eq!(format(&mut symbolizer, 0x8082, 0x8000_0008), "ret");
+
+        // objdump mode.
+        let mut symbolizer = symbolizer.with_objdump(true);
+        assert_eq!(
+            format(&mut symbolizer, 0xbff5, 0x8000_0104),
+            "c.j\t80000100 <loop>"
+        );
+        format(&mut symbolizer, 0x0000_0517, 0x8000_0000);
+        assert_eq!(
+            format(&mut symbolizer, 0x1005_0513, 0x8000_0004),
+            "addi\ta0,a0,256 # 80000100 <loop>"
+        );
+
+        // formatting options.
+        let opts = FormatOptions::new()
+            .with_numeric_names(true)
+            .with_separator(",");
+        let mut symbolizer = symbolizer.with_objdump(false).with_options(&opts);
+        assert_eq!(
+            format(&mut symbolizer, 0xfeb5_1ce3, 0x8000_0108),
+            "bne x10,x11,0x80000100 <loop>"
+        );
+        let mut symbolizer = symbolizer.with_options(&opts.with_aliases(true));
+        assert_eq!(
+            format(&mut symbolizer, 0xfe05_1ee3, 0x8000_0104),
+            "bnez x10,0x80000100 <loop>"
+        );
+
+        // no symbols.
+        let mut symbolizer = Symbolizer::new(Isa::Rv64);
+        let jal = 0x1200_00ef_u32.decode(Isa::Rv64).unwrap();
+        assert_eq!(
+            format!("{:#}", symbolizer.format(&jal, 0x1000)),
+            "jal x1, 0x1120"
+        );
+    }
+}
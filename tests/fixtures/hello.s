@@ -0,0 +1,25 @@
+# llvm-mc -triple=riscv64 -filetype=obj hello.s -o hello64.o
+# sed s/rv64i/rv32i/ hello.s | llvm-mc -triple=riscv32 -filetype=obj -o hello32.o
+	.attribute arch, "rv64i2p0_m2p0_a2p0_c2p0"
+	.text
+	.globl _start
+_start:
+	addi a0, a0, 1
+	c.mv a1, a0
+	mul a0, a0, a1
+	.2byte 0
+	csrr t0, mstatus
+	jal ra, memcpy
+	ret
+
+	.type memcpy, @function
+memcpy:
+	beq a2, zero, .Ldone
+	addi a2, a2, -1
+	j memcpy
+.Ldone:
+	ret
+
+	.section .text.init, "ax", @progbits
+	li a7, 93
+	ecall